@@ -0,0 +1,4 @@
+-- Add Google and user overridden colours to calendars
+ALTER TABLE calendars ADD COLUMN color_id TEXT;
+ALTER TABLE calendars ADD COLUMN background_color TEXT;
+ALTER TABLE calendars ADD COLUMN colour_override TEXT;
@@ -0,0 +1,5 @@
+-- Store how much access the account has to each calendar
+ALTER TABLE calendars ADD COLUMN access_role TEXT;
+
+-- An account always owns its primary calendar
+UPDATE calendars SET access_role = 'owner' WHERE primary_calendar = 1;
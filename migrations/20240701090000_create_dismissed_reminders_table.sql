@@ -0,0 +1,5 @@
+-- Reminders which have been dismissed so they aren't shown again
+CREATE TABLE IF NOT EXISTS dismissed_reminders(
+                    event_id TEXT NOT NULL,
+                    remind_at TEXT NOT NULL,
+                    PRIMARY KEY (event_id, remind_at))
@@ -1,15 +1,86 @@
-use clap::{Args, Parser, Subcommand};
+use chrono::NaiveDate;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::domain::events::ResponseStatus;
 
 #[derive(Debug, Parser)]
 pub struct SoCalArgs {
+    /// Opens the calendar when no command is given
     #[clap(subcommand)]
-    pub entity: Entity,
+    pub entity: Option<Entity>,
 }
 
 #[derive(Debug, Subcommand)]
 pub enum Entity {
     /// Manage connected accounts
     Account(AccountCommand),
+    /// Act on calendar events
+    Event(EventCommand),
+    /// Create an event described in a single line, e.g. "Lunch with Sam tomorrow
+    /// 1pm for 1h @work"
+    Add {
+        /// The event's title, when it happens and optionally @calendar, quotes
+        /// are optional
+        #[clap(required = true)]
+        text: Vec<String>,
+        /// Show what would be created without creating it
+        #[clap(long)]
+        dry_run: bool,
+    },
+    /// List free time in working hours across every calendar, set the hours with
+    /// `working_hours` in config.toml, e.g. "mon-fri 09:00-17:00"
+    Free {
+        /// First day to search, defaults to today
+        #[clap(long, value_name = "YYYY-MM-DD")]
+        from: Option<NaiveDate>,
+        /// Last day to search, defaults to four days after the first
+        #[clap(long, value_name = "YYYY-MM-DD")]
+        to: Option<NaiveDate>,
+        /// Shortest slot to list, in minutes, at most a day
+        #[clap(long, default_value_t = 30, value_parser = clap::value_parser!(i64).range(1..=1440))]
+        min: i64,
+        /// Print the slots as JSON
+        #[clap(long)]
+        json: bool,
+    },
+    /// Find a time everyone is free and invite them to a meeting
+    Schedule {
+        /// Email addresses of everyone to invite
+        #[clap(required = true)]
+        attendees: Vec<String>,
+        /// What the meeting is called
+        #[clap(long, short)]
+        title: String,
+        /// Length of the meeting in minutes, at most a day
+        #[clap(long, default_value_t = 30, value_parser = clap::value_parser!(i64).range(1..=1440))]
+        minutes: i64,
+        /// First day to look on, defaults to today
+        #[clap(long, value_name = "YYYY-MM-DD")]
+        from: Option<NaiveDate>,
+        /// Last day to look on, defaults to four days after the first
+        #[clap(long, value_name = "YYYY-MM-DD")]
+        to: Option<NaiveDate>,
+    },
+    /// Keep running, syncing events and running commands as they start and
+    /// end. Commands get the event's details in SOCAL_EVENT_* variables
+    Daemon {
+        /// Command to run before each event starts, can be given more than once
+        #[clap(long = "before", value_name = "COMMAND")]
+        before_commands: Vec<String>,
+        /// How many minutes before events start to run the --before commands
+        #[clap(long, default_value_t = 5, value_parser = clap::value_parser!(i64).range(0..))]
+        minutes_before: i64,
+        /// Command to run as each event starts, can be given more than once
+        #[clap(long = "start", value_name = "COMMAND")]
+        start_commands: Vec<String>,
+        /// Command to run as each event ends, can be given more than once
+        #[clap(long = "end", value_name = "COMMAND")]
+        end_commands: Vec<String>,
+        /// How often to sync events in minutes, defaults to refresh_minutes from
+        /// the config file
+        #[clap(long, value_parser = clap::value_parser!(u64).range(1..))]
+        sync_minutes: Option<u64>,
+    },
 }
 
 #[derive(Debug, Args)]
@@ -25,3 +96,39 @@ pub enum AccountSubcommand {
     /// List the currently connected accounts
     List,
 }
+
+#[derive(Debug, Args)]
+pub struct EventCommand {
+    #[clap(subcommand)]
+    pub command: EventSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EventSubcommand {
+    /// Reply to an event you've been invited to
+    Rsvp {
+        /// Id of the event, shown in its details
+        id: String,
+        response: RsvpResponse,
+        /// Note to the organizer sent with the reply
+        #[clap(long, short)]
+        comment: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum RsvpResponse {
+    Yes,
+    No,
+    Maybe,
+}
+
+impl From<RsvpResponse> for ResponseStatus {
+    fn from(response: RsvpResponse) -> Self {
+        match response {
+            RsvpResponse::Yes => Self::Accepted,
+            RsvpResponse::No => Self::Declined,
+            RsvpResponse::Maybe => Self::Tentative,
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use color_eyre::eyre::Result;
 use dotenv_codegen::dotenv;
-use eyre::Context;
+use eyre::{eyre, Context};
 use sqlx::{sqlite::SqliteConnectOptions, SqlitePool};
 use std::{fs, path::PathBuf};
 
 use oauth2::{basic::BasicClient, AuthUrl, ClientId, ClientSecret, RedirectUrl, TokenUrl};
 
-use crate::features::oauth_http_client::GoogleOAuthClient;
+use crate::{
+    domain::{config::Config, timezone::DisplayTimezone, working_hours::WorkingHours},
+    features::oauth_http_client::GoogleOAuthClient,
+};
 
 #[derive(Debug, Clone)]
 pub struct Application {
@@ -15,6 +18,8 @@ pub struct Application {
     pub oauth_client: BasicClient,
     pub db: SqlitePool,
     pub google_client: GoogleOAuthClient,
+    pub display_timezone: DisplayTimezone,
+    pub config: Config,
 }
 
 impl Application {
@@ -31,12 +36,24 @@ impl Application {
 
         let google_client = GoogleOAuthClient::new(db.clone(), oauth_client.clone());
 
+        let display_timezone = configure_display_timezone()?;
+        let mut config = load_config()?;
+        if let Some(working_hours) = configure_working_hours()? {
+            config.working_hours = working_hours;
+        }
+        // https://no-color.org, set to anything but an empty string
+        if std::env::var("NO_COLOR").is_ok_and(|value| !value.is_empty()) {
+            config.monochrome = true;
+        }
+
         Ok(Self {
             data_dir,
             db_path,
             oauth_client,
             db,
             google_client,
+            display_timezone,
+            config,
         })
     }
 }
@@ -63,6 +80,48 @@ fn configure_oauth_client() -> Result<BasicClient> {
     ))
 }
 
+// Display times in the system time zone unless an IANA name is given in SOCAL_TIMEZONE
+fn configure_display_timezone() -> Result<DisplayTimezone> {
+    match std::env::var("SOCAL_TIMEZONE") {
+        Ok(name) => DisplayTimezone::from_name(&name).ok_or(eyre!(
+            "SOCAL_TIMEZONE is not a valid IANA time zone: {}",
+            name
+        )),
+        Err(_) => Ok(DisplayTimezone::Local),
+    }
+}
+
+// Read config.toml from the config directory, or the path in SOCAL_CONFIG,
+// using the defaults if there isn't one
+fn load_config() -> Result<Config> {
+    let config_path = match std::env::var("SOCAL_CONFIG") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => dirs_next::config_dir()
+            .expect("Unable to find config directory")
+            .join("so-calendar")
+            .join("config.toml"),
+    };
+
+    match fs::read_to_string(&config_path) {
+        Ok(toml) => Config::from_toml(&toml)
+            .wrap_err_with(|| format!("Invalid config in {}", config_path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(e) => Err(e).wrap_err_with(|| format!("Failed to read {}", config_path.display())),
+    }
+}
+
+// Working hours given in SOCAL_WORKING_HOURS, e.g. "mon-fri 09:00-17:00", take
+// precedence over the config file
+fn configure_working_hours() -> Result<Option<WorkingHours>> {
+    match std::env::var("SOCAL_WORKING_HOURS") {
+        Ok(spec) => WorkingHours::from_spec(&spec).map(Some).ok_or(eyre!(
+            "SOCAL_WORKING_HOURS should look like \"mon-fri 09:00-17:00\": {}",
+            spec
+        )),
+        Err(_) => Ok(None),
+    }
+}
+
 async fn setup_database(db_path: &PathBuf) -> Result<SqlitePool> {
     let sqlite_options = SqliteConnectOptions::new()
         .filename(db_path)
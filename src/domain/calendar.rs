@@ -1,5 +1,18 @@
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Calendar {
     pub calendar_id: String,
     pub account_id: i64,
+    pub title: String,
+    pub color_id: Option<String>,
+    pub background_color: Option<String>,
+    pub colour_override: Option<String>,
+    /// How much access the account has, e.g. "owner" or "reader"
+    pub access_role: Option<String>,
+}
+
+impl Calendar {
+    /// Whether events can be created on this calendar
+    pub fn is_writable(&self) -> bool {
+        matches!(self.access_role.as_deref(), Some("owner" | "writer"))
+    }
 }
@@ -0,0 +1,368 @@
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Duration, Utc, Weekday,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::{
+    keymap::{KeyBindings, Keymap, KeymapError},
+    theme::{CustomThemes, Theme, ThemeError},
+    timezone::DisplayTimezone,
+    working_hours::WorkingHours,
+};
+
+/// Longest gap between fetches, a day
+pub const MAX_REFRESH_MINUTES: u64 = 24 * 60;
+/// Furthest ahead events are fetched, a little over a year
+pub const MAX_FETCH_DAYS: u64 = 400;
+
+/// Settings read from `config.toml`, anything left out keeps its default
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// e.g. "mon-fri 09:00-17:00", used for free time and the hours shown
+    #[serde(deserialize_with = "deserialise_working_hours")]
+    pub working_hours: WorkingHours,
+    #[serde(deserialize_with = "deserialise_weekday")]
+    pub week_start: Weekday,
+    pub clock: Clock,
+    /// Format of dates in headings, e.g. "03 June, Monday"
+    pub date_format: String,
+    /// Format of dates in lists and next to times, e.g. "Mon 3 Jun"
+    pub short_date_format: String,
+    pub default_view: DefaultView,
+    /// How often to fetch events again, in minutes
+    pub refresh_minutes: u64,
+    /// How many days of events to fetch, starting today
+    pub fetch_days: u64,
+    /// How many days to search for invitations, starting today, longer than
+    /// `fetch_days` as invitations are often sent well ahead
+    pub invitation_days: u64,
+    /// Changes to the default key bindings, by view then action
+    #[serde(rename = "keys")]
+    pub key_bindings: KeyBindings,
+    /// The default key bindings with `key_bindings` applied
+    #[serde(skip)]
+    pub keymap: Keymap,
+    /// Name of the theme to start with, built-in or from `custom_themes`
+    pub theme: String,
+    #[serde(rename = "themes")]
+    pub custom_themes: CustomThemes,
+    /// The built-in themes then the custom ones, in the order they're switched
+    /// through
+    #[serde(skip)]
+    pub themes: Vec<Theme>,
+    /// Draw without colours whatever the theme, also turned on by `NO_COLOR`
+    pub monochrome: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            working_hours: WorkingHours::default(),
+            week_start: Weekday::Mon,
+            clock: Clock::default(),
+            date_format: "%d %B, %A".to_owned(),
+            short_date_format: "%a %-d %b".to_owned(),
+            default_view: DefaultView::default(),
+            refresh_minutes: 5,
+            fetch_days: 2,
+            invitation_days: 60,
+            key_bindings: KeyBindings::new(),
+            keymap: Keymap::default(),
+            theme: "light".to_owned(),
+            custom_themes: CustomThemes::new(),
+            themes: Theme::all(&CustomThemes::new()).expect("built-in themes are valid"),
+            monochrome: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Clock {
+    #[default]
+    #[serde(rename = "24h")]
+    TwentyFourHour,
+    #[serde(rename = "12h")]
+    TwelveHour,
+}
+
+impl Clock {
+    pub fn time_format(&self) -> &'static str {
+        match self {
+            Self::TwentyFourHour => "%R",
+            Self::TwelveHour => "%-I:%M%P",
+        }
+    }
+
+    /// Columns taken by the longest time, e.g. "10:30pm"
+    pub fn time_width(&self) -> u16 {
+        match self {
+            Self::TwentyFourHour => 5,
+            Self::TwelveHour => 7,
+        }
+    }
+}
+
+/// View shown when the calendar opens
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultView {
+    #[default]
+    Month,
+    Days,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0} is not a valid date format: {1}")]
+    InvalidDateFormat(&'static str, String),
+    #[error("{0} should be at least 1")]
+    TooSmall(&'static str),
+    #[error("{0} should be at most {1}")]
+    TooLarge(&'static str, u64),
+    #[error(transparent)]
+    Keymap(#[from] KeymapError),
+    #[error(transparent)]
+    Theme(#[from] ThemeError),
+}
+
+impl Config {
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        let mut config: Self =
+            toml::from_str(toml).map_err(|e| ConfigError::Invalid(e.message().to_owned()))?;
+
+        for (name, format) in [
+            ("date_format", &config.date_format),
+            ("short_date_format", &config.short_date_format),
+        ] {
+            // chrono only reports bad formats when they're displayed, so check up front
+            if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                return Err(ConfigError::InvalidDateFormat(name, format.to_owned()));
+            }
+        }
+        if config.refresh_minutes == 0 {
+            return Err(ConfigError::TooSmall("refresh_minutes"));
+        }
+        if config.fetch_days == 0 {
+            return Err(ConfigError::TooSmall("fetch_days"));
+        }
+        if config.invitation_days == 0 {
+            return Err(ConfigError::TooSmall("invitation_days"));
+        }
+        // Larger values overflow the dates and durations worked out from them
+        if config.refresh_minutes > MAX_REFRESH_MINUTES {
+            return Err(ConfigError::TooLarge(
+                "refresh_minutes",
+                MAX_REFRESH_MINUTES,
+            ));
+        }
+        if config.fetch_days > MAX_FETCH_DAYS {
+            return Err(ConfigError::TooLarge("fetch_days", MAX_FETCH_DAYS));
+        }
+        if config.invitation_days > MAX_FETCH_DAYS {
+            return Err(ConfigError::TooLarge("invitation_days", MAX_FETCH_DAYS));
+        }
+        config.keymap = Keymap::new(&config.key_bindings)?;
+        config.themes = Theme::all(&config.custom_themes)?;
+        if !config.themes.iter().any(|theme| theme.name == config.theme) {
+            return Err(ThemeError::UnknownTheme(config.theme).into());
+        }
+
+        Ok(config)
+    }
+
+    /// The theme named in the config file
+    pub fn initial_theme(&self) -> Theme {
+        if self.monochrome {
+            return Theme::monochrome();
+        }
+
+        self.themes
+            .iter()
+            .find(|theme| theme.name == self.theme)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The theme after the named one, going back to the first after the last
+    pub fn theme_after(&self, name: &str) -> Theme {
+        if self.monochrome {
+            return Theme::monochrome();
+        }
+
+        let index = self
+            .themes
+            .iter()
+            .position(|theme| theme.name == name)
+            .unwrap_or(0);
+
+        self.themes
+            .iter()
+            .cycle()
+            .nth(index + 1)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn time_format(&self) -> &'static str {
+        self.clock.time_format()
+    }
+
+    /// Short date followed by the time, e.g. "Mon 3 Jun 09:30"
+    pub fn date_time_format(&self) -> String {
+        format!("{} {}", self.short_date_format, self.time_format())
+    }
+
+    /// Events are fetched from the start of today until the end of the fetch horizon
+    pub fn fetch_window(
+        &self,
+        display_timezone: DisplayTimezone,
+    ) -> (DateTime<Utc>, DateTime<Utc>) {
+        days_from_today(self.fetch_days, display_timezone)
+    }
+
+    /// Invitations are searched for from the start of today for `invitation_days`
+    pub fn invitation_window(
+        &self,
+        display_timezone: DisplayTimezone,
+    ) -> (DateTime<Utc>, DateTime<Utc>) {
+        days_from_today(self.invitation_days, display_timezone)
+    }
+
+    pub fn refresh_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.refresh_minutes * 60)
+    }
+}
+
+fn days_from_today(days: u64, display_timezone: DisplayTimezone) -> (DateTime<Utc>, DateTime<Utc>) {
+    let today = display_timezone.today();
+    let days = Duration::days(days.try_into().unwrap_or(i64::MAX));
+
+    (
+        display_timezone.start_of_day(today),
+        display_timezone.start_of_day(today + days),
+    )
+}
+
+fn deserialise_working_hours<'de, D>(deserializer: D) -> Result<WorkingHours, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let spec = String::deserialize(deserializer)?;
+    WorkingHours::from_spec(&spec).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "working_hours should look like \"mon-fri 09:00-17:00\": {}",
+            spec
+        ))
+    })
+}
+
+fn deserialise_weekday<'de, D>(deserializer: D) -> Result<Weekday, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let day = String::deserialize(deserializer)?;
+    day.parse()
+        .map_err(|_| serde::de::Error::custom(format!("week_start is not a day: {}", day)))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveTime, Weekday};
+
+    use super::{Clock, Config, ConfigError, DefaultView};
+    use crate::domain::theme::ThemeError;
+
+    #[test]
+    fn reads_settings_and_defaults_the_rest() {
+        // Arrange
+        let toml = r#"
+            working_hours = "mon-thu 08:00-16:00"
+            week_start = "sunday"
+            clock = "12h"
+            default_view = "days"
+        "#;
+
+        // Act
+        let config = Config::from_toml(toml).unwrap();
+
+        // Assert
+        assert_eq!(config.working_hours.days.len(), 4);
+        assert_eq!(
+            config.working_hours.start,
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap()
+        );
+        assert_eq!(config.week_start, Weekday::Sun);
+        assert_eq!(config.clock, Clock::TwelveHour);
+        assert_eq!(config.default_view, DefaultView::Days);
+        assert_eq!(config.date_format, Config::default().date_format);
+        assert_eq!(config.fetch_days, 2);
+        assert_eq!(config.invitation_days, 60);
+        assert_eq!(Config::from_toml("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn starts_with_the_named_theme_and_switches_through_the_rest() {
+        // Arrange
+        let toml = r##"
+            theme = "mocha"
+
+            [themes.mocha]
+            extends = "dark"
+            background = "#1e1e2e"
+        "##;
+
+        // Act
+        let config = Config::from_toml(toml).unwrap();
+
+        // Assert
+        assert_eq!(config.initial_theme().name, "mocha");
+        assert_eq!(config.theme_after("light").name, "dark");
+        assert_eq!(config.theme_after("mocha").name, "light");
+
+        let monochrome = Config::from_toml("theme = \"dark\"\nmonochrome = true").unwrap();
+        assert!(monochrome.initial_theme().monochrome);
+        assert!(monochrome.theme_after("dark").monochrome);
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert_eq!(
+            Config::from_toml("date_format = \"%d %Q\""),
+            Err(ConfigError::InvalidDateFormat(
+                "date_format",
+                "%d %Q".to_owned()
+            ))
+        );
+        assert_eq!(
+            Config::from_toml("fetch_days = 0"),
+            Err(ConfigError::TooSmall("fetch_days"))
+        );
+        assert_eq!(
+            Config::from_toml("fetch_days = 9223372036854775807"),
+            Err(ConfigError::TooLarge("fetch_days", 400))
+        );
+        assert_eq!(
+            Config::from_toml("refresh_minutes = 9223372036854775807"),
+            Err(ConfigError::TooLarge("refresh_minutes", 1440))
+        );
+        assert!(matches!(
+            Config::from_toml("clock = \"13h\""),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            Config::from_toml("colour = \"blue\""),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert_eq!(
+            Config::from_toml("theme = \"mocha\""),
+            Err(ConfigError::Theme(ThemeError::UnknownTheme(
+                "mocha".to_owned()
+            )))
+        );
+    }
+}
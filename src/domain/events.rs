@@ -1,18 +1,442 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Days, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+use super::{colour::Colour, recurrence::Recurrence, timezone::DisplayTimezone};
+
+#[derive(Debug, Clone)]
 pub struct Event {
     pub id: String,
+    /// Version of the event Google last sent, changes are only applied if it
+    /// still matches so others' edits aren't overwritten
+    pub etag: Option<String>,
     pub title: String,
     pub description: Option<String>,
+    pub status: EventStatus,
+    pub time: EventTime,
+    pub colour_id: Option<String>,
+    pub colour: Option<Colour>,
+    pub location: Option<String>,
+    pub organizer: Option<Person>,
+    pub attendees: Vec<Attendee>,
+    pub html_link: Option<String>,
+    pub hangout_link: Option<String>,
+    pub conference_entry_points: Vec<ConferenceEntryPoint>,
+    pub transparency: Transparency,
+    pub visibility: Visibility,
+    pub event_type: EventType,
+    pub calendar_id: String,
+    /// Name of the calendar the event is on, filled in once the event is fetched
+    pub calendar_title: Option<String>,
+    pub account_id: i64,
+    /// How the series this event belongs to repeats, if it is recurring
+    pub recurrence: Option<Recurrence>,
+    /// Id of the series this event is an instance of
+    pub recurring_event_id: Option<String>,
+    /// Start this instance would have had if it hadn't been moved
+    pub original_start: Option<EventStart>,
+    /// When to be reminded of the event, the calendar's defaults unless the
+    /// event has its own
+    pub reminders: Vec<Reminder>,
+}
+
+/// When an event happens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventTime {
+    /// A fixed span of time, the same instants whichever time zone it is shown in
+    Timed {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+    /// Wall clock times without a time zone, shown at the same times in any
+    /// display time zone
+    Floating {
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    },
+    /// Whole days, the end date is exclusive
+    AllDay { start: NaiveDate, end: NaiveDate },
+}
+
+/// The start of an event, identifies an instance within a recurring series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStart {
+    Instant(DateTime<Utc>),
+    Floating(NaiveDateTime),
+    Date(NaiveDate),
+}
+
+impl EventTime {
+    pub fn start(&self) -> EventStart {
+        match self {
+            Self::Timed { start, .. } => EventStart::Instant(*start),
+            Self::Floating { start, .. } => EventStart::Floating(*start),
+            Self::AllDay { start, .. } => EventStart::Date(*start),
+        }
+    }
+
+    pub fn is_all_day(&self) -> bool {
+        matches!(self, Self::AllDay { .. })
+    }
+
+    /// Instant the event starts at when shown in the given time zone
+    pub fn start_instant(&self, display_timezone: DisplayTimezone) -> DateTime<Utc> {
+        match self {
+            Self::Timed { start, .. } => *start,
+            Self::Floating { start, .. } => display_timezone.to_utc(start),
+            Self::AllDay { start, .. } => display_timezone.start_of_day(*start),
+        }
+    }
+
+    /// Instant the event ends at when shown in the given time zone
+    pub fn end_instant(&self, display_timezone: DisplayTimezone) -> DateTime<Utc> {
+        match self {
+            Self::Timed { end, .. } => *end,
+            Self::Floating { end, .. } => display_timezone.to_utc(end),
+            Self::AllDay { end, .. } => display_timezone.start_of_day(*end),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Timed { start, end } => *end - *start,
+            Self::Floating { start, end } => *end - *start,
+            Self::AllDay { start, end } => *end - *start,
+        }
+    }
+
+    /// Whether any of the event falls within from..until, events without a
+    /// length overlap the range if they start inside it
+    pub fn overlaps(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        display_timezone: DisplayTimezone,
+    ) -> bool {
+        let start = self.start_instant(display_timezone);
+        let end = self.end_instant(display_timezone);
+
+        start < until && (end > from || start == from)
+    }
+
+    pub fn occurs_on(&self, date: NaiveDate, display_timezone: DisplayTimezone) -> bool {
+        match self {
+            Self::AllDay { start, end } => *start <= date && date < *end,
+            _ => self.overlaps(
+                display_timezone.start_of_day(date),
+                display_timezone.start_of_day(date + Days::new(1)),
+                display_timezone,
+            ),
+        }
+    }
+}
+
+/// The part of a timed event which falls on a single day, events crossing
+/// midnight are split into a segment for each day they cover
+#[derive(Debug, Clone, Copy)]
+pub struct EventSegment<'a> {
+    pub event: &'a Event,
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
+    pub continues_from_previous_day: bool,
+    pub continues_to_next_day: bool,
 }
 
-#[derive(Debug)]
-pub struct DayEvent {
-    pub id: String,
-    pub title: String,
-    pub description: Option<String>,
-    pub date: NaiveDate,
+impl Event {
+    /// The part of this event on the given day in the display time zone, all day
+    /// events aren't split so have no segments
+    pub fn segment_on(
+        &self,
+        date: NaiveDate,
+        display_timezone: DisplayTimezone,
+    ) -> Option<EventSegment<'_>> {
+        if self.time.is_all_day() || !self.time.occurs_on(date, display_timezone) {
+            return None;
+        }
+
+        let day_start = display_timezone.start_of_day(date);
+        let day_end = display_timezone.start_of_day(date + Days::new(1));
+        let start_time = self.time.start_instant(display_timezone);
+        let end_time = self.time.end_instant(display_timezone);
+
+        Some(EventSegment {
+            event: self,
+            start_time: start_time.max(day_start),
+            end_time: end_time.min(day_end),
+            continues_from_previous_day: start_time < day_start,
+            continues_to_next_day: end_time > day_end,
+        })
+    }
+
+    /// Whether we can change or delete the event, it must be one we organise and
+    /// we must know which version of it we have. Instances of recurring series
+    /// are expanded locally so can't be changed
+    pub fn is_editable(&self) -> bool {
+        let is_organizer = self
+            .organizer
+            .as_ref()
+            .map(|organizer| organizer.is_self)
+            .unwrap_or(true);
+
+        is_organizer && self.etag.is_some()
+    }
+
+    /// How we've replied to the event, if we're invited to it
+    pub fn own_response(&self) -> Option<ResponseStatus> {
+        self.attendees
+            .iter()
+            .find(|attendee| attendee.is_self)
+            .map(|attendee| attendee.response_status)
+    }
+
+    /// Whether the event blocks out its time, events marked as free and those
+    /// we've declined don't
+    pub fn is_busy(&self) -> bool {
+        self.transparency == Transparency::Opaque
+            && self.own_response() != Some(ResponseStatus::Declined)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    Confirmed,
+    /// The event has been tentatively confirmed by its organizer
+    Tentative,
+}
+
+/// An organizer or creator of an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Person {
+    pub email: Option<String>,
+    pub display_name: Option<String>,
+    /// Whether this person is the account the event was fetched with
+    pub is_self: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attendee {
+    pub email: Option<String>,
+    pub display_name: Option<String>,
+    pub response_status: ResponseStatus,
+    pub comment: Option<String>,
+    pub optional: bool,
+    pub organizer: bool,
+    /// A room or other resource rather than a person
+    pub resource: bool,
+    /// Guests the attendee is bringing along
+    pub additional_guests: u32,
+    pub is_self: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ResponseStatus {
+    #[default]
+    NeedsAction,
+    Declined,
+    Tentative,
+    Accepted,
+    /// Added to the API since, never sent back
+    #[serde(other)]
+    Unknown,
+}
+
+/// A way of joining a conference linked to an event, e.g. a video call url
+/// or a dial in number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConferenceEntryPoint {
+    pub entry_point_type: String,
+    pub uri: String,
+    pub label: Option<String>,
+}
+
+/// Whether an event blocks time on the calendar
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum Transparency {
+    #[default]
+    Opaque,
+    Transparent,
+}
+
+/// A reminder the given number of minutes before an event starts
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reminder {
+    pub method: ReminderMethod,
+    pub minutes: i64,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReminderMethod {
+    Popup,
+    Email,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum Visibility {
+    #[default]
+    Default,
+    Public,
+    Private,
+    Confidential,
+    /// Added to the API since, treated as the calendar's default
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum EventType {
+    #[default]
+    Default,
+    Birthday,
+    FocusTime,
+    FromGmail,
+    OutOfOffice,
+    WorkingLocation,
+    #[serde(other)]
+    Other,
+}
+
+impl Event {
+    /// An hour long confirmed event on a test calendar, for unit and integration
+    /// tests to override the fields they care about with `..Event::test_default()`
+    pub fn test_default() -> Self {
+        let start = DateTime::parse_from_rfc3339("2024-06-03T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        Self {
+            id: "event_id".into(),
+            etag: None,
+            title: "Event".into(),
+            description: None,
+            status: EventStatus::Confirmed,
+            time: EventTime::Timed {
+                start,
+                end: start + Duration::hours(1),
+            },
+            colour_id: None,
+            colour: None,
+            location: None,
+            organizer: None,
+            attendees: vec![],
+            html_link: None,
+            hangout_link: None,
+            conference_entry_points: vec![],
+            transparency: Transparency::Opaque,
+            visibility: Visibility::Default,
+            event_type: EventType::Default,
+            calendar_id: "test@test.com".into(),
+            calendar_title: None,
+            account_id: 1,
+            recurrence: None,
+            recurring_event_id: None,
+            original_start: None,
+            reminders: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    use super::{Event, EventTime};
+    use crate::domain::timezone::DisplayTimezone;
+
+    fn time(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn event(time: EventTime) -> Event {
+        Event {
+            id: "event_id".into(),
+            title: "Late release".into(),
+            time,
+            ..Event::test_default()
+        }
+    }
+
+    #[test]
+    fn splits_events_crossing_midnight_into_segments() {
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+        // 22:00 to 02:00 in British summer time
+        let event = event(EventTime::Timed {
+            start: time("2024-06-03T21:00:00Z"),
+            end: time("2024-06-04T01:00:00Z"),
+        });
+
+        let first_day = event
+            .segment_on(NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(), london)
+            .unwrap();
+        let second_day = event
+            .segment_on(NaiveDate::from_ymd_opt(2024, 6, 4).unwrap(), london)
+            .unwrap();
+
+        assert_eq!(first_day.start_time, time("2024-06-03T21:00:00Z"));
+        assert_eq!(first_day.end_time, time("2024-06-03T23:00:00Z"));
+        assert!(!first_day.continues_from_previous_day);
+        assert!(first_day.continues_to_next_day);
+
+        assert_eq!(second_day.start_time, time("2024-06-03T23:00:00Z"));
+        assert_eq!(second_day.end_time, time("2024-06-04T01:00:00Z"));
+        assert!(second_day.continues_from_previous_day);
+        assert!(!second_day.continues_to_next_day);
+    }
+
+    #[test]
+    fn events_ending_at_midnight_do_not_spill_into_next_day() {
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+        let event = event(EventTime::Timed {
+            start: time("2024-06-03T21:00:00Z"),
+            end: time("2024-06-03T23:00:00Z"),
+        });
+
+        let next_day = event.segment_on(NaiveDate::from_ymd_opt(2024, 6, 4).unwrap(), london);
+
+        assert!(next_day.is_none());
+    }
+
+    #[test]
+    fn all_day_events_occur_on_every_day_they_cover() {
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+        let offsite = EventTime::AllDay {
+            start: NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(),
+            end: NaiveDate::from_ymd_opt(2024, 6, 8).unwrap(),
+        };
+
+        assert!(offsite.occurs_on(NaiveDate::from_ymd_opt(2024, 6, 7).unwrap(), london));
+        assert!(!offsite.occurs_on(NaiveDate::from_ymd_opt(2024, 6, 8).unwrap(), london));
+        assert_eq!(offsite.duration().num_days(), 5);
+    }
+
+    #[test]
+    fn floating_times_follow_the_display_time_zone() {
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+        let new_york = DisplayTimezone::from_name("America/New_York").unwrap();
+        let lunch = EventTime::Floating {
+            start: NaiveDate::from_ymd_opt(2024, 6, 3)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+            end: NaiveDate::from_ymd_opt(2024, 6, 3)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        };
+
+        assert_eq!(lunch.start_instant(london), time("2024-06-03T11:00:00Z"));
+        assert_eq!(lunch.start_instant(new_york), time("2024-06-03T16:00:00Z"));
+        assert!(lunch.overlaps(
+            time("2024-06-03T11:30:00Z"),
+            time("2024-06-03T12:30:00Z"),
+            london
+        ));
+    }
 }
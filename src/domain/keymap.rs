@@ -0,0 +1,662 @@
+use std::{collections::HashMap, fmt};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Counts above this are capped, so a slip of the finger can't queue up
+/// thousands of moves
+const MAX_COUNT: usize = 999;
+
+/// Key presses given in the config file for each action, replacing the
+/// defaults for that action in the same scope
+pub type KeyBindings = HashMap<KeyScope, HashMap<Action, Vec<String>>>;
+
+/// Views with their own bindings, global bindings apply everywhere unless a
+/// view binds the same action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyScope {
+    Global,
+    Month,
+    Days,
+    Invitations,
+    FreeSlots,
+    Accounts,
+    SignUp,
+}
+
+impl KeyScope {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::Month => "month",
+            Self::Days => "days",
+            Self::Invitations => "invitations",
+            Self::FreeSlots => "free_slots",
+            Self::Accounts => "accounts",
+            Self::SignUp => "sign_up",
+        }
+    }
+}
+
+/// Everything a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    First,
+    Last,
+    NextView,
+    PreviousView,
+    New,
+    QuickAdd,
+    Edit,
+    Delete,
+    Rsvp,
+    Invitations,
+    FreeSlots,
+    ReplyYes,
+    ReplyMaybe,
+    ReplyNo,
+    Accounts,
+    CycleColour,
+    ToggleTentative,
+    Snooze,
+    SnoozeLong,
+    ToggleTheme,
+    Help,
+}
+
+impl Action {
+    /// Whether a count before the keys repeats the action, e.g. "3j"
+    pub fn is_repeatable(&self) -> bool {
+        matches!(self, Self::Up | Self::Down | Self::Left | Self::Right)
+    }
+
+    /// What the action does, as shown in the help
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Quit => "Quit",
+            Self::Back => "Go back or close",
+            Self::Up => "Move up",
+            Self::Down => "Move down",
+            Self::Left => "Move left",
+            Self::Right => "Move right",
+            Self::Enter => "Select",
+            Self::First => "Go to the top",
+            Self::Last => "Go to the bottom",
+            Self::NextView => "Next view",
+            Self::PreviousView => "Previous view",
+            Self::New => "Add new",
+            Self::QuickAdd => "Quick add an event",
+            Self::Edit => "Edit event",
+            Self::Delete => "Delete event",
+            Self::Rsvp => "Reply to event",
+            Self::Invitations => "Invitations",
+            Self::FreeSlots => "Free time",
+            Self::ReplyYes => "Accept",
+            Self::ReplyMaybe => "Maybe",
+            Self::ReplyNo => "Decline",
+            Self::Accounts => "Manage accounts",
+            Self::CycleColour => "Change calendar colour",
+            Self::ToggleTentative => "Show or hide tentative events",
+            Self::Snooze => "Snooze reminder",
+            Self::SnoozeLong => "Snooze reminder for longer",
+            Self::ToggleTheme => "Switch colour theme",
+            Self::Help => "Show or hide these keys",
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Matches the names used in the config file
+        let name = format!("{:?}", self);
+        let mut snake_case = String::new();
+        for (i, c) in name.chars().enumerate() {
+            if c.is_uppercase() && i > 0 {
+                snake_case.push('_');
+            }
+            snake_case.push(c.to_ascii_lowercase());
+        }
+        f.write_str(&snake_case)
+    }
+}
+
+const DEFAULT_BINDINGS: &[(KeyScope, Action, &[&str])] = &[
+    (KeyScope::Global, Action::Quit, &["q"]),
+    (KeyScope::Global, Action::Back, &["<Esc>"]),
+    (KeyScope::Global, Action::Up, &["k", "<Up>"]),
+    (KeyScope::Global, Action::Down, &["j", "<Down>"]),
+    (KeyScope::Global, Action::Left, &["h", "<Left>"]),
+    (KeyScope::Global, Action::Right, &["l", "<Right>"]),
+    (KeyScope::Global, Action::Enter, &["<Enter>"]),
+    (KeyScope::Global, Action::First, &["gg"]),
+    (KeyScope::Global, Action::Last, &["G"]),
+    (KeyScope::Global, Action::NextView, &["gt"]),
+    (KeyScope::Global, Action::PreviousView, &["gT"]),
+    (KeyScope::Global, Action::Invitations, &["i"]),
+    (KeyScope::Global, Action::FreeSlots, &["f"]),
+    (KeyScope::Global, Action::Accounts, &["a"]),
+    (KeyScope::Global, Action::Snooze, &["s"]),
+    (KeyScope::Global, Action::SnoozeLong, &["S"]),
+    (KeyScope::Global, Action::ToggleTheme, &["T"]),
+    (KeyScope::Global, Action::Help, &["?"]),
+    (KeyScope::Month, Action::New, &["n"]),
+    (KeyScope::Month, Action::QuickAdd, &["N"]),
+    (KeyScope::Month, Action::Edit, &["e"]),
+    (KeyScope::Month, Action::Delete, &["d"]),
+    (KeyScope::Month, Action::Rsvp, &["r"]),
+    (KeyScope::Month, Action::ToggleTentative, &["t"]),
+    (KeyScope::Days, Action::New, &["n"]),
+    (KeyScope::Days, Action::QuickAdd, &["N"]),
+    (KeyScope::Days, Action::Edit, &["e"]),
+    (KeyScope::Days, Action::Delete, &["d"]),
+    (KeyScope::Days, Action::Rsvp, &["r"]),
+    (KeyScope::Days, Action::ToggleTentative, &["t"]),
+    (KeyScope::Invitations, Action::ReplyYes, &["y"]),
+    (KeyScope::Invitations, Action::ReplyMaybe, &["m"]),
+    (KeyScope::Invitations, Action::ReplyNo, &["x"]),
+    (KeyScope::Accounts, Action::New, &["n"]),
+    (KeyScope::Accounts, Action::CycleColour, &["c"]),
+];
+
+/// A single key press, shift is part of the character so only ctrl and alt
+/// are kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyPress {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl From<KeyEvent> for KeyPress {
+    fn from(key: KeyEvent) -> Self {
+        Self {
+            code: key.code,
+            modifiers: key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+}
+
+impl fmt::Display for KeyPress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.code {
+            KeyCode::Char('<') => "lt".to_owned(),
+            KeyCode::Char(' ') => "Space".to_owned(),
+            KeyCode::Char(c) if self.modifiers.is_empty() => return write!(f, "{}", c),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(number) => format!("F{}", number),
+            code => format!("{:?}", code),
+        };
+        let ctrl = if self.modifiers.contains(KeyModifiers::CONTROL) {
+            "C-"
+        } else {
+            ""
+        };
+        let alt = if self.modifiers.contains(KeyModifiers::ALT) {
+            "A-"
+        } else {
+            ""
+        };
+
+        write!(f, "<{}{}{}>", ctrl, alt, name)
+    }
+}
+
+/// Keys of a binding as they'd be written in the config file, e.g. "gg" or "<C-d>"
+pub fn format_keys(keys: &[KeyPress]) -> String {
+    keys.iter().map(|key| key.to_string()).collect()
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KeymapError {
+    #[error("\"{0}\" is not a valid key binding, use e.g. \"j\", \"gg\", \"<Esc>\" or \"<C-d>\"")]
+    InvalidKeys(String),
+    #[error("\"{0}\" can't be bound, digits before a binding are used as a count")]
+    CountKeys(String),
+    #[error("\"{keys}\" for {action} and \"{other_keys}\" for {other_action} conflict in [keys.{scope}]")]
+    Conflict {
+        scope: &'static str,
+        keys: String,
+        action: Action,
+        other_keys: String,
+        other_action: Action,
+    },
+    #[error("\"{keys}\" for {action} in [keys.{scope}] and \"{global_keys}\" for {global_action} in [keys.global] conflict")]
+    GlobalConflict {
+        scope: &'static str,
+        keys: String,
+        action: Action,
+        global_keys: String,
+        global_action: Action,
+    },
+}
+
+/// Parse a sequence of keys, e.g. "gg", "G", "<Esc>" or "<C-d>"
+pub fn parse_keys(spec: &str) -> Result<Vec<KeyPress>, KeymapError> {
+    let invalid = || KeymapError::InvalidKeys(spec.to_owned());
+
+    let mut keys = vec![];
+    let mut chars = spec.chars();
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            keys.push(KeyPress {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+            });
+            continue;
+        }
+
+        let name = chars.by_ref().take_while(|c| *c != '>').collect::<String>();
+        let mut modifiers = KeyModifiers::NONE;
+        let mut name = name.as_str();
+        loop {
+            let lower = name.to_ascii_lowercase();
+            if lower.starts_with("c-") && name.len() > 2 {
+                modifiers |= KeyModifiers::CONTROL;
+            } else if (lower.starts_with("a-") || lower.starts_with("m-")) && name.len() > 2 {
+                modifiers |= KeyModifiers::ALT;
+            } else {
+                break;
+            }
+            name = &name[2..];
+        }
+
+        let code = match name.to_ascii_lowercase().as_str() {
+            "esc" => KeyCode::Esc,
+            "enter" | "cr" => KeyCode::Enter,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "tab" => KeyCode::Tab,
+            "backtab" | "s-tab" => KeyCode::BackTab,
+            "backspace" | "bs" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "space" => KeyCode::Char(' '),
+            "lt" => KeyCode::Char('<'),
+            function if function.starts_with('f') && function.len() > 1 => {
+                KeyCode::F(function[1..].parse().map_err(|_| invalid())?)
+            }
+            _ => {
+                let mut name_chars = name.chars();
+                match (name_chars.next(), name_chars.next()) {
+                    (Some(c), None) if !modifiers.is_empty() => KeyCode::Char(c),
+                    _ => return Err(invalid()),
+                }
+            }
+        };
+        keys.push(KeyPress { code, modifiers });
+    }
+
+    match keys.first() {
+        None => Err(invalid()),
+        Some(KeyPress {
+            code: KeyCode::Char('1'..='9'),
+            modifiers: KeyModifiers::NONE,
+        }) => Err(KeymapError::CountKeys(spec.to_owned())),
+        Some(_) => Ok(keys),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    pub keys: Vec<KeyPress>,
+    pub action: Action,
+}
+
+impl Binding {
+    /// Whether one binding's keys would be typed on the way to the other's
+    fn overlaps(&self, other: &Binding) -> bool {
+        self.keys.starts_with(&other.keys) || other.keys.starts_with(&self.keys)
+    }
+}
+
+/// Keys bound to actions in each view, the defaults with the config file's
+/// changes applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    scopes: HashMap<KeyScope, Vec<Binding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&KeyBindings::new()).expect("default key bindings don't conflict")
+    }
+}
+
+impl Keymap {
+    /// Fails when two bindings in the same scope could be typed with the same
+    /// keys, or one is the start of another, or when a view's binding would hide
+    /// a global one for a different action
+    pub fn new(overrides: &KeyBindings) -> Result<Self, KeymapError> {
+        let mut scopes: HashMap<KeyScope, Vec<Binding>> = HashMap::new();
+
+        let defaults = DEFAULT_BINDINGS
+            .iter()
+            .filter(|(scope, action, _)| {
+                !overrides
+                    .get(scope)
+                    .is_some_and(|actions| actions.contains_key(action))
+            })
+            .flat_map(|(scope, action, keys)| {
+                keys.iter().map(|keys| (*scope, *action, keys.to_string()))
+            });
+        let overridden = overrides.iter().flat_map(|(scope, actions)| {
+            actions.iter().flat_map(|(action, keys)| {
+                keys.iter().map(|keys| (*scope, *action, keys.to_owned()))
+            })
+        });
+
+        for (scope, action, keys) in defaults.chain(overridden) {
+            let binding = Binding {
+                keys: parse_keys(&keys)?,
+                action,
+            };
+            let bindings = scopes.entry(scope).or_default();
+            if !bindings.contains(&binding) {
+                bindings.push(binding);
+            }
+        }
+
+        for (scope, bindings) in scopes.iter_mut() {
+            // Defaults come first, so changes from the config file are named first
+            for (i, binding) in bindings.iter().enumerate() {
+                if let Some(other) = bindings[..i].iter().find(|other| binding.overlaps(other)) {
+                    return Err(KeymapError::Conflict {
+                        scope: scope.name(),
+                        keys: format_keys(&binding.keys),
+                        action: binding.action,
+                        other_keys: format_keys(&other.keys),
+                        other_action: other.action,
+                    });
+                }
+            }
+            bindings.sort_by_key(|binding| binding.action);
+        }
+
+        // Views are checked in order so the same conflict is always reported
+        let global = scopes.get(&KeyScope::Global).cloned().unwrap_or_default();
+        let mut views = scopes
+            .iter()
+            .filter(|(scope, _)| **scope != KeyScope::Global)
+            .collect::<Vec<_>>();
+        views.sort_by_key(|(scope, _)| **scope);
+        for (scope, bindings) in views {
+            // Global actions the view binds itself are meant to be replaced
+            let conflict = bindings.iter().find_map(|binding| {
+                global
+                    .iter()
+                    .filter(|global| !bindings.iter().any(|own| own.action == global.action))
+                    .find(|global| binding.overlaps(global))
+                    .map(|global| (binding, global))
+            });
+            if let Some((binding, global)) = conflict {
+                return Err(KeymapError::GlobalConflict {
+                    scope: scope.name(),
+                    keys: format_keys(&binding.keys),
+                    action: binding.action,
+                    global_keys: format_keys(&global.keys),
+                    global_action: global.action,
+                });
+            }
+        }
+
+        Ok(Self { scopes })
+    }
+
+    /// Bindings which apply in a view, its own followed by the global ones for
+    /// other actions and keys
+    pub fn bindings(&self, scope: KeyScope) -> Vec<&Binding> {
+        let global = self.scopes.get(&KeyScope::Global).into_iter().flatten();
+        let own = match scope {
+            KeyScope::Global => None,
+            scope => self.scopes.get(&scope),
+        }
+        .map(|bindings| bindings.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+        let global = global
+            .filter(|binding| {
+                !own.iter()
+                    .any(|own| own.action == binding.action || own.overlaps(binding))
+            })
+            .collect::<Vec<_>>();
+
+        own.into_iter().chain(global).collect()
+    }
+
+    /// The first keys which do an action in a view, for hints like "? keys"
+    pub fn keys_for(&self, scope: KeyScope, action: Action) -> Option<String> {
+        self.bindings(scope)
+            .into_iter()
+            .find(|binding| binding.action == action)
+            .map(|binding| format_keys(&binding.keys))
+    }
+
+    /// A hint naming the keys for each group of actions, e.g.
+    /// " k/j scroll · <Esc> close ", leaving out groups with an unbound action
+    pub fn hint(&self, scope: KeyScope, hints: &[(&[Action], &str)]) -> String {
+        let parts = hints
+            .iter()
+            .filter_map(|(actions, label)| {
+                let keys = actions
+                    .iter()
+                    .map(|action| self.keys_for(scope, *action))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("{} {}", keys.join("/"), label))
+            })
+            .collect::<Vec<_>>();
+
+        if parts.is_empty() {
+            String::new()
+        } else {
+            format!(" {} ", parts.join(" · "))
+        }
+    }
+}
+
+/// Keys typed so far towards a count or a binding of more than one key
+#[derive(Debug, Default)]
+pub struct KeyInput {
+    count: Option<usize>,
+    pending: Vec<KeyPress>,
+}
+
+impl KeyInput {
+    /// The action once all of a binding's keys have been typed, with how many
+    /// times to do it
+    pub fn press(&mut self, key: KeyPress, bindings: &[&Binding]) -> Option<(Action, usize)> {
+        if self.pending.is_empty() && key.modifiers.is_empty() {
+            if let KeyCode::Char(c @ '0'..='9') = key.code {
+                if c != '0' || self.count.is_some() {
+                    let digit = c.to_digit(10).expect("digit") as usize;
+                    let count = self.count.unwrap_or(0) * 10 + digit;
+                    self.count = Some(count.min(MAX_COUNT));
+                    return None;
+                }
+            }
+        }
+
+        self.pending.push(key);
+        if let Some(binding) = bindings.iter().find(|binding| binding.keys == self.pending) {
+            let action = binding.action;
+            let count = match action.is_repeatable() {
+                true => self.count.unwrap_or(1),
+                false => 1,
+            };
+            self.reset();
+            return Some((action, count));
+        }
+        if bindings
+            .iter()
+            .any(|binding| binding.keys.starts_with(&self.pending))
+        {
+            return None;
+        }
+
+        // Start again from this key when it doesn't continue the keys so far
+        let started_again = self.pending.len() > 1;
+        self.reset();
+        if started_again {
+            return self.press(key, bindings);
+        }
+        None
+    }
+
+    pub fn reset(&mut self) {
+        self.count = None;
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    use super::{
+        format_keys, parse_keys, Action, KeyBindings, KeyInput, KeyPress, KeyScope, Keymap,
+        KeymapError,
+    };
+
+    fn press(input: &mut KeyInput, keymap: &Keymap, keys: &str) -> Option<(Action, usize)> {
+        let bindings = keymap.bindings(KeyScope::Month);
+        keys.chars()
+            .map(|c| KeyPress {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+            })
+            .fold(None, |_, key| input.press(key, &bindings))
+    }
+
+    #[test]
+    fn parses_keys_and_formats_them_back() {
+        let keys = parse_keys("g<C-d><Esc><lt>").unwrap();
+
+        assert_eq!(
+            keys,
+            vec![
+                KeyPress {
+                    code: KeyCode::Char('g'),
+                    modifiers: KeyModifiers::NONE
+                },
+                KeyPress {
+                    code: KeyCode::Char('d'),
+                    modifiers: KeyModifiers::CONTROL
+                },
+                KeyPress {
+                    code: KeyCode::Esc,
+                    modifiers: KeyModifiers::NONE
+                },
+                KeyPress {
+                    code: KeyCode::Char('<'),
+                    modifiers: KeyModifiers::NONE
+                },
+            ]
+        );
+        assert_eq!(format_keys(&keys), "g<C-d><Esc><lt>");
+        assert!(matches!(
+            parse_keys("<Nope>"),
+            Err(KeymapError::InvalidKeys(_))
+        ));
+        assert!(matches!(parse_keys("3j"), Err(KeymapError::CountKeys(_))));
+    }
+
+    #[test]
+    fn counts_and_multi_key_bindings_resolve_to_actions() {
+        // Arrange
+        let keymap = Keymap::default();
+        let mut input = KeyInput::default();
+
+        // Act/Assert
+        assert_eq!(press(&mut input, &keymap, "j"), Some((Action::Down, 1)));
+        assert_eq!(press(&mut input, &keymap, "12k"), Some((Action::Up, 12)));
+        assert_eq!(press(&mut input, &keymap, "g"), None);
+        assert_eq!(press(&mut input, &keymap, "g"), Some((Action::First, 1)));
+        assert_eq!(
+            press(&mut input, &keymap, "gT"),
+            Some((Action::PreviousView, 1))
+        );
+        assert_eq!(press(&mut input, &keymap, "3e"), Some((Action::Edit, 1)));
+        assert_eq!(press(&mut input, &keymap, "gj"), Some((Action::Down, 1)));
+    }
+
+    #[test]
+    fn hints_name_the_configured_keys() {
+        // Arrange
+        let overrides: KeyBindings = HashMap::from([(
+            KeyScope::Days,
+            HashMap::from([(Action::Rsvp, vec!["R".to_owned()])]),
+        )]);
+        let keymap = Keymap::new(&overrides).unwrap();
+        let hints: &[(&[Action], &str)] = &[
+            (&[Action::Up, Action::Down], "scroll"),
+            (&[Action::Rsvp], "reply"),
+            (&[Action::ReplyYes], "yes"),
+            (&[Action::Back], "close"),
+        ];
+
+        // Act
+        let days = keymap.hint(KeyScope::Days, hints);
+        let month = keymap.hint(KeyScope::Month, hints);
+
+        // Assert
+        assert_eq!(days, " k/j scroll · R reply · <Esc> close ");
+        assert_eq!(month, " k/j scroll · r reply · <Esc> close ");
+        assert_eq!(
+            keymap.hint(KeyScope::Days, &[(&[Action::ReplyYes], "yes")]),
+            ""
+        );
+    }
+
+    #[test]
+    fn config_replaces_default_keys_and_rejects_conflicts() {
+        // Arrange
+        let overrides: KeyBindings = HashMap::from([(
+            KeyScope::Month,
+            HashMap::from([(Action::Down, vec!["<C-n>".to_owned()])]),
+        )]);
+        let conflicting: KeyBindings = HashMap::from([(
+            KeyScope::Global,
+            HashMap::from([(Action::Edit, vec!["g".to_owned()])]),
+        )]);
+
+        // Act
+        let keymap = Keymap::new(&overrides).unwrap();
+        let conflict = Keymap::new(&conflicting);
+        let global_conflict = Keymap::new(&HashMap::from([(
+            KeyScope::Global,
+            HashMap::from([(Action::Down, vec!["d".to_owned()])]),
+        )]));
+
+        // Assert
+        let month_down = keymap
+            .bindings(KeyScope::Month)
+            .into_iter()
+            .filter(|binding| binding.action == Action::Down)
+            .map(|binding| format_keys(&binding.keys))
+            .collect::<Vec<_>>();
+        assert_eq!(month_down, vec!["<C-n>"]);
+        assert_eq!(
+            conflict.unwrap_err().to_string(),
+            "\"g\" for edit and \"gg\" for first conflict in [keys.global]"
+        );
+        assert_eq!(
+            global_conflict.unwrap_err().to_string(),
+            "\"d\" for delete in [keys.month] and \"d\" for down in [keys.global] conflict"
+        );
+    }
+}
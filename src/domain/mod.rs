@@ -1,2 +1,9 @@
 pub mod calendar;
+pub mod colour;
+pub mod config;
 pub mod events;
+pub mod keymap;
+pub mod recurrence;
+pub mod theme;
+pub mod timezone;
+pub mod working_hours;
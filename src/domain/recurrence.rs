@@ -0,0 +1,836 @@
+use chrono::{Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use chrono_tz::Tz;
+use thiserror::Error;
+
+// Stop expanding a rule after this many periods, protects against rules which
+// never produce an occurrence
+const MAX_PERIODS: u32 = 10_000;
+
+/// The recurrence of an event series, parsed from the RRULE, EXDATE and RDATE
+/// lines Google returns in `recurrence`
+// https://datatracker.ietf.org/doc/html/rfc5545#section-3.8.5
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    /// Start of the first instance of the series
+    pub start: NaiveDateTime,
+    /// Time zone the series repeats in, all dates are wall clock times in this zone
+    pub time_zone: Tz,
+    pub rules: Vec<RecurrenceRule>,
+    pub exception_dates: Vec<NaiveDateTime>,
+    pub extra_dates: Vec<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDateTime>,
+    pub by_day: Vec<WeekdayNum>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub week_start: Weekday,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A BYDAY entry, e.g. "TU" for every Tuesday or "-1FR" for the last Friday
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayNum {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RecurrenceError {
+    #[error("Unrecognised recurrence line: {0}")]
+    InvalidLine(String),
+    #[error("Unsupported recurrence frequency: {0}")]
+    UnsupportedFrequency(String),
+    #[error("Invalid recurrence rule part: {0}")]
+    InvalidRulePart(String),
+    #[error("Invalid recurrence date: {0}")]
+    InvalidDate(String),
+}
+
+impl Recurrence {
+    /// Parse the recurrence of a series starting at the given wall clock time,
+    /// UTC dates in the lines are converted into the series' time zone
+    pub fn parse(
+        lines: &[String],
+        start: NaiveDateTime,
+        time_zone: Tz,
+    ) -> Result<Self, RecurrenceError> {
+        let mut recurrence = Self {
+            start,
+            time_zone,
+            rules: vec![],
+            exception_dates: vec![],
+            extra_dates: vec![],
+        };
+
+        for line in lines {
+            let (name, value) = line
+                .split_once(':')
+                .ok_or(RecurrenceError::InvalidLine(line.to_string()))?;
+
+            // Strip any parameters such as "EXDATE;TZID=Europe/London"
+            let name = name.split(';').next().unwrap_or(name);
+
+            match name {
+                "RRULE" => recurrence
+                    .rules
+                    .push(RecurrenceRule::parse(value, time_zone)?),
+                "EXDATE" => recurrence
+                    .exception_dates
+                    .append(&mut parse_date_list(value, time_zone)?),
+                "RDATE" => recurrence
+                    .extra_dates
+                    .append(&mut parse_date_list(value, time_zone)?),
+                _ => return Err(RecurrenceError::InvalidLine(line.to_string())),
+            }
+        }
+
+        Ok(recurrence)
+    }
+
+    /// Start of every instance beginning within from..until
+    pub fn occurrences_between(
+        &self,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> Vec<NaiveDateTime> {
+        self.occurrences_before(until)
+            .into_iter()
+            .filter(|occurrence| *occurrence >= from)
+            .collect()
+    }
+
+    /// 1-based position of the instance starting at the given time within the series
+    pub fn instance_number(&self, instance_start: NaiveDateTime) -> Option<usize> {
+        self.occurrences_before(instance_start + Duration::seconds(1))
+            .iter()
+            .position(|occurrence| *occurrence == instance_start)
+            .map(|i| i + 1)
+    }
+
+    /// Number of instances in the series, or None if it repeats forever
+    pub fn total_instances(&self) -> Option<usize> {
+        let is_finite = self
+            .rules
+            .iter()
+            .all(|rule| rule.count.is_some() || rule.until.is_some());
+
+        is_finite.then(|| self.occurrences_before(NaiveDateTime::MAX).len())
+    }
+
+    /// Human readable summary of the series, e.g. "weekly on Tue, 12 of 20"
+    pub fn series_summary(&self, instance_start: NaiveDateTime) -> String {
+        let description = self.describe();
+
+        match (self.instance_number(instance_start), self.total_instances()) {
+            (Some(number), Some(total)) => format!("{}, {} of {}", description, number, total),
+            (Some(number), None) => format!("{}, #{}", description, number),
+            _ => description,
+        }
+    }
+
+    /// Human readable description of how often the series repeats, e.g. "every 2 weeks on Mon, Wed"
+    pub fn describe(&self) -> String {
+        let Some(rule) = self.rules.first() else {
+            return "custom dates".to_string();
+        };
+
+        rule.describe(self.start)
+    }
+
+    fn occurrences_before(&self, until: NaiveDateTime) -> Vec<NaiveDateTime> {
+        let mut occurrences: Vec<NaiveDateTime> = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.occurrences(self.start, until))
+            .chain(
+                self.extra_dates
+                    .iter()
+                    .copied()
+                    .filter(|date| *date < until),
+            )
+            .collect();
+
+        // A series always includes its first instance
+        if self.start < until {
+            occurrences.push(self.start);
+        }
+
+        occurrences.retain(|occurrence| !self.is_excluded(*occurrence));
+        occurrences.sort();
+        occurrences.dedup();
+
+        occurrences
+    }
+
+    fn is_excluded(&self, occurrence: NaiveDateTime) -> bool {
+        self.exception_dates.iter().any(|exception| {
+            // Date only exceptions remove every instance on that day
+            *exception == occurrence
+                || (exception.time() == NaiveTime::MIN && exception.date() == occurrence.date())
+        })
+    }
+}
+
+impl RecurrenceRule {
+    /// Parse the value of an RRULE line, e.g. "FREQ=WEEKLY;BYDAY=TU;COUNT=20"
+    pub fn parse(value: &str, time_zone: Tz) -> Result<Self, RecurrenceError> {
+        let mut frequency = None;
+        let mut rule = Self {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: vec![],
+            by_month_day: vec![],
+            by_month: vec![],
+            week_start: Weekday::Mon,
+        };
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or(RecurrenceError::InvalidRulePart(part.to_string()))?;
+            let invalid_part = || RecurrenceError::InvalidRulePart(part.to_string());
+
+            match key {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(RecurrenceError::UnsupportedFrequency(value.to_string())),
+                    })
+                }
+                "INTERVAL" => rule.interval = value.parse().map_err(|_| invalid_part())?,
+                "COUNT" => rule.count = Some(value.parse().map_err(|_| invalid_part())?),
+                "UNTIL" => rule.until = Some(parse_date(value, time_zone)?),
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(parse_weekday_num)
+                        .collect::<Option<_>>()
+                        .ok_or_else(invalid_part)?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = value
+                        .split(',')
+                        .map(|day| day.parse().ok())
+                        .collect::<Option<_>>()
+                        .ok_or_else(invalid_part)?
+                }
+                "BYMONTH" => {
+                    rule.by_month = value
+                        .split(',')
+                        .map(|month| month.parse().ok())
+                        .collect::<Option<_>>()
+                        .ok_or_else(invalid_part)?
+                }
+                "WKST" => rule.week_start = parse_weekday(value).ok_or_else(invalid_part)?,
+                // Less common parts such as BYSETPOS aren't supported yet and are ignored
+                _ => {}
+            }
+        }
+
+        rule.frequency = frequency.ok_or(RecurrenceError::InvalidRulePart(value.to_string()))?;
+        if rule.interval == 0 {
+            return Err(RecurrenceError::InvalidRulePart(value.to_string()));
+        }
+
+        Ok(rule)
+    }
+
+    fn occurrences(&self, start: NaiveDateTime, until: NaiveDateTime) -> Vec<NaiveDateTime> {
+        // The series start is always the first instance and counts towards COUNT,
+        // even when it doesn't match the rule (RFC 5545 3.8.5.3)
+        let mut occurrences = vec![];
+        if start < until {
+            occurrences.push(start);
+        }
+        let mut emitted = 1;
+
+        for period in 0..MAX_PERIODS {
+            let Some((period_start, mut candidates)) = self.period_candidates(start, period) else {
+                break;
+            };
+
+            // Every later period starts after the requested range so there is nothing left to find
+            if period_start.and_time(NaiveTime::MIN) >= until {
+                break;
+            }
+
+            candidates.sort();
+            for date in candidates {
+                let candidate = date.and_time(start.time());
+                if candidate <= start {
+                    continue;
+                }
+                if self.until.is_some_and(|rule_until| candidate > rule_until)
+                    || self.count.is_some_and(|count| emitted >= count)
+                {
+                    return occurrences;
+                }
+
+                emitted += 1;
+                if candidate < until {
+                    occurrences.push(candidate);
+                }
+            }
+        }
+
+        occurrences
+    }
+
+    /// The first day of the nth period of the rule, along with each candidate day inside it
+    fn period_candidates(
+        &self,
+        start: NaiveDateTime,
+        period: u32,
+    ) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let start_date = start.date();
+        let step = self.interval.checked_mul(period)?;
+
+        match self.frequency {
+            Frequency::Daily => {
+                let day = start_date.checked_add_days(Days::new(step.into()))?;
+                let candidates = [day]
+                    .into_iter()
+                    .filter(|day| self.matches_month(day) && self.matches_weekday(day))
+                    .filter(|day| self.by_month_day.is_empty() || self.matches_month_day(day))
+                    .collect();
+
+                Some((day, candidates))
+            }
+            Frequency::Weekly => {
+                let days_into_week = start_date.weekday().days_since(self.week_start);
+                let week_start = start_date - Days::new(days_into_week.into());
+                let period_start = week_start.checked_add_days(Days::new(u64::from(step) * 7))?;
+
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![start_date.weekday()]
+                } else {
+                    self.by_day.iter().map(|day| day.weekday).collect()
+                };
+                let candidates = weekdays
+                    .into_iter()
+                    .filter_map(|weekday| {
+                        period_start
+                            .checked_add_days(Days::new(weekday.days_since(self.week_start).into()))
+                    })
+                    .filter(|day| self.matches_month(day))
+                    .collect();
+
+                Some((period_start, candidates))
+            }
+            Frequency::Monthly => {
+                let month_start = start_date
+                    .with_day(1)?
+                    .checked_add_months(Months::new(step))?;
+                let candidates = if self.matches_month(&month_start) {
+                    self.month_candidates(month_start, start_date.day())
+                } else {
+                    vec![]
+                };
+
+                Some((month_start, candidates))
+            }
+            Frequency::Yearly => {
+                let year_start = NaiveDate::from_ymd_opt(start_date.year(), 1, 1)?
+                    .checked_add_months(Months::new(step.checked_mul(12)?))?;
+                let months = if self.by_month.is_empty() {
+                    vec![start_date.month()]
+                } else {
+                    self.by_month.clone()
+                };
+                let candidates = months
+                    .into_iter()
+                    .filter_map(|month| year_start.with_month(month))
+                    .flat_map(|month_start| self.month_candidates(month_start, start_date.day()))
+                    .collect();
+
+                Some((year_start, candidates))
+            }
+        }
+    }
+
+    fn month_candidates(&self, month_start: NaiveDate, default_day: u32) -> Vec<NaiveDate> {
+        let days_in_month: Vec<NaiveDate> = month_start
+            .iter_days()
+            .take_while(|day| day.month() == month_start.month())
+            .collect();
+
+        if !self.by_month_day.is_empty() {
+            return days_in_month
+                .into_iter()
+                .filter(|day| self.matches_month_day(day))
+                .filter(|day| self.by_day.is_empty() || self.matches_weekday(day))
+                .collect();
+        }
+
+        if !self.by_day.is_empty() {
+            return self
+                .by_day
+                .iter()
+                .flat_map(|by_day| {
+                    let matching: Vec<NaiveDate> = days_in_month
+                        .iter()
+                        .copied()
+                        .filter(|day| day.weekday() == by_day.weekday)
+                        .collect();
+
+                    match by_day.ordinal {
+                        None => matching,
+                        Some(n) if n > 0 => {
+                            matching.get(n as usize - 1).copied().into_iter().collect()
+                        }
+                        Some(n) => matching
+                            .len()
+                            .checked_sub(n.unsigned_abs() as usize)
+                            .and_then(|i| matching.get(i).copied())
+                            .into_iter()
+                            .collect(),
+                    }
+                })
+                .collect();
+        }
+
+        month_start.with_day(default_day).into_iter().collect()
+    }
+
+    fn matches_month(&self, day: &NaiveDate) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&day.month())
+    }
+
+    fn matches_weekday(&self, day: &NaiveDate) -> bool {
+        self.by_day.is_empty()
+            || self
+                .by_day
+                .iter()
+                .any(|by_day| by_day.weekday == day.weekday())
+    }
+
+    fn matches_month_day(&self, day: &NaiveDate) -> bool {
+        let days_in_month = day
+            .with_day(1)
+            .map(|month_start| {
+                month_start
+                    .iter_days()
+                    .take_while(|later| later.month() == day.month())
+                    .count()
+            })
+            .unwrap_or(31) as i32;
+        let day_of_month = day.day() as i32;
+        // Negative days count back from the end of the month, -1 being the last day
+        let from_end = day_of_month - days_in_month - 1;
+
+        self.by_month_day
+            .iter()
+            .any(|month_day| *month_day == day_of_month || *month_day == from_end)
+    }
+
+    fn describe(&self, start: NaiveDateTime) -> String {
+        let (unit, plural) = match self.frequency {
+            Frequency::Daily => ("daily", "days"),
+            Frequency::Weekly => ("weekly", "weeks"),
+            Frequency::Monthly => ("monthly", "months"),
+            Frequency::Yearly => ("yearly", "years"),
+        };
+
+        let repeats = if self.interval == 1 {
+            unit.to_string()
+        } else {
+            format!("every {} {}", self.interval, plural)
+        };
+
+        let days = self
+            .by_day
+            .iter()
+            .map(|day| match day.ordinal {
+                Some(n) => format!("{} {}", ordinal_name(n), day.weekday),
+                None => day.weekday.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        match self.frequency {
+            Frequency::Weekly if days.is_empty() => format!("{} on {}", repeats, start.weekday()),
+            Frequency::Monthly if days.is_empty() && self.by_month_day.is_empty() => {
+                format!("{} on the {}", repeats, ordinal_name(start.day() as i32))
+            }
+            _ if !days.is_empty() => format!("{} on {}", repeats, days),
+            _ => repeats,
+        }
+    }
+}
+
+fn ordinal_name(n: i32) -> String {
+    if n == -1 {
+        return "last".to_string();
+    }
+
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", n, suffix)
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_weekday_num(value: &str) -> Option<WeekdayNum> {
+    // Splitting on bytes needs every character to be one byte
+    if !value.is_ascii() {
+        return None;
+    }
+    let split_at = value.len().checked_sub(2)?;
+    let (ordinal, weekday) = value.split_at(split_at);
+
+    let ordinal = match ordinal {
+        "" => None,
+        ordinal => Some(ordinal.trim_start_matches('+').parse().ok()?),
+    };
+
+    Some(WeekdayNum {
+        ordinal,
+        weekday: parse_weekday(weekday)?,
+    })
+}
+
+fn parse_date_list(value: &str, time_zone: Tz) -> Result<Vec<NaiveDateTime>, RecurrenceError> {
+    value
+        .split(',')
+        .map(|date| parse_date(date, time_zone))
+        .collect()
+}
+
+/// Parse an iCalendar date or date-time, e.g. "20240604", "20240604T090000"
+/// or "20240604T090000Z", UTC date-times are converted to the given time zone
+fn parse_date(value: &str, time_zone: Tz) -> Result<NaiveDateTime, RecurrenceError> {
+    let invalid_date = || RecurrenceError::InvalidDate(value.to_string());
+
+    if let Some(utc_value) = value.strip_suffix('Z') {
+        NaiveDateTime::parse_from_str(utc_value, "%Y%m%dT%H%M%S")
+            .map(|date_time| date_time.and_utc().with_timezone(&time_zone).naive_local())
+            .map_err(|_| invalid_date())
+    } else if value.contains('T') {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid_date())
+    } else {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map(|date| date.and_time(NaiveTime::MIN))
+            .map_err(|_| invalid_date())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use chrono_tz::{Europe::London, UTC};
+
+    use super::{Recurrence, RecurrenceError};
+
+    fn datetime(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn recurrence(lines: &[&str], start: &str) -> Recurrence {
+        let lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        Recurrence::parse(&lines, datetime(start), UTC).unwrap()
+    }
+
+    #[test]
+    fn expands_weekly_rule_with_count() {
+        // 4 June 2024 is a Tuesday
+        let recurrence = recurrence(&["RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=3"], "2024-06-04 09:00");
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-01-01 00:00"), datetime("2025-01-01 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-06-04 09:00"),
+                datetime("2024-06-11 09:00"),
+                datetime("2024-06-18 09:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_only_within_requested_range() {
+        let recurrence = recurrence(&["RRULE:FREQ=DAILY"], "2024-06-01 12:00");
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-06-10 00:00"), datetime("2024-06-12 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![datetime("2024-06-10 12:00"), datetime("2024-06-11 12:00")]
+        );
+    }
+
+    #[test]
+    fn expands_multiple_weekdays_with_interval() {
+        let recurrence = recurrence(
+            &["RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20240620T000000Z"],
+            "2024-06-03 10:00",
+        );
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-06-01 00:00"), datetime("2024-07-01 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-06-03 10:00"),
+                datetime("2024-06-05 10:00"),
+                datetime("2024-06-17 10:00"),
+                datetime("2024-06-19 10:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_monthly_nth_weekday() {
+        // Second Tuesday and last Friday of each month
+        let recurrence = recurrence(
+            &["RRULE:FREQ=MONTHLY;BYDAY=2TU,-1FR;COUNT=4"],
+            "2024-06-11 15:00",
+        );
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-06-01 00:00"), datetime("2025-01-01 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-06-11 15:00"),
+                datetime("2024-06-28 15:00"),
+                datetime("2024-07-09 15:00"),
+                datetime("2024-07-26 15:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_rule_skips_months_without_the_day() {
+        let recurrence = recurrence(&["RRULE:FREQ=MONTHLY;COUNT=3"], "2024-01-31 09:00");
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-01-01 00:00"), datetime("2025-01-01 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-01-31 09:00"),
+                datetime("2024-03-31 09:00"),
+                datetime("2024-05-31 09:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_days_counted_from_the_end_of_the_month() {
+        let last_day = recurrence(
+            &["RRULE:FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=5"],
+            "2023-01-31 09:00",
+        );
+        let second_to_last = recurrence(
+            &["RRULE:FREQ=MONTHLY;BYMONTHDAY=-2;COUNT=4"],
+            "2024-01-30 09:00",
+        );
+        let range = (datetime("2023-01-01 00:00"), datetime("2025-01-01 00:00"));
+
+        // 2023 has a 28 day February, 2024 a 29 day one, and April has 30 days
+        assert_eq!(
+            last_day.occurrences_between(range.0, range.1),
+            vec![
+                datetime("2023-01-31 09:00"),
+                datetime("2023-02-28 09:00"),
+                datetime("2023-03-31 09:00"),
+                datetime("2023-04-30 09:00"),
+                datetime("2023-05-31 09:00"),
+            ]
+        );
+        assert_eq!(
+            second_to_last.occurrences_between(range.0, range.1),
+            vec![
+                datetime("2024-01-30 09:00"),
+                datetime("2024-02-28 09:00"),
+                datetime("2024-03-30 09:00"),
+                datetime("2024-04-29 09:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn series_start_counts_towards_count_when_it_does_not_match() {
+        // 3 June 2024 is a Monday, and 31 January isn't the last day of February
+        let weekly = recurrence(&["RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=2"], "2024-06-03 09:00");
+        let monthly = recurrence(
+            &["RRULE:FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3"],
+            "2024-01-15 09:00",
+        );
+        let range = (datetime("2024-01-01 00:00"), datetime("2025-01-01 00:00"));
+
+        assert_eq!(
+            weekly.occurrences_between(range.0, range.1),
+            vec![datetime("2024-06-03 09:00"), datetime("2024-06-04 09:00")]
+        );
+        assert_eq!(weekly.total_instances(), Some(2));
+        assert_eq!(
+            monthly.occurrences_between(range.0, range.1),
+            vec![
+                datetime("2024-01-15 09:00"),
+                datetime("2024-01-31 09:00"),
+                datetime("2024-02-29 09:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn yearly_rule_repeats_on_the_same_date() {
+        let recurrence = recurrence(&["RRULE:FREQ=YEARLY"], "2020-06-03 00:00");
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-01-01 00:00"), datetime("2025-01-01 00:00"));
+
+        assert_eq!(occurrences, vec![datetime("2024-06-03 00:00")]);
+    }
+
+    #[test]
+    fn applies_exception_and_extra_dates() {
+        let recurrence = recurrence(
+            &[
+                "RRULE:FREQ=DAILY;COUNT=3",
+                "EXDATE;TZID=Europe/London:20240602T090000",
+                "RDATE;VALUE=DATE-TIME:20240610T090000Z",
+            ],
+            "2024-06-01 09:00",
+        );
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-06-01 00:00"), datetime("2024-07-01 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-06-01 09:00"),
+                datetime("2024-06-03 09:00"),
+                datetime("2024-06-10 09:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_utc_dates_into_series_time_zone() {
+        let lines = vec![
+            "RRULE:FREQ=DAILY;UNTIL=20240604T080000Z".to_string(),
+            "EXDATE:20240602T080000Z".to_string(),
+        ];
+
+        let recurrence = Recurrence::parse(&lines, datetime("2024-06-01 09:00"), London).unwrap();
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-06-01 00:00"), datetime("2024-07-01 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-06-01 09:00"),
+                datetime("2024-06-03 09:00"),
+                datetime("2024-06-04 09:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn date_only_exceptions_apply_to_all_day_series() {
+        let recurrence = recurrence(
+            &["RRULE:FREQ=WEEKLY;BYDAY=MO", "EXDATE;VALUE=DATE:20240610"],
+            "2024-06-03 00:00",
+        );
+
+        let occurrences = recurrence
+            .occurrences_between(datetime("2024-06-01 00:00"), datetime("2024-06-20 00:00"));
+
+        assert_eq!(
+            occurrences,
+            vec![datetime("2024-06-03 00:00"), datetime("2024-06-17 00:00")]
+        );
+    }
+
+    #[test]
+    fn summarises_position_in_series() {
+        let recurrence = recurrence(&["RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=20"], "2024-06-04 09:00");
+        let twelfth = NaiveDate::from_ymd_opt(2024, 8, 20)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+
+        assert_eq!(
+            recurrence.series_summary(twelfth),
+            "weekly on Tue, 12 of 20"
+        );
+    }
+
+    #[test]
+    fn summarises_endless_series() {
+        let recurrence = recurrence(&["RRULE:FREQ=MONTHLY;INTERVAL=2"], "2024-06-03 09:00");
+
+        assert_eq!(
+            recurrence.series_summary(datetime("2024-08-03 09:00")),
+            "every 2 months on the 3rd, #2"
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_rules() {
+        let lines = vec!["RRULE:FREQ=SECONDLY".to_string()];
+
+        let result = Recurrence::parse(&lines, datetime("2024-06-03 09:00"), UTC);
+
+        assert_eq!(
+            result,
+            Err(RecurrenceError::UnsupportedFrequency("SECONDLY".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_weekdays_that_are_not_ascii() {
+        let lines = vec!["RRULE:FREQ=WEEKLY;BYDAY=1€".to_string()];
+
+        let result = Recurrence::parse(&lines, datetime("2024-06-03 09:00"), UTC);
+
+        assert_eq!(
+            result,
+            Err(RecurrenceError::InvalidRulePart("BYDAY=1€".into()))
+        );
+    }
+}
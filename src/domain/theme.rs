@@ -0,0 +1,381 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use ratatui::style::{palette::tailwind, Color, Modifier, Style};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Themes given in the config file by name, each changing some of the colours
+/// of a built-in theme
+pub type CustomThemes = BTreeMap<String, CustomTheme>;
+
+/// Colours used across the app, named by what they're for rather than what
+/// they look like
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    /// Behind the calendar
+    pub background: Color,
+    /// Behind lists, like the day's events or invitations
+    pub panel: Color,
+    /// Behind popups shown over a view, like event details and forms
+    pub popup: Color,
+    pub text: Color,
+    /// Labels, hints and anything less important than the text next to it
+    pub muted: Color,
+    /// Behind the selected item of a list or field of a form
+    pub selected: Color,
+    /// Today's date
+    pub today: Color,
+    /// Marks events of calendars without a colour of their own
+    pub event: Color,
+    /// Marks all day events of calendars without a colour of their own
+    pub all_day: Color,
+    /// Keys and other things to pick out
+    pub accent: Color,
+    pub error: Color,
+    /// Replies still to be made and tentative replies
+    pub warning: Color,
+    pub success: Color,
+    /// Behind reminders
+    pub reminder: Color,
+    /// Whether to tell things apart with bold, underlined, dim and reversed
+    /// text and plain ASCII markers, rather than colours
+    pub monochrome: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// A theme from the config file, any colour left out is the same as in the
+/// theme it extends
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CustomTheme {
+    /// Name of the built-in theme to start from, light unless given
+    pub extends: Option<String>,
+    /// Colours by slot, e.g. `background = "#1e1e2e"`, `text = "white"` or
+    /// `muted = "244"`
+    #[serde(flatten)]
+    pub colours: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ThemeError {
+    #[error("there's no theme called \"{0}\"")]
+    UnknownTheme(String),
+    #[error("\"{slot}\" in [themes.{theme}] is not a theme colour")]
+    UnknownSlot { theme: String, slot: String },
+    #[error("\"{colour}\" for {slot} in [themes.{theme}] is not a colour, use e.g. \"red\", \"#ff0000\" or \"196\"")]
+    InvalidColour {
+        theme: String,
+        slot: String,
+        colour: String,
+    },
+}
+
+impl Theme {
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_owned(),
+            background: tailwind::STONE.c200,
+            panel: tailwind::STONE.c100,
+            popup: tailwind::STONE.c50,
+            text: tailwind::STONE.c800,
+            muted: tailwind::STONE.c500,
+            selected: tailwind::STONE.c300,
+            today: tailwind::RED.c500,
+            event: tailwind::SKY.c500,
+            all_day: tailwind::EMERALD.c500,
+            accent: tailwind::SKY.c700,
+            error: tailwind::RED.c600,
+            warning: tailwind::AMBER.c600,
+            success: tailwind::GREEN.c600,
+            reminder: tailwind::AMBER.c100,
+            monochrome: false,
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_owned(),
+            background: tailwind::STONE.c950,
+            panel: tailwind::STONE.c900,
+            popup: tailwind::STONE.c800,
+            text: tailwind::STONE.c100,
+            muted: tailwind::STONE.c400,
+            selected: tailwind::STONE.c700,
+            today: tailwind::RED.c400,
+            event: tailwind::SKY.c400,
+            all_day: tailwind::EMERALD.c400,
+            accent: tailwind::SKY.c300,
+            error: tailwind::RED.c400,
+            warning: tailwind::AMBER.c400,
+            success: tailwind::GREEN.c400,
+            reminder: tailwind::AMBER.c900,
+            monochrome: false,
+        }
+    }
+
+    /// Keeps the terminal's own background and text colours, using only the
+    /// basic colours every terminal palette defines
+    pub fn terminal() -> Self {
+        Self {
+            name: "terminal".to_owned(),
+            background: Color::Reset,
+            panel: Color::Reset,
+            popup: Color::Reset,
+            text: Color::Reset,
+            muted: Color::DarkGray,
+            selected: Color::DarkGray,
+            today: Color::Red,
+            event: Color::Blue,
+            all_day: Color::Green,
+            accent: Color::Cyan,
+            error: Color::Red,
+            warning: Color::Yellow,
+            success: Color::Green,
+            reminder: Color::Reset,
+            monochrome: false,
+        }
+    }
+
+    /// No colours at all, for `NO_COLOR`, colour blindness and recordings
+    pub fn monochrome() -> Self {
+        Self {
+            name: "monochrome".to_owned(),
+            background: Color::Reset,
+            panel: Color::Reset,
+            popup: Color::Reset,
+            text: Color::Reset,
+            muted: Color::Reset,
+            selected: Color::Reset,
+            today: Color::Reset,
+            event: Color::Reset,
+            all_day: Color::Reset,
+            accent: Color::Reset,
+            error: Color::Reset,
+            warning: Color::Reset,
+            success: Color::Reset,
+            reminder: Color::Reset,
+            monochrome: true,
+        }
+    }
+
+    fn built_in() -> Vec<Self> {
+        vec![Self::light(), Self::dark(), Self::terminal()]
+    }
+
+    /// The built-in themes followed by the custom ones, a custom theme with the
+    /// name of a built-in one takes its place
+    pub fn all(custom_themes: &CustomThemes) -> Result<Vec<Self>, ThemeError> {
+        let mut themes = Self::built_in();
+
+        for (name, custom) in custom_themes {
+            let extends = custom.extends.as_deref().unwrap_or("light");
+            let mut theme = Self::built_in()
+                .into_iter()
+                .find(|theme| theme.name == extends)
+                .ok_or_else(|| ThemeError::UnknownTheme(extends.to_owned()))?;
+            theme.name = name.to_owned();
+
+            for (slot, colour) in &custom.colours {
+                theme.set_colour(slot, colour)?;
+            }
+
+            match themes.iter_mut().find(|existing| existing.name == *name) {
+                Some(existing) => *existing = theme,
+                None => themes.push(theme),
+            }
+        }
+
+        Ok(themes)
+    }
+
+    pub fn muted_style(&self) -> Style {
+        self.colour_or(self.muted, Modifier::DIM)
+    }
+
+    pub fn selected_style(&self) -> Style {
+        if self.monochrome {
+            Style::new().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::new().bg(self.selected)
+        }
+    }
+
+    pub fn today_style(&self) -> Style {
+        self.colour_or(self.today, Modifier::UNDERLINED)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    pub fn accent_style(&self) -> Style {
+        self.colour_or(self.accent, Modifier::BOLD)
+    }
+
+    pub fn error_style(&self) -> Style {
+        self.colour_or(self.error, Modifier::BOLD)
+    }
+
+    pub fn warning_style(&self) -> Style {
+        self.colour_or(self.warning, Modifier::BOLD)
+    }
+
+    pub fn success_style(&self) -> Style {
+        self.colour_or(self.success, Modifier::BOLD)
+    }
+
+    /// Bold marker in the calendar's colour, or the fallback colour for
+    /// calendars without one
+    pub fn marker_style(&self, colour: Option<Color>, fallback: Color) -> Style {
+        self.colour_or(colour.unwrap_or(fallback), Modifier::empty())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// The symbol, or the ASCII one in monochrome where a symbol's colour
+    /// might have been the only thing telling it apart
+    pub fn symbol<'a>(&self, symbol: &'a str, ascii: &'a str) -> &'a str {
+        if self.monochrome {
+            ascii
+        } else {
+            symbol
+        }
+    }
+
+    fn colour_or(&self, colour: Color, modifier: Modifier) -> Style {
+        if self.monochrome {
+            Style::new().add_modifier(modifier)
+        } else {
+            Style::new().fg(colour)
+        }
+    }
+
+    fn set_colour(&mut self, slot: &str, colour: &str) -> Result<(), ThemeError> {
+        let parsed = Color::from_str(colour).map_err(|_| ThemeError::InvalidColour {
+            theme: self.name.to_owned(),
+            slot: slot.to_owned(),
+            colour: colour.to_owned(),
+        })?;
+
+        let target = match slot {
+            "background" => &mut self.background,
+            "panel" => &mut self.panel,
+            "popup" => &mut self.popup,
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "selected" => &mut self.selected,
+            "today" => &mut self.today,
+            "event" => &mut self.event,
+            "all_day" => &mut self.all_day,
+            "accent" => &mut self.accent,
+            "error" => &mut self.error,
+            "warning" => &mut self.warning,
+            "success" => &mut self.success,
+            "reminder" => &mut self.reminder,
+            _ => {
+                return Err(ThemeError::UnknownSlot {
+                    theme: self.name.to_owned(),
+                    slot: slot.to_owned(),
+                })
+            }
+        };
+        *target = parsed;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use ratatui::style::{Color, Style, Stylize};
+
+    use super::{CustomTheme, Theme, ThemeError};
+
+    fn custom_theme(extends: Option<&str>, colours: &[(&str, &str)]) -> CustomTheme {
+        CustomTheme {
+            extends: extends.map(str::to_owned),
+            colours: colours
+                .iter()
+                .map(|(slot, colour)| (slot.to_string(), colour.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn custom_themes_change_the_theme_they_extend() {
+        // Arrange
+        let custom_themes = BTreeMap::from([
+            (
+                "mocha".to_owned(),
+                custom_theme(
+                    Some("dark"),
+                    &[("background", "#1e1e2e"), ("today", "magenta")],
+                ),
+            ),
+            ("light".to_owned(), custom_theme(None, &[("muted", "244")])),
+        ]);
+
+        // Act
+        let themes = Theme::all(&custom_themes).unwrap();
+
+        // Assert
+        let names: Vec<_> = themes.iter().map(|theme| theme.name.as_str()).collect();
+        assert_eq!(names, vec!["light", "dark", "terminal", "mocha"]);
+
+        let mocha = &themes[3];
+        assert_eq!(mocha.background, Color::Rgb(0x1e, 0x1e, 0x2e));
+        assert_eq!(mocha.today, Color::Magenta);
+        assert_eq!(mocha.text, Theme::dark().text);
+
+        assert_eq!(themes[0].muted, Color::Indexed(244));
+        assert_eq!(themes[0].text, Theme::light().text);
+    }
+
+    #[test]
+    fn monochrome_uses_text_styles_and_ascii_in_place_of_colour() {
+        // Arrange
+        let theme = Theme::monochrome();
+        let calendar_colour = Some(Color::Indexed(32));
+
+        // Act/Assert
+        assert_eq!(theme.selected_style(), Style::new().reversed());
+        assert_eq!(theme.today_style(), Style::new().bold().underlined());
+        assert_eq!(theme.muted_style(), Style::new().dim());
+        assert_eq!(
+            theme.marker_style(calendar_colour, theme.event),
+            Style::new().bold()
+        );
+        assert_eq!(theme.symbol("●", "*"), "*");
+
+        let light = Theme::light();
+        assert_eq!(
+            light.marker_style(calendar_colour, light.event),
+            Style::new().fg(Color::Indexed(32)).bold()
+        );
+        assert_eq!(light.symbol("●", "*"), "●");
+    }
+
+    #[test]
+    fn rejects_unknown_slots_colours_and_themes() {
+        let theme = |custom| Theme::all(&BTreeMap::from([("mine".to_owned(), custom)]));
+
+        assert_eq!(
+            theme(custom_theme(None, &[("border", "red")])),
+            Err(ThemeError::UnknownSlot {
+                theme: "mine".to_owned(),
+                slot: "border".to_owned()
+            })
+        );
+        assert!(matches!(
+            theme(custom_theme(None, &[("text", "#12345")])),
+            Err(ThemeError::InvalidColour { .. })
+        ));
+        assert_eq!(
+            theme(custom_theme(Some("mocha"), &[])),
+            Err(ThemeError::UnknownTheme("mocha".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::{
+    DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+use chrono_tz::Tz;
+
+/// Time zone events are displayed in, defaults to the system time zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayTimezone {
+    #[default]
+    Local,
+    Named(Tz),
+}
+
+impl DisplayTimezone {
+    /// Parse an IANA time zone name, e.g. "Europe/London"
+    pub fn from_name(name: &str) -> Option<Self> {
+        name.parse::<Tz>().ok().map(Self::Named)
+    }
+
+    pub fn to_local(&self, time: &DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Self::Local => time.with_timezone(&Local).naive_local(),
+            Self::Named(tz) => time.with_timezone(tz).naive_local(),
+        }
+    }
+
+    pub fn today(&self) -> NaiveDate {
+        self.to_local(&Utc::now()).date()
+    }
+
+    /// Instant the given day begins in this time zone
+    pub fn start_of_day(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.to_utc(&date.and_time(NaiveTime::MIN))
+    }
+
+    /// Instant a wall clock time in this time zone happens at, times skipped by
+    /// daylight savings changes are moved forward past the gap
+    pub fn to_utc(&self, time: &NaiveDateTime) -> DateTime<Utc> {
+        match self {
+            Self::Local => localise(&Local, time),
+            Self::Named(tz) => localise(tz, time),
+        }
+    }
+
+    pub fn format(&self, time: &DateTime<Utc>, format: &str) -> String {
+        self.to_local(time).format(format).to_string()
+    }
+}
+
+/// Instant a wall clock time in the given time zone happens at, times skipped by
+/// daylight savings changes are moved forward past the gap
+pub fn localise<T: TimeZone>(tz: &T, time: &NaiveDateTime) -> DateTime<Utc> {
+    tz.from_local_datetime(time)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+        .unwrap_or_else(|| {
+            // Applying the offset from before the gap lands on the equivalent wall
+            // time after it, e.g. 01:30 becomes 02:30 when clocks go forward at 01:00
+            let offset = tz
+                .offset_from_utc_datetime(&(*time - Duration::days(1)))
+                .fix();
+            (*time - offset).and_utc()
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+
+    use super::DisplayTimezone;
+
+    #[test]
+    fn converts_to_british_summer_time() {
+        let timezone = DisplayTimezone::from_name("Europe/London").unwrap();
+        let meeting = DateTime::parse_from_rfc3339("2024-06-03T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(timezone.format(&meeting, "%R"), "10:00");
+    }
+
+    #[test]
+    fn moves_times_in_daylight_savings_gap_forward() {
+        let timezone = DisplayTimezone::from_name("Europe/London").unwrap();
+        let skipped = NaiveDateTime::parse_from_str("2024-03-31 01:30", "%Y-%m-%d %H:%M").unwrap();
+
+        assert_eq!(
+            timezone.to_utc(&skipped).to_rfc3339(),
+            "2024-03-31T01:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_time_zones() {
+        assert_eq!(DisplayTimezone::from_name("Europe/Nowhere"), None);
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+
+/// Days and hours of the week to look for free time in, defaults to 09:00 to
+/// 17:00 Monday to Friday
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingHours {
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self {
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            start: NaiveTime::from_hms_opt(9, 0, 0).expect("valid time"),
+            end: NaiveTime::from_hms_opt(17, 0, 0).expect("valid time"),
+        }
+    }
+}
+
+impl WorkingHours {
+    /// Parse days and hours, e.g. "mon-fri 09:00-17:00", "mon,wed 10:00-16:00" or
+    /// just "08:30-16:30" for weekdays
+    pub fn from_spec(spec: &str) -> Option<Self> {
+        let (days, hours) = match spec.trim().split_once(' ') {
+            Some((days, hours)) => (parse_days(days.trim())?, hours.trim()),
+            None => (Self::default().days, spec.trim()),
+        };
+
+        let (start, end) = hours.split_once('-')?;
+        let start = NaiveTime::parse_from_str(start, "%H:%M").ok()?;
+        let end = NaiveTime::parse_from_str(end, "%H:%M").ok()?;
+        if end <= start {
+            return None;
+        }
+
+        Some(Self { days, start, end })
+    }
+
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.days.contains(&date.weekday())
+    }
+}
+
+/// Comma separated days or ranges of days, e.g. "mon-fri" or "mon,wed,fri"
+fn parse_days(spec: &str) -> Option<Vec<Weekday>> {
+    let mut days = vec![];
+    for part in spec.split(',') {
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let mut day = first.parse::<Weekday>().ok()?;
+                let last = last.parse::<Weekday>().ok()?;
+                days.push(day);
+                while day != last {
+                    day = day.succ();
+                    days.push(day);
+                }
+            }
+            None => days.push(part.parse().ok()?),
+        }
+    }
+
+    Some(days)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveTime, Weekday};
+
+    use super::WorkingHours;
+
+    #[test]
+    fn parses_days_and_hours() {
+        let working_hours = WorkingHours::from_spec("sat-mon,wed 08:30-12:00").unwrap();
+
+        assert_eq!(
+            working_hours,
+            WorkingHours {
+                days: vec![Weekday::Sat, Weekday::Sun, Weekday::Mon, Weekday::Wed],
+                start: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+                end: NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            }
+        );
+        assert_eq!(
+            WorkingHours::from_spec("10:00-16:00").unwrap().days,
+            WorkingHours::default().days
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_hours() {
+        assert_eq!(WorkingHours::from_spec("17:00-09:00"), None);
+        assert_eq!(WorkingHours::from_spec("someday 09:00-17:00"), None);
+        assert_eq!(WorkingHours::from_spec("9-5"), None);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use reqwest::Response;
+use serde::Deserialize;
+
+use crate::{
+    domain::{calendar::Calendar, colour::Colour},
+    features::oauth_http_client::GoogleOAuthClient,
+};
+
+// https://developers.google.com/calendar/api/v3/reference/colors#resource
+#[derive(Deserialize, Debug)]
+struct ColorsResponse {
+    calendar: HashMap<String, ColorDefinition>,
+    event: HashMap<String, ColorDefinition>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ColorDefinition {
+    background: String,
+}
+
+/// Lookup of Google `colorId`s to the colours they represent
+#[derive(Debug, Default, Clone)]
+pub struct ColourPalette {
+    calendar: HashMap<String, Colour>,
+    event: HashMap<String, Colour>,
+}
+
+impl From<ColorsResponse> for ColourPalette {
+    fn from(response: ColorsResponse) -> Self {
+        let parse_definitions = |definitions: HashMap<String, ColorDefinition>| {
+            definitions
+                .into_iter()
+                .filter_map(|(id, definition)| {
+                    Colour::from_hex(&definition.background).map(|colour| (id, colour))
+                })
+                .collect()
+        };
+
+        Self {
+            calendar: parse_definitions(response.calendar),
+            event: parse_definitions(response.event),
+        }
+    }
+}
+
+impl ColourPalette {
+    /// Resolve a calendar's colour, preferring a local override, then the
+    /// calendar's own background colour, then its `colorId`
+    pub fn calendar_colour(&self, calendar: &Calendar) -> Option<Colour> {
+        calendar
+            .colour_override
+            .as_deref()
+            .and_then(Colour::from_hex)
+            .or_else(|| {
+                calendar
+                    .background_color
+                    .as_deref()
+                    .and_then(Colour::from_hex)
+            })
+            .or_else(|| {
+                calendar
+                    .color_id
+                    .as_ref()
+                    .and_then(|id| self.calendar.get(id).copied())
+            })
+    }
+
+    pub fn event_colour(&self, color_id: &str) -> Option<Colour> {
+        self.event.get(color_id).copied()
+    }
+}
+
+#[tracing::instrument(name = "Fetch colour palette", skip(google_client))]
+pub async fn fetch_colour_palette(
+    account_id: i64,
+    google_client: &GoogleOAuthClient,
+) -> Result<ColourPalette> {
+    let http_client = reqwest::Client::new();
+    let colors_request = http_client.get("https://www.googleapis.com/calendar/v3/colors");
+
+    let response = google_client.send(account_id, colors_request).await?;
+
+    deserialise_colors_response(response).await
+}
+
+pub async fn deserialise_colors_response(response: Response) -> Result<ColourPalette> {
+    let colors: ColorsResponse = response.json().await?;
+
+    Ok(ColourPalette::from(colors))
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::Response;
+
+    use super::{deserialise_colors_response, ColourPalette};
+    use crate::domain::{calendar::Calendar, colour::Colour};
+
+    async fn palette() -> ColourPalette {
+        let raw_json = r##"
+{
+  "kind": "calendar#colors",
+  "updated": "2012-02-14T00:00:00.000Z",
+  "calendar": {
+    "1": { "background": "#ac725e", "foreground": "#1d1d1d" },
+    "2": { "background": "#d06b64", "foreground": "#1d1d1d" }
+  },
+  "event": {
+    "8": { "background": "#e1e1e1", "foreground": "#1d1d1d" }
+  }
+}
+        "##;
+        let response = http::Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(raw_json.to_string())
+            .unwrap();
+
+        deserialise_colors_response(Response::from(response))
+            .await
+            .unwrap()
+    }
+
+    fn calendar(
+        color_id: Option<&str>,
+        background_color: Option<&str>,
+        colour_override: Option<&str>,
+    ) -> Calendar {
+        Calendar {
+            calendar_id: "test@test.com".into(),
+            account_id: 1,
+            title: "Test calendar".into(),
+            color_id: color_id.map(String::from),
+            background_color: background_color.map(String::from),
+            colour_override: colour_override.map(String::from),
+            access_role: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_event_colour_ids() {
+        let palette = palette().await;
+
+        assert_eq!(palette.event_colour("8"), Colour::from_hex("#e1e1e1"));
+        assert_eq!(palette.event_colour("3"), None);
+    }
+
+    #[tokio::test]
+    async fn prefers_override_then_background_then_colour_id() {
+        let palette = palette().await;
+
+        let only_id = calendar(Some("2"), None, None);
+        let with_background = calendar(Some("2"), Some("#9fc6e7"), None);
+        let with_override = calendar(Some("2"), Some("#9fc6e7"), Some("#33b679"));
+
+        assert_eq!(
+            palette.calendar_colour(&only_id),
+            Colour::from_hex("#d06b64")
+        );
+        assert_eq!(
+            palette.calendar_colour(&with_background),
+            Colour::from_hex("#9fc6e7")
+        );
+        assert_eq!(
+            palette.calendar_colour(&with_override),
+            Colour::from_hex("#33b679")
+        );
+    }
+}
@@ -0,0 +1,34 @@
+pub(crate) mod colour_palette;
+pub(crate) mod store_colour_override;
+
+/// Colours a user can pick from when overriding a calendar's colour, these
+/// match the named event colours in the Google calendar web app
+pub const OVERRIDE_COLOURS: [(&str, &str); 11] = [
+    ("Tomato", "#d50000"),
+    ("Flamingo", "#e67c73"),
+    ("Tangerine", "#f4511e"),
+    ("Banana", "#f6bf26"),
+    ("Sage", "#33b679"),
+    ("Basil", "#0b8043"),
+    ("Peacock", "#039be5"),
+    ("Blueberry", "#3f51b5"),
+    ("Lavender", "#7986cb"),
+    ("Grape", "#8e24aa"),
+    ("Graphite", "#616161"),
+];
+
+/// The override following the current one, wrapping back round to no override
+/// once every colour has been tried
+pub fn next_colour_override(current: Option<&str>) -> Option<String> {
+    let current_index = current.and_then(|current| {
+        OVERRIDE_COLOURS
+            .iter()
+            .position(|(_, hex)| hex.eq_ignore_ascii_case(current))
+    });
+
+    match current_index {
+        None => Some(OVERRIDE_COLOURS[0].1.to_string()),
+        Some(i) if i + 1 < OVERRIDE_COLOURS.len() => Some(OVERRIDE_COLOURS[i + 1].1.to_string()),
+        Some(_) => None,
+    }
+}
@@ -0,0 +1,20 @@
+use color_eyre::eyre::Result;
+use eyre::Context;
+use sqlx::SqlitePool;
+
+pub async fn store_colour_override(
+    calendar_id: i64,
+    colour_override: Option<&str>,
+    db: &SqlitePool,
+) -> Result<()> {
+    sqlx::query!(
+        "UPDATE calendars SET colour_override = $1 WHERE id = $2",
+        colour_override,
+        calendar_id
+    )
+    .execute(db)
+    .await
+    .wrap_err("Error while storing calendar colour override")?;
+
+    Ok(())
+}
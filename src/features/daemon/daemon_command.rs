@@ -0,0 +1,140 @@
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+use color_eyre::eyre::{bail, Result};
+
+use crate::{
+    configuration::Application,
+    domain::events::Event,
+    features::fetch_events::{
+        deserialise_event_response::EventList, expand_recurring_events::expand_recurring_events,
+        fetch_events::fetch_events_and_series,
+    },
+};
+
+use super::{
+    event_triggers::{due_triggers, TriggerKind},
+    run_hooks::run_hooks,
+};
+
+/// How often to look for triggers which have happened
+const CHECK_INTERVAL_SECONDS: u64 = 15;
+
+/// Shell commands to run as events come up
+#[derive(Debug)]
+pub struct Hooks {
+    pub before: Vec<String>,
+    pub minutes_before: i64,
+    pub start: Vec<String>,
+    pub end: Vec<String>,
+}
+
+impl Hooks {
+    fn commands(&self, trigger: TriggerKind) -> &[String] {
+        match trigger {
+            TriggerKind::Before => &self.before,
+            TriggerKind::Start => &self.start,
+            TriggerKind::End => &self.end,
+        }
+    }
+}
+
+/// Keep running, fetching events every `sync_interval` and running hooks as
+/// events come up until interrupted. Only triggers which happen while running
+/// are acted on
+pub async fn run_daemon_command(
+    application: &Application,
+    hooks: Hooks,
+    sync_interval: std::time::Duration,
+) -> Result<()> {
+    if hooks.before.is_empty() && hooks.start.is_empty() && hooks.end.is_empty() {
+        bail!("Give at least one command to run with --before, --start or --end");
+    }
+    let display_timezone = application.display_timezone;
+
+    let mut events = vec![];
+    let mut series = vec![];
+    let mut fetched_until = Utc::now();
+    let mut next_sync = Instant::now();
+    let mut checked_until = Utc::now();
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECONDS));
+
+    println!("Watching for events, press ctrl-c to stop");
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            _ = interval.tick() => {}
+        }
+
+        if Instant::now() >= next_sync {
+            // Events still in progress are listed too, so their end isn't missed
+            let now = Utc::now();
+            let (_, fetch_until) = application.config.fetch_window(display_timezone);
+            let fetch_until = fetch_until + Duration::minutes(hooks.minutes_before);
+            match fetch_events_and_series(
+                now,
+                fetch_until,
+                application.db.clone(),
+                application.google_client.clone(),
+            )
+            .await
+            {
+                Ok(fetched) => {
+                    events = fetched.events;
+                    series = fetched.series;
+                }
+                // Keep going with the events we have until the next sync, carrying
+                // on recurring series past the range fetched last time
+                Err(e) => {
+                    tracing::error!("Failed to sync events: {:?}", e);
+                    add_unfetched_instances(&mut events, &series, fetched_until, fetch_until);
+                }
+            }
+            fetched_until = fetch_until.max(fetched_until);
+            next_sync = Instant::now() + sync_interval;
+        }
+
+        let now = Utc::now();
+        let due = due_triggers(
+            &events,
+            checked_until,
+            now,
+            hooks.minutes_before,
+            display_timezone,
+        );
+        for (trigger, _, event) in due {
+            tracing::info!(event.id, trigger = trigger.name(), "Running hooks");
+            run_hooks(
+                hooks.commands(trigger),
+                trigger,
+                event,
+                application.config.clock,
+                display_timezone,
+            );
+        }
+        checked_until = now;
+    }
+}
+
+/// Add instances of the series between the end of the range last fetched and
+/// the end of the range which should have been
+fn add_unfetched_instances(
+    events: &mut Vec<Event>,
+    series: &[EventList],
+    fetched_until: DateTime<Utc>,
+    until: DateTime<Utc>,
+) {
+    if until <= fetched_until {
+        return;
+    }
+
+    let instances: Vec<Event> = series
+        .iter()
+        .cloned()
+        .flat_map(|event_list| expand_recurring_events(event_list, fetched_until, until))
+        .filter(|instance| instance.recurring_event_id.is_some())
+        .filter(|instance| !events.iter().any(|event| event.id == instance.id))
+        .collect();
+    events.extend(instances);
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{
+    events::{Event, ResponseStatus},
+    timezone::DisplayTimezone,
+};
+
+/// Points in an event's life commands can be run at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerKind {
+    /// A configured number of minutes before it starts
+    Before,
+    Start,
+    End,
+}
+
+impl TriggerKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::Start => "start",
+            Self::End => "end",
+        }
+    }
+}
+
+/// Triggers which happened after `since` and up to and including `until`, in
+/// the order they happened. All day events and events we've declined don't
+/// trigger anything
+pub fn due_triggers(
+    events: &[Event],
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    minutes_before: i64,
+    display_timezone: DisplayTimezone,
+) -> Vec<(TriggerKind, DateTime<Utc>, &Event)> {
+    let mut due = events
+        .iter()
+        .filter(|event| !event.time.is_all_day())
+        .filter(|event| event.own_response() != Some(ResponseStatus::Declined))
+        .flat_map(|event| {
+            let start = event.time.start_instant(display_timezone);
+            let end = event.time.end_instant(display_timezone);
+
+            [
+                (
+                    TriggerKind::Before,
+                    start - Duration::minutes(minutes_before),
+                ),
+                (TriggerKind::Start, start),
+                (TriggerKind::End, end),
+            ]
+            .into_iter()
+            .filter(|(_, at)| *at > since && *at <= until)
+            .map(move |(kind, at)| (kind, at, event))
+        })
+        .collect::<Vec<_>>();
+
+    // Ends go first so back to back events leave things set for the next one
+    due.sort_by_key(|(kind, at, _)| (*at, *kind != TriggerKind::End));
+    due
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+    use super::{due_triggers, TriggerKind};
+    use crate::domain::{
+        events::{Attendee, Event, EventTime, ResponseStatus},
+        timezone::DisplayTimezone,
+    };
+
+    fn utc(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn event(id: &str, time: EventTime) -> Event {
+        Event {
+            id: id.into(),
+            title: id.into(),
+            time,
+            ..Event::test_default()
+        }
+    }
+
+    fn timed(id: &str, start: &str, minutes: i64) -> Event {
+        let start = utc(start);
+        event(
+            id,
+            EventTime::Timed {
+                start,
+                end: start + Duration::minutes(minutes),
+            },
+        )
+    }
+
+    #[test]
+    fn finds_triggers_within_the_period() {
+        // Arrange
+        let mut declined = timed("declined", "2024-06-03T10:00:00Z", 30);
+        declined.attendees = vec![Attendee {
+            email: Some("me@test.com".into()),
+            display_name: None,
+            response_status: ResponseStatus::Declined,
+            comment: None,
+            optional: false,
+            organizer: false,
+            resource: false,
+            additional_guests: 0,
+            is_self: true,
+        }];
+        let all_day = event(
+            "all_day",
+            EventTime::AllDay {
+                start: NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 6, 4).unwrap(),
+            },
+        );
+        let events = vec![
+            timed("standup", "2024-06-03T10:00:00Z", 15),
+            timed("earlier", "2024-06-03T09:30:00Z", 30),
+            timed("later", "2024-06-03T11:00:00Z", 30),
+            declined,
+            all_day,
+        ];
+
+        // Act
+        let due = due_triggers(
+            &events,
+            utc("2024-06-03T09:55:00Z"),
+            utc("2024-06-03T10:55:00Z"),
+            5,
+            DisplayTimezone::from_name("UTC").unwrap(),
+        );
+
+        // Assert
+        let due = due
+            .iter()
+            .map(|(kind, at, event)| (event.id.as_str(), *kind, *at))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            due,
+            vec![
+                ("earlier", TriggerKind::End, utc("2024-06-03T10:00:00Z")),
+                ("standup", TriggerKind::Start, utc("2024-06-03T10:00:00Z")),
+                ("standup", TriggerKind::End, utc("2024-06-03T10:15:00Z")),
+                ("later", TriggerKind::Before, utc("2024-06-03T10:55:00Z")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod daemon_command;
+pub(crate) mod event_triggers;
+pub(crate) mod run_hooks;
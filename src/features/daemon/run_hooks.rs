@@ -0,0 +1,83 @@
+use tokio::process::Command;
+
+use crate::domain::{config::Clock, events::Event, timezone::DisplayTimezone};
+
+use super::event_triggers::TriggerKind;
+
+/// Run each command through the shell in the background, with details of the
+/// event in `SOCAL_*` environment variables
+pub fn run_hooks(
+    commands: &[String],
+    trigger: TriggerKind,
+    event: &Event,
+    clock: Clock,
+    display_timezone: DisplayTimezone,
+) {
+    let variables = hook_variables(trigger, event, clock, display_timezone);
+
+    for command in commands {
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .envs(variables.iter().cloned())
+            .spawn();
+
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                tracing::error!(command, "Failed to run hook: {:?}", e);
+                continue;
+            }
+        };
+
+        let command = command.to_owned();
+        tokio::spawn(async move {
+            match child.wait().await {
+                Ok(status) if status.success() => {}
+                Ok(status) => tracing::warn!(command, "Hook exited with {}", status),
+                Err(e) => tracing::error!(command, "Failed to wait for hook: {:?}", e),
+            }
+        });
+    }
+}
+
+fn hook_variables(
+    trigger: TriggerKind,
+    event: &Event,
+    clock: Clock,
+    display_timezone: DisplayTimezone,
+) -> Vec<(&'static str, String)> {
+    let start = event.time.start_instant(display_timezone);
+    let end = event.time.end_instant(display_timezone);
+    let link = event
+        .hangout_link
+        .as_ref()
+        .or(event.html_link.as_ref())
+        .cloned()
+        .unwrap_or_default();
+
+    vec![
+        ("SOCAL_TRIGGER", trigger.name().to_owned()),
+        ("SOCAL_EVENT_ID", event.id.to_owned()),
+        ("SOCAL_EVENT_TITLE", event.title.to_owned()),
+        ("SOCAL_EVENT_START", start.to_rfc3339()),
+        ("SOCAL_EVENT_END", end.to_rfc3339()),
+        (
+            "SOCAL_EVENT_START_TIME",
+            display_timezone.format(&start, clock.time_format()),
+        ),
+        (
+            "SOCAL_EVENT_END_TIME",
+            display_timezone.format(&end, clock.time_format()),
+        ),
+        (
+            "SOCAL_EVENT_LOCATION",
+            event.location.to_owned().unwrap_or_default(),
+        ),
+        (
+            "SOCAL_EVENT_CALENDAR",
+            event.calendar_title.to_owned().unwrap_or_default(),
+        ),
+        ("SOCAL_EVENT_LINK", link),
+    ]
+}
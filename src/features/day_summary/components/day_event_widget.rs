@@ -1,18 +1,25 @@
+use chrono::NaiveDate;
 use ratatui::prelude::*;
-use ratatui::style::palette::tailwind;
 use ratatui::widgets::Widget;
 use ratatui::widgets::*;
 
-use crate::domain::events::DayEvent;
+use crate::domain::events::Event;
+use crate::domain::events::EventStatus;
+use crate::domain::events::EventTime;
+use crate::domain::theme::Theme;
+use crate::util::colour_helpers::nearest_terminal_colour;
 use crate::util::text_helpers::truncate_text;
 
 pub struct DayEventWidget<'a> {
-    event: &'a DayEvent,
+    event: &'a Event,
+    /// The day the event is being shown on, used to mark multi day events
+    date: NaiveDate,
+    theme: &'a Theme,
 }
 
 impl<'a> DayEventWidget<'a> {
-    pub fn new(event: &'a DayEvent) -> Self {
-        Self { event }
+    pub fn new(event: &'a Event, date: NaiveDate, theme: &'a Theme) -> Self {
+        Self { event, date, theme }
     }
 }
 
@@ -24,27 +31,71 @@ impl Widget for DayEventWidget<'_> {
         let event_row_layout = Layout::horizontal([
             Constraint::Length(1),
             Constraint::Fill(1),
-            Constraint::Length(7),
+            Constraint::Length(9),
         ])
         .spacing(1)
         .split(area);
 
-        // DayEvent dot indicator
-        Paragraph::new("●")
-            .style(Style::new().fg(tailwind::EMERALD.c500).bold())
+        // All day event dot indicator
+        let dot_style = self.theme.marker_style(
+            self.event
+                .colour
+                .map(|colour| nearest_terminal_colour(&colour)),
+            self.theme.all_day,
+        );
+        // Tentative events are hollow and italicised to show they may not happen
+        let is_tentative = self.event.status == EventStatus::Tentative;
+        // Without colour all day events have their own markers, so they can't
+        // be mistaken for timed ones
+        let dot = if is_tentative {
+            self.theme.symbol("○", "~")
+        } else {
+            self.theme.symbol("●", "=")
+        };
+        let title_style = if is_tentative {
+            self.theme.muted_style().italic()
+        } else {
+            Style::new().fg(self.theme.text)
+        };
+
+        Paragraph::new(dot)
+            .style(dot_style)
             .render(event_row_layout[0], buf);
 
         // Main event information
         let max_width: usize = event_row_layout[1].width.into();
 
-        let event_title = truncate_text(&self.event.title, max_width);
+        // Arrows mark events which carry on from the previous day or into the next
+        let (day_number, total_days) = match self.event.time {
+            EventTime::AllDay { start, end } => {
+                ((self.date - start).num_days() + 1, (end - start).num_days())
+            }
+            _ => (1, 1),
+        };
+        let continues_from = if day_number > 1 {
+            self.theme.symbol("◂ ", "< ")
+        } else {
+            ""
+        };
+        let continues_to = if day_number < total_days {
+            self.theme.symbol(" ▸", " >")
+        } else {
+            ""
+        };
+        let title = format!("{}{}{}", continues_from, self.event.title, continues_to);
+        let event_title = truncate_text(&title, max_width);
         Paragraph::new(event_title)
-            .style(Style::new().fg(tailwind::STONE.c800))
+            .style(title_style)
             .render(event_row_layout[1], buf);
 
-        // All day indicator
-        Paragraph::new("All day")
-            .style(Style::new().fg(tailwind::STONE.c500))
+        // All day indicator, showing progress through events covering several days
+        let day_label = if total_days > 1 {
+            format!("Day {}/{}", day_number, total_days)
+        } else {
+            "All day".to_string()
+        };
+        Paragraph::new(day_label)
+            .style(self.theme.muted_style())
             .render(event_row_layout[2], buf);
     }
 }
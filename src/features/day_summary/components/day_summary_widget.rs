@@ -1,10 +1,13 @@
 use chrono::NaiveDate;
-use ratatui::style::palette::tailwind;
 use ratatui::widgets::Widget;
 use ratatui::widgets::*;
 use ratatui::{layout::Flex, prelude::*};
 
-use crate::domain::events::{DayEvent, Event};
+use crate::domain::config::Config;
+use crate::domain::events::Event;
+use crate::domain::theme::Theme;
+use crate::domain::timezone::DisplayTimezone;
+use crate::features::day_summary::summary_events::SummaryEvents;
 
 use super::day_event_widget::DayEventWidget;
 use super::event_widget::EventWidget;
@@ -12,17 +15,42 @@ use super::event_widget::EventWidget;
 pub struct DaySummaryWidget<'a> {
     date: NaiveDate,
     events: &'a [Event],
-    day_events: &'a [DayEvent],
+    config: &'a Config,
+    theme: &'a Theme,
+    display_timezone: DisplayTimezone,
+    hide_tentative: bool,
+    /// Position of the highlighted event in the list, if any
+    selected: Option<usize>,
 }
 
 impl<'a> DaySummaryWidget<'a> {
-    pub fn new(date: NaiveDate, events: &'a [Event], day_events: &'a [DayEvent]) -> Self {
+    pub fn new(
+        date: NaiveDate,
+        events: &'a [Event],
+        config: &'a Config,
+        theme: &'a Theme,
+        display_timezone: DisplayTimezone,
+    ) -> Self {
         Self {
             date,
             events,
-            day_events,
+            config,
+            theme,
+            display_timezone,
+            hide_tentative: false,
+            selected: None,
         }
     }
+
+    pub fn hide_tentative(mut self, hide_tentative: bool) -> Self {
+        self.hide_tentative = hide_tentative;
+        self
+    }
+
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
 }
 
 impl Widget for DaySummaryWidget<'_> {
@@ -30,14 +58,30 @@ impl Widget for DaySummaryWidget<'_> {
     where
         Self: Sized,
     {
+        let SummaryEvents {
+            all_day: day_events,
+            segments,
+        } = SummaryEvents::for_day(
+            self.events,
+            self.date,
+            self.display_timezone,
+            self.hide_tentative,
+        );
+        let num_all_day = day_events.len();
+        // The list can shrink under the cursor, e.g. when tentative events are hidden
+        let selected = self
+            .selected
+            .zip((num_all_day + segments.len()).checked_sub(1))
+            .map(|(selected, last_index)| selected.min(last_index));
+        let selected_style = self.theme.selected_style();
+
         let main_container = Block::default()
             .padding(Padding::horizontal(1))
-            .style(Style::new().bg(tailwind::STONE.c100));
+            .style(Style::new().bg(self.theme.panel));
         let main_container_area = main_container.inner(area);
         main_container.render(area, buf);
 
-        let num_day_events: u16 = self
-            .day_events
+        let num_day_events: u16 = day_events
             .len()
             .try_into()
             .expect("way too many day events");
@@ -51,15 +95,15 @@ impl Widget for DaySummaryWidget<'_> {
         .split(main_container_area);
 
         // Selected date
-        let formatted_date = self.date.format("%d %B, %A").to_string();
+        let formatted_date = self.date.format(&self.config.date_format).to_string();
         Paragraph::new(formatted_date)
-            .style(Style::new().fg(tailwind::RED.c500).bold())
+            .style(self.theme.today_style())
             .render(main_layout[0], buf);
 
         // Separator
         let separator: String = std::iter::repeat("-").take(area.width.into()).collect();
         Paragraph::new(separator)
-            .style(Style::new().fg(tailwind::STONE.c400))
+            .style(self.theme.muted_style())
             .render(main_layout[1], buf);
 
         // Day events list
@@ -69,19 +113,31 @@ impl Widget for DaySummaryWidget<'_> {
             .flex(Flex::Start)
             .split(main_layout[2]);
 
-        for (i, day_event) in self.day_events.iter().enumerate() {
-            DayEventWidget::new(day_event).render(events_layout[i], buf);
+        for (i, day_event) in day_events.into_iter().enumerate() {
+            if selected == Some(i) {
+                buf.set_style(events_layout[i], selected_style);
+            }
+            DayEventWidget::new(day_event, self.date, self.theme).render(events_layout[i], buf);
         }
 
         // Events list
         let events_layout_constraints =
-            std::iter::repeat(Constraint::Length(2)).take(self.events.len());
+            std::iter::repeat(Constraint::Length(2)).take(segments.len());
         let events_layout = Layout::vertical(events_layout_constraints)
             .flex(Flex::Start)
             .split(main_layout[3]);
 
-        for (i, event) in self.events.iter().enumerate() {
-            EventWidget::new(event).render(events_layout[i], buf);
+        for (i, segment) in segments.into_iter().enumerate() {
+            if selected == Some(num_all_day + i) {
+                buf.set_style(events_layout[i], selected_style);
+            }
+            EventWidget::new(
+                segment,
+                self.config.clock,
+                self.theme,
+                self.display_timezone,
+            )
+            .render(events_layout[i], buf);
         }
     }
 }
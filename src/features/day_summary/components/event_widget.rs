@@ -1,18 +1,43 @@
 use ratatui::prelude::*;
-use ratatui::style::palette::tailwind;
 use ratatui::widgets::Widget;
 use ratatui::widgets::*;
 
+use crate::domain::config::Clock;
 use crate::domain::events::Event;
+use crate::domain::events::EventSegment;
+use crate::domain::events::EventStatus;
+use crate::domain::events::ResponseStatus;
+use crate::domain::theme::Theme;
+use crate::domain::timezone::DisplayTimezone;
+use crate::features::event_details::view::response_marker;
+use crate::util::colour_helpers::nearest_terminal_colour;
 use crate::util::text_helpers::truncate_text;
 
+/// Marker shown in place of a time for events continuing across midnight
+const CONTINUATION_MARKER: &str = "  ...";
+
 pub struct EventWidget<'a> {
     event: &'a Event,
+    segment: EventSegment<'a>,
+    clock: Clock,
+    theme: &'a Theme,
+    display_timezone: DisplayTimezone,
 }
 
 impl<'a> EventWidget<'a> {
-    pub fn new(event: &'a Event) -> Self {
-        Self { event }
+    pub fn new(
+        segment: EventSegment<'a>,
+        clock: Clock,
+        theme: &'a Theme,
+        display_timezone: DisplayTimezone,
+    ) -> Self {
+        Self {
+            event: segment.event,
+            segment,
+            clock,
+            theme,
+            display_timezone,
+        }
     }
 }
 
@@ -24,14 +49,38 @@ impl Widget for EventWidget<'_> {
         let event_row_layout = Layout::horizontal([
             Constraint::Length(1),
             Constraint::Fill(1),
-            Constraint::Length(5),
+            Constraint::Length(1),
+            Constraint::Length(self.clock.time_width()),
         ])
         .spacing(1)
         .split(area);
 
         // Event dot indicator
-        Paragraph::new("●")
-            .style(Style::new().fg(tailwind::SKY.c500).bold())
+        let dot_style = self.theme.marker_style(
+            self.event
+                .colour
+                .map(|colour| nearest_terminal_colour(&colour)),
+            self.theme.event,
+        );
+        // Tentative events are hollow and italicised to show they may not happen
+        let is_tentative = self.event.status == EventStatus::Tentative;
+        let dot = if is_tentative {
+            self.theme.symbol("○", "o")
+        } else {
+            self.theme.symbol("●", "*")
+        };
+        let own_response = self.event.own_response();
+        let mut title_style = if is_tentative {
+            self.theme.muted_style().italic()
+        } else {
+            Style::new().fg(self.theme.text)
+        };
+        if own_response == Some(ResponseStatus::Declined) {
+            title_style = title_style.crossed_out();
+        }
+
+        Paragraph::new(dot)
+            .style(dot_style)
             .render(event_row_layout[0], buf);
 
         // Main event information
@@ -41,26 +90,44 @@ impl Widget for EventWidget<'_> {
 
         let event_title = truncate_text(&self.event.title, max_width);
         Paragraph::new(event_title)
-            .style(Style::new().fg(tailwind::STONE.c800))
+            .style(title_style)
             .render(event_content_layout[0], buf);
 
         if let Some(description) = &self.event.description {
             Paragraph::new(truncate_text(description, max_width))
-                .style(Style::new().fg(tailwind::STONE.c500))
+                .style(self.theme.muted_style())
                 .render(event_content_layout[1], buf);
         }
 
+        // Our reply, for events we're invited to
+        if let Some(response) = own_response {
+            let (marker, _, marker_style) = response_marker(response, self.theme);
+            Paragraph::new(marker)
+                .style(marker_style.bold())
+                .render(event_row_layout[2], buf);
+        }
+
         // Event time information
         let event_time_layout = Layout::vertical([Constraint::Length(1), Constraint::Length(1)])
-            .split(event_row_layout[2]);
+            .split(event_row_layout[3]);
 
-        let start_time = self.event.start_time.format("%R").to_string();
+        let start_time = if self.segment.continues_from_previous_day {
+            CONTINUATION_MARKER.to_string()
+        } else {
+            self.display_timezone
+                .format(&self.segment.start_time, self.clock.time_format())
+        };
         Paragraph::new(start_time)
-            .style(Style::new().fg(tailwind::STONE.c700))
+            .style(Style::new().fg(self.theme.text))
             .render(event_time_layout[0], buf);
-        let end_time = self.event.end_time.format("%R").to_string();
+        let end_time = if self.segment.continues_to_next_day {
+            CONTINUATION_MARKER.to_string()
+        } else {
+            self.display_timezone
+                .format(&self.segment.end_time, self.clock.time_format())
+        };
         Paragraph::new(end_time)
-            .style(Style::new().fg(tailwind::STONE.c500))
+            .style(self.theme.muted_style())
             .render(event_time_layout[1], buf);
     }
 }
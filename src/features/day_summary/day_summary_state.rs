@@ -0,0 +1,20 @@
+use crate::features::event_details::event_details_state::EventDetailsState;
+
+/// The cursor over today's events and the event opened from it, in the views
+/// which list today's events
+#[derive(Debug, Default)]
+pub struct DaySummaryState {
+    /// Position of the highlighted event in today's summary
+    pub selected_event: usize,
+    /// The event opened in the details popup, if it is open
+    pub event_details: Option<Box<EventDetailsState>>,
+}
+
+impl DaySummaryState {
+    /// Whether we're choosing how to reply to the open event
+    pub fn is_answering_rsvp(&self) -> bool {
+        self.event_details
+            .as_ref()
+            .is_some_and(|details| details.rsvp.is_some())
+    }
+}
@@ -1 +1,4 @@
 pub(crate) mod components;
+pub(crate) mod day_summary_state;
+pub(crate) mod summary_events;
+pub(crate) mod update_day_summary;
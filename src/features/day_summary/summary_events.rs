@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+
+use crate::domain::{
+    events::{Event, EventSegment, EventStatus},
+    timezone::DisplayTimezone,
+};
+
+/// The events listed in a day summary, all day events come first followed by
+/// the timed events in the order they start
+pub struct SummaryEvents<'a> {
+    pub all_day: Vec<&'a Event>,
+    pub segments: Vec<EventSegment<'a>>,
+}
+
+impl<'a> SummaryEvents<'a> {
+    pub fn for_day(
+        events: &'a [Event],
+        date: NaiveDate,
+        display_timezone: DisplayTimezone,
+        hide_tentative: bool,
+    ) -> Self {
+        let is_shown = |event: &&Event| !(hide_tentative && event.status == EventStatus::Tentative);
+
+        let all_day = events
+            .iter()
+            .filter(is_shown)
+            .filter(|event| event.time.is_all_day())
+            .filter(|event| event.time.occurs_on(date, display_timezone))
+            .collect();
+
+        let mut segments: Vec<EventSegment> = events
+            .iter()
+            .filter(is_shown)
+            .filter_map(|event| event.segment_on(date, display_timezone))
+            .collect();
+        segments.sort_by_key(|segment| segment.start_time);
+
+        Self { all_day, segments }
+    }
+
+    pub fn len(&self) -> usize {
+        self.all_day.len() + self.segments.len()
+    }
+
+    /// The event at the given position in the list
+    pub fn get(&self, index: usize) -> Option<&'a Event> {
+        match index.checked_sub(self.all_day.len()) {
+            None => self.all_day.get(index).copied(),
+            Some(segment_index) => self
+                .segments
+                .get(segment_index)
+                .map(|segment| segment.event),
+        }
+    }
+}
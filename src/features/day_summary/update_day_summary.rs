@@ -0,0 +1,200 @@
+use color_eyre::eyre::Result;
+
+use crate::{
+    domain::events::Event,
+    features::{
+        event_changes::update_event_changes::run_delete_event_task,
+        event_details::event_details_state::EventDetailsState,
+        event_form::{
+            event_form_state::EventFormState, retrieve_calendars::retrieve_writable_calendars,
+        },
+        rsvp::update_rsvp::handle_submit_message as handle_rsvp_submit_message,
+    },
+    tui::model::{CurrentState, EventsState, Model},
+};
+
+use super::summary_events::SummaryEvents;
+
+/// Move the cursor over today's events, wrapping around at either end, or
+/// scroll the open event's details
+pub fn handle_vertical_message(model: &mut Model, down: bool) {
+    let event_count = todays_event_count(model);
+    let Some(state) = model.current_state.day_summary_mut() else {
+        return;
+    };
+
+    if let Some(details) = &mut state.event_details {
+        details.scroll(
+            down,
+            model.screen,
+            &model.application.config,
+            &model.theme,
+            model.application.display_timezone,
+        );
+        return;
+    }
+
+    if event_count == 0 {
+        return;
+    }
+
+    let current_index = state.selected_event.min(event_count - 1);
+    state.selected_event = if down {
+        (current_index + 1) % event_count
+    } else {
+        current_index.checked_sub(1).unwrap_or(event_count - 1)
+    };
+}
+
+/// Select the first or last of today's events, or scroll to the top or bottom
+/// of the open event's details
+pub fn handle_jump_message(model: &mut Model, last: bool) {
+    let event_count = todays_event_count(model);
+    let Some(state) = model.current_state.day_summary_mut() else {
+        return;
+    };
+
+    if let Some(details) = &mut state.event_details {
+        details.jump(
+            last,
+            model.screen,
+            &model.application.config,
+            &model.theme,
+            model.application.display_timezone,
+        );
+        return;
+    }
+
+    state.selected_event = if last {
+        event_count.saturating_sub(1)
+    } else {
+        0
+    };
+}
+
+/// Open the selected event in the details popup, or delete the open event once
+/// it has been confirmed
+pub fn handle_enter_message(model: &mut Model) {
+    let Some(state) = model.current_state.day_summary_mut() else {
+        return;
+    };
+    if state.is_answering_rsvp() {
+        return handle_rsvp_submit_message(model);
+    }
+    if let Some(details) = state.event_details.take() {
+        if details.confirm_delete {
+            run_delete_event_task(details.event, model);
+        } else {
+            state.event_details = Some(details);
+        }
+        return;
+    }
+
+    let selected_index = state.selected_event;
+    let selected_event = todays_events(model).and_then(|events| {
+        let last_index = events.len().checked_sub(1)?;
+        events.get(selected_index.min(last_index)).cloned()
+    });
+
+    if let Some(state) = model.current_state.day_summary_mut() {
+        state.event_details = selected_event.map(|event| Box::new(EventDetailsState::new(event)));
+    }
+}
+
+pub fn handle_back_message(model: &mut Model) {
+    let Some(state) = model.current_state.day_summary_mut() else {
+        return;
+    };
+
+    match &mut state.event_details {
+        Some(details) if details.rsvp.is_some() => details.rsvp = None,
+        Some(details) if details.confirm_delete => details.confirm_delete = false,
+        _ => state.event_details = None,
+    }
+}
+
+/// Open the event shown in the details popup in the form to change it
+pub async fn handle_edit_message(model: &mut Model) -> Result<()> {
+    let Some(event) = open_event(model) else {
+        return Ok(());
+    };
+    if let Some(reason) = cannot_change_reason(&event) {
+        model.notification = Some(reason.to_owned());
+        return Ok(());
+    }
+
+    let calendar = retrieve_writable_calendars(&model.application.db)
+        .await?
+        .into_iter()
+        .find(|calendar| {
+            calendar.calendar_id == event.calendar_id && calendar.account_id == event.account_id
+        });
+
+    match calendar {
+        Some(calendar) => {
+            let form = EventFormState::edit(event, calendar, model.application.display_timezone);
+            model.current_state = CurrentState::EventForm(Box::new(form));
+        }
+        None => model.notification = Some("Events on this calendar can't be changed".to_owned()),
+    }
+
+    Ok(())
+}
+
+/// Ask to confirm deleting the event shown in the details popup
+pub fn handle_delete_message(model: &mut Model) {
+    let Some(event) = open_event(model) else {
+        return;
+    };
+    if let Some(reason) = cannot_change_reason(&event) {
+        model.notification = Some(reason.to_owned());
+        return;
+    }
+
+    if let Some(details) = model
+        .current_state
+        .day_summary_mut()
+        .and_then(|state| state.event_details.as_mut())
+    {
+        details.confirm_delete = true;
+    }
+}
+
+/// The event in the details popup, if it is open
+fn open_event(model: &Model) -> Option<Event> {
+    model
+        .current_state
+        .day_summary()?
+        .event_details
+        .as_ref()
+        .map(|details| details.event.to_owned())
+}
+
+fn cannot_change_reason(event: &Event) -> Option<&'static str> {
+    if event.is_editable() {
+        None
+    } else if event.recurring_event_id.is_some() || event.recurrence.is_some() {
+        Some("Recurring events can't be changed yet")
+    } else {
+        Some("Only events you organise can be changed")
+    }
+}
+
+/// The events listed in today's summary which the cursor moves over
+fn todays_events(model: &Model) -> Option<SummaryEvents<'_>> {
+    let EventsState::Ready(events) = &model.events_state else {
+        return None;
+    };
+    let display_timezone = model.application.display_timezone;
+
+    Some(SummaryEvents::for_day(
+        events,
+        display_timezone.today(),
+        display_timezone,
+        model.event_filters.hide_tentative,
+    ))
+}
+
+fn todays_event_count(model: &Model) -> usize {
+    todays_events(model).map_or(0, |events| events.len())
+}
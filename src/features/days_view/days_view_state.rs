@@ -1,7 +1,7 @@
-use crate::domain::events::{DayEvent, Event};
+use crate::features::day_summary::day_summary_state::DaySummaryState;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct DaysViewState {
-    pub events: Vec<Event>,
-    pub day_events: Vec<DayEvent>,
+    /// Today's events listed beside the times of day
+    pub summary: DaySummaryState,
 }
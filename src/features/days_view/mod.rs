@@ -1,5 +1,2 @@
 pub mod days_view_state;
-pub(crate) mod deserialise_event_response;
-pub(crate) mod handle_days_view_message;
-pub(crate) mod retrieve_calendars;
 pub(crate) mod view;
@@ -1,18 +1,26 @@
+use chrono::{NaiveTime, Timelike};
 use ratatui::prelude::*;
-use ratatui::style::palette::tailwind;
 use ratatui::widgets::*;
 
+use crate::domain::{config::Config, keymap::KeyScope, theme::Theme, timezone::DisplayTimezone};
+use crate::features::day_summary::components::day_summary_widget::DaySummaryWidget;
+use crate::features::event_details;
+use crate::tui::model::{EventFilters, EventsState};
+
+use super::days_view_state::DaysViewState;
+
 // 10:00 <-- slightly greyed out colour
 //
 // 11:00
 //
 // 12:00
-fn render_times(frame: &mut Frame, area: Rect) {
+fn render_times(frame: &mut Frame, area: Rect, config: &Config, theme: &Theme) {
     let area = Rect::new(area.x, area.y + 1, area.width, area.height - 1);
 
-    // TODO: these should be passed in
-    let time_from = 9;
-    let time_until = 19;
+    // Every hour which is at least partly within working hours
+    let working_hours = &config.working_hours;
+    let time_from = working_hours.start.hour();
+    let time_until = working_hours.end.hour() + u32::from(working_hours.end.minute() > 0);
 
     let num_lines = time_until - time_from;
 
@@ -21,8 +29,11 @@ fn render_times(frame: &mut Frame, area: Rect) {
     let layout = Layout::vertical(constraints).spacing(1).split(area);
 
     for (i, time) in (time_from..time_until).enumerate() {
-        let formatted_time = format!("{:02}:00", time);
-        let o_clock_line = Line::styled(formatted_time, tailwind::STONE.c400);
+        let formatted_time = NaiveTime::from_hms_opt(time, 0, 0)
+            .expect("hours within a day")
+            .format(config.time_format())
+            .to_string();
+        let o_clock_line = Line::styled(formatted_time, theme.muted_style());
         frame.render_widget(o_clock_line, layout[i]);
     }
 }
@@ -39,14 +50,22 @@ fn render_times(frame: &mut Frame, area: Rect) {
 // 13:00 Blah blah        <-- some overlapping events
 //       ---- Other..     <-- but this one starts later
 // 14:00 ---- .......
-fn render_day(frame: &mut Frame, area: Rect) {
-    let date = Line::styled("6 Jun", (tailwind::RED.c400, Modifier::BOLD));
+fn render_day(frame: &mut Frame, area: Rect, theme: &Theme) {
+    let date = Line::styled("6 Jun", theme.today_style());
     frame.render_widget(date, area);
 }
 
-pub fn render(frame: &mut Frame) {
+pub fn render(
+    frame: &mut Frame,
+    state: &DaysViewState,
+    events_state: &EventsState,
+    event_filters: &EventFilters,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
     let main_layout = Layout::horizontal([
-        Constraint::Length(6),
+        Constraint::Length(config.clock.time_width() + 1),
         Constraint::Length(20),
         Constraint::Min(20),
     ])
@@ -55,13 +74,50 @@ pub fn render(frame: &mut Frame) {
     let calendar_block = Block::default();
     let _month_view_placeholder = Paragraph::new("Days view").centered().block(calendar_block);
 
-    let today_block = Block::default().borders(Borders::LEFT);
-    let today_view_placeholder = Paragraph::new("Today overview")
-        .centered()
-        .block(today_block);
-
     //frame.render_widget(month_view_placeholder, main_layout[0]);
-    render_times(frame, main_layout[0]);
-    render_day(frame, main_layout[1]);
-    frame.render_widget(today_view_placeholder, main_layout[2]);
+    render_times(frame, main_layout[0], config, theme);
+    render_day(frame, main_layout[1], theme);
+
+    let events = match events_state {
+        EventsState::Ready(events) => events,
+        EventsState::Loading => {
+            let today_block = Block::default().borders(Borders::LEFT);
+            let loading = Paragraph::new("Loading events...")
+                .centered()
+                .block(today_block);
+            frame.render_widget(loading, main_layout[2]);
+            return;
+        }
+        EventsState::Error(error) => {
+            let today_block = Block::default().borders(Borders::LEFT);
+            let error = Paragraph::new(error.to_owned())
+                .centered()
+                .style(theme.error_style())
+                .block(today_block);
+            frame.render_widget(error, main_layout[2]);
+            return;
+        }
+    };
+
+    let today_widget = DaySummaryWidget::new(
+        display_timezone.today(),
+        events,
+        config,
+        theme,
+        display_timezone,
+    )
+    .hide_tentative(event_filters.hide_tentative)
+    .selected(Some(state.summary.selected_event));
+    frame.render_widget(today_widget, main_layout[2]);
+
+    if let Some(event_details) = &state.summary.event_details {
+        event_details::view::render(
+            frame,
+            event_details,
+            KeyScope::Days,
+            config,
+            theme,
+            display_timezone,
+        );
+    }
 }
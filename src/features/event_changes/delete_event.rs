@@ -0,0 +1,34 @@
+use color_eyre::eyre::{eyre, Result};
+use eyre::Context;
+use reqwest::{header::IF_MATCH, StatusCode};
+
+use crate::{domain::events::Event, features::oauth_http_client::GoogleOAuthClient};
+
+/// Delete an event, only if it hasn't changed since we fetched it
+#[tracing::instrument(name = "Delete event", skip(event, google_client), fields(event_id = event.id))]
+pub async fn delete_event(event: &Event, google_client: &GoogleOAuthClient) -> Result<()> {
+    let etag = event
+        .etag
+        .as_deref()
+        .ok_or(eyre!("The event's version is unknown"))?;
+    let delete_url = format!(
+        "https://www.googleapis.com/calendar/v3/calendars/{}/events/{}",
+        urlencoding::encode(&event.calendar_id),
+        urlencoding::encode(&event.id)
+    );
+
+    let http_client = reqwest::Client::new();
+    let delete_request = http_client.delete(delete_url).header(IF_MATCH, etag);
+
+    let response = google_client.send(event.account_id, delete_request).await?;
+    if response.status() == StatusCode::PRECONDITION_FAILED {
+        return Err(eyre!(
+            "The event was changed elsewhere, refresh to see the latest version"
+        ));
+    }
+    response
+        .error_for_status()
+        .wrap_err("Google rejected deleting the event")?;
+
+    Ok(())
+}
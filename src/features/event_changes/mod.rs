@@ -0,0 +1,3 @@
+pub(crate) mod delete_event;
+pub(crate) mod patch_event;
+pub(crate) mod update_event_changes;
@@ -0,0 +1,53 @@
+use color_eyre::eyre::{eyre, Result};
+use eyre::Context;
+use reqwest::{header::IF_MATCH, StatusCode};
+use serde::Serialize;
+
+use crate::{
+    domain::events::Event,
+    features::{
+        fetch_events::deserialise_event_response::deserialise_event_response,
+        oauth_http_client::GoogleOAuthClient,
+    },
+};
+
+/// Apply changes to an event, only if it hasn't changed since we fetched it.
+/// Returns the event as Google stored it
+#[tracing::instrument(
+    name = "Patch event",
+    skip(request, event, google_client),
+    fields(event_id = event.id)
+)]
+pub async fn patch_event<T: Serialize>(
+    request: &T,
+    event: &Event,
+    google_client: &GoogleOAuthClient,
+) -> Result<Event> {
+    let etag = event
+        .etag
+        .as_deref()
+        .ok_or(eyre!("The event's version is unknown"))?;
+    let patch_url = format!(
+        "https://www.googleapis.com/calendar/v3/calendars/{}/events/{}",
+        urlencoding::encode(&event.calendar_id),
+        urlencoding::encode(&event.id)
+    );
+
+    let http_client = reqwest::Client::new();
+    let patch_request = http_client
+        .patch(patch_url)
+        .json(request)
+        .header(IF_MATCH, etag);
+
+    let response = google_client.send(event.account_id, patch_request).await?;
+    if response.status() == StatusCode::PRECONDITION_FAILED {
+        return Err(eyre!(
+            "The event was changed elsewhere, refresh to see the latest version"
+        ));
+    }
+    let response = response
+        .error_for_status()
+        .wrap_err("Google rejected the changes")?;
+
+    deserialise_event_response(response, &event.calendar_id, event.account_id).await
+}
@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use crate::{
+    domain::events::Event,
+    tui::model::{CurrentState, EventsState, Message, Model},
+};
+
+use super::{delete_event::delete_event, patch_event::patch_event};
+
+/// Show the changed event straight away and send the changes to Google in the
+/// background, rolling back to the original if they are rejected
+/// request: body of the patch request
+/// updated: the event as it will be once the changes are applied
+pub fn run_patch_event_task<T>(request: T, updated: Event, original: Event, model: &mut Model)
+where
+    T: Serialize + Send + Sync + 'static,
+{
+    replace_event(model, &original.id, updated);
+
+    let google_client = model.application.google_client.clone();
+    let message_channel = model.message_channel.clone();
+
+    tokio::spawn(async move {
+        let message = match patch_event(&request, &original, &google_client).await {
+            Ok(event) => Message::EventSaved(Box::new(event)),
+            Err(e) => {
+                tracing::error!("Failed to update event: {:?}", e);
+                Message::EventChangeFailed(
+                    Box::new(original),
+                    format!("Failed to update event: {}", e),
+                )
+            }
+        };
+
+        message_channel
+            .send(message)
+            .expect("Message channel should never be closed");
+    });
+}
+
+/// Remove the event straight away and delete it from Google in the background,
+/// restoring it if that fails
+pub fn run_delete_event_task(original: Event, model: &mut Model) {
+    if let EventsState::Ready(events) = &mut model.events_state {
+        events.retain(|event| event.id != original.id);
+    }
+
+    let google_client = model.application.google_client.clone();
+    let message_channel = model.message_channel.clone();
+
+    tokio::spawn(async move {
+        if let Err(e) = delete_event(&original, &google_client).await {
+            tracing::error!("Failed to delete event: {:?}", e);
+            message_channel
+                .send(Message::EventChangeFailed(
+                    Box::new(original),
+                    format!("Failed to delete event: {}", e),
+                ))
+                .expect("Message channel should never be closed");
+        }
+    });
+}
+
+/// Swap the optimistic copy of an event for the version Google saved
+pub fn handle_event_saved(model: &mut Model, mut event: Event) {
+    let invitations = match &model.current_state {
+        CurrentState::Invitations(state) => Some(&state.events),
+        _ => None,
+    };
+    let existing = [Some(&model.events_state), invitations]
+        .into_iter()
+        .flatten()
+        .filter_map(|events_state| match events_state {
+            EventsState::Ready(events) => Some(events),
+            _ => None,
+        })
+        .flatten()
+        .find(|existing| existing.id == event.id);
+
+    // Colours and calendar names aren't part of the event so are kept from our
+    // copy, as is the recurrence of instances Google returns without their series
+    // and the calendar's default reminders
+    if let Some(existing) = existing {
+        event.colour = existing.colour;
+        event.calendar_title = existing.calendar_title.to_owned();
+        if event.recurrence.is_none() {
+            event.recurrence = existing.recurrence.to_owned();
+        }
+        if event.reminders.is_empty() {
+            event.reminders = existing.reminders.to_owned();
+        }
+    }
+
+    let id = event.id.to_owned();
+    replace_event(model, &id, event);
+}
+
+/// Put an event back how it was before a change Google rejected
+pub fn handle_event_change_failed(model: &mut Model, original: Event, error: String) {
+    let id = original.id.to_owned();
+    replace_event(model, &id, original);
+    model.notification = Some(error);
+}
+
+fn replace_event(model: &mut Model, id: &str, event: Event) {
+    // Keep the details popup showing the latest version of the event
+    if let Some(details) = model
+        .current_state
+        .day_summary_mut()
+        .and_then(|state| state.event_details.as_mut())
+    {
+        if details.event.id == id {
+            details.event = event.clone();
+        }
+    }
+    // Invitations are fetched over more days, so have their own copy
+    if let CurrentState::Invitations(ref mut state) = model.current_state {
+        if let EventsState::Ready(events) = &mut state.events {
+            if let Some(existing) = events.iter_mut().find(|existing| existing.id == id) {
+                *existing = event.clone();
+            }
+        }
+    }
+
+    if let EventsState::Ready(events) = &mut model.events_state {
+        events.retain(|existing| existing.id != id);
+        events.push(event);
+    }
+}
@@ -0,0 +1,109 @@
+use ratatui::layout::Rect;
+
+use crate::{
+    domain::{config::Config, events::Event, theme::Theme, timezone::DisplayTimezone},
+    features::rsvp::rsvp_state::RsvpState,
+};
+
+use super::view::{details, details_width};
+
+/// An event opened in the details popup
+#[derive(Debug)]
+pub struct EventDetailsState {
+    pub event: Event,
+    /// Number of wrapped lines scrolled down from the top of the details
+    pub scroll: u16,
+    /// Whether we're asking to confirm deleting the event
+    pub confirm_delete: bool,
+    /// The reply being chosen, if we're replying to the event
+    pub rsvp: Option<RsvpState>,
+}
+
+impl EventDetailsState {
+    pub fn new(event: Event) -> Self {
+        Self {
+            event,
+            scroll: 0,
+            confirm_delete: false,
+            rsvp: None,
+        }
+    }
+
+    /// Scroll a line up or down, stopping with the last line at the top
+    pub fn scroll(
+        &mut self,
+        down: bool,
+        screen: Rect,
+        config: &Config,
+        theme: &Theme,
+        display_timezone: DisplayTimezone,
+    ) {
+        self.scroll = if down {
+            (self.scroll + 1).min(self.max_scroll(screen, config, theme, display_timezone))
+        } else {
+            self.scroll.saturating_sub(1)
+        };
+    }
+
+    /// Scroll to the top, or until the last line is at the top
+    pub fn jump(
+        &mut self,
+        last: bool,
+        screen: Rect,
+        config: &Config,
+        theme: &Theme,
+        display_timezone: DisplayTimezone,
+    ) {
+        self.scroll = if last {
+            self.max_scroll(screen, config, theme, display_timezone)
+        } else {
+            0
+        };
+    }
+
+    fn max_scroll(
+        &self,
+        screen: Rect,
+        config: &Config,
+        theme: &Theme,
+        display_timezone: DisplayTimezone,
+    ) -> u16 {
+        details(&self.event, config, theme, display_timezone)
+            .line_count(details_width(screen))
+            .saturating_sub(1)
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ratatui::layout::Rect;
+
+    use super::EventDetailsState;
+    use crate::domain::{config::Config, events::Event, theme::Theme, timezone::DisplayTimezone};
+
+    #[test]
+    fn scrolls_to_the_end_of_wrapped_descriptions() {
+        // Arrange
+        let event = Event {
+            description: Some("word ".repeat(200)),
+            ..Event::test_default()
+        };
+        let mut state = EventDetailsState::new(event);
+        let (config, theme) = (Config::default(), Theme::default());
+        let narrow = Rect::new(0, 0, 40, 24);
+        let wide = Rect::new(0, 0, 120, 24);
+
+        // Act
+        state.jump(true, narrow, &config, &theme, DisplayTimezone::Local);
+        let narrow_end = state.scroll;
+        state.jump(true, wide, &config, &theme, DisplayTimezone::Local);
+        let wide_end = state.scroll;
+
+        // Assert
+        // 1000 characters wrap onto at least 31 lines of 32 columns
+        assert!(narrow_end > wide_end, "{} > {}", narrow_end, wide_end);
+        assert!(narrow_end >= 31);
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod event_details_state;
+pub(crate) mod view;
@@ -0,0 +1,383 @@
+use chrono::{Duration, NaiveTime};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::domain::config::Config;
+use crate::domain::events::{Attendee, Event, EventStart, EventTime, ResponseStatus};
+use crate::domain::keymap::{Action, KeyScope, Keymap};
+use crate::domain::theme::Theme;
+use crate::domain::timezone::DisplayTimezone;
+use crate::features::rsvp::rsvp_state::RsvpState;
+use crate::tui::util::centered_popup;
+use crate::util::text_helpers::strip_html;
+
+use super::event_details_state::EventDetailsState;
+
+const POPUP_WIDTH: u16 = 72;
+const POPUP_HEIGHT: u16 = 24;
+const CONFIRMATION_WIDTH: u16 = 48;
+const CONFIRMATION_HEIGHT: u16 = 4;
+const RSVP_WIDTH: u16 = 48;
+const RSVP_HEIGHT: u16 = 6;
+
+pub fn render(
+    frame: &mut Frame,
+    state: &EventDetailsState,
+    scope: KeyScope,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
+    let screen = frame.size();
+    let popup_area = centered_popup(
+        screen,
+        POPUP_WIDTH.min(screen.width.saturating_sub(4)),
+        POPUP_HEIGHT.min(screen.height.saturating_sub(2)),
+    );
+
+    let block = Block::bordered()
+        .title(" Event details ")
+        .title_bottom(
+            Line::from(config.keymap.hint(
+                scope,
+                &[
+                    (&[Action::Up, Action::Down], "scroll"),
+                    (&[Action::Rsvp], "reply"),
+                    (&[Action::Edit], "edit"),
+                    (&[Action::Delete], "delete"),
+                    (&[Action::Back], "close"),
+                ],
+            ))
+            .right_aligned(),
+        )
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.popup).fg(theme.text));
+
+    let details = details(&state.event, config, theme, display_timezone)
+        .block(block)
+        .scroll((state.scroll, 0));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(details, popup_area);
+
+    if state.confirm_delete {
+        render_delete_confirmation(frame, &state.event, &config.keymap, scope, theme);
+    }
+    if let Some(rsvp) = &state.rsvp {
+        render_rsvp_prompt(frame, rsvp, &config.keymap, scope, theme);
+    }
+}
+
+/// Columns the details wrap at, inside the popup's borders and padding
+pub fn details_width(screen: Rect) -> u16 {
+    POPUP_WIDTH
+        .min(screen.width.saturating_sub(4))
+        .saturating_sub(4)
+}
+
+/// The details, wrapped to the width they're drawn at
+pub fn details(
+    event: &Event,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) -> Paragraph<'static> {
+    Paragraph::new(detail_lines(event, config, theme, display_timezone)).wrap(Wrap { trim: false })
+}
+
+fn render_rsvp_prompt(
+    frame: &mut Frame,
+    rsvp: &RsvpState,
+    keymap: &Keymap,
+    scope: KeyScope,
+    theme: &Theme,
+) {
+    let screen = frame.size();
+    let popup_area = centered_popup(
+        screen,
+        RSVP_WIDTH.min(screen.width.saturating_sub(4)),
+        RSVP_HEIGHT.min(screen.height.saturating_sub(2)),
+    );
+
+    let block = Block::bordered()
+        .title(" Reply ")
+        .title_bottom(
+            Line::from(keymap.hint(
+                scope,
+                &[
+                    (&[Action::Left, Action::Right], "choose"),
+                    (&[Action::Enter], "send"),
+                    (&[Action::Back], "cancel"),
+                ],
+            ))
+            .right_aligned(),
+        )
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.popup).fg(theme.text));
+
+    let responses = RsvpState::responses()
+        .iter()
+        .flat_map(|response| {
+            let (marker, _, marker_style) = response_marker(*response, theme);
+            let label = match response {
+                ResponseStatus::Accepted => "yes",
+                ResponseStatus::Declined => "no",
+                _ => "maybe",
+            };
+            let style = if *response == rsvp.response {
+                theme.selected_style().bold()
+            } else {
+                Style::new()
+            };
+
+            [
+                Span::styled(format!(" {} ", marker), style.patch(marker_style)),
+                Span::styled(format!("{} ", label), style),
+                Span::raw("  "),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let lines = vec![
+        Line::from(responses),
+        Line::default(),
+        Line::from(vec![
+            Span::styled("Comment  ", theme.muted_style()),
+            Span::raw(rsvp.comment.to_owned()),
+            Span::raw("▏"),
+        ]),
+    ];
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn render_delete_confirmation(
+    frame: &mut Frame,
+    event: &Event,
+    keymap: &Keymap,
+    scope: KeyScope,
+    theme: &Theme,
+) {
+    let screen = frame.size();
+    let popup_area = centered_popup(
+        screen,
+        CONFIRMATION_WIDTH.min(screen.width.saturating_sub(4)),
+        CONFIRMATION_HEIGHT.min(screen.height.saturating_sub(2)),
+    );
+
+    let block = Block::bordered()
+        .title(" Delete event ")
+        .title_bottom(
+            Line::from(keymap.hint(
+                scope,
+                &[(&[Action::Enter], "delete"), (&[Action::Back], "cancel")],
+            ))
+            .right_aligned(),
+        )
+        .padding(Padding::horizontal(1))
+        .style(theme.error_style().bg(theme.popup));
+
+    let confirmation = Paragraph::new(format!("Delete \"{}\"?", event.title))
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(confirmation, popup_area);
+}
+
+/// Every line of the details popup, before wrapping
+fn detail_lines(
+    event: &Event,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) -> Vec<Line<'static>> {
+    let label_style = theme.muted_style();
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", label), label_style),
+            Span::raw(value),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::styled(event.title.to_owned(), Style::new().bold()),
+        Line::default(),
+        field("When", describe_time(&event.time, config, display_timezone)),
+    ];
+
+    if let Some(recurrence) = &event.recurrence {
+        let instance_start = match event.original_start.unwrap_or(event.time.start()) {
+            EventStart::Instant(start) => start.with_timezone(&recurrence.time_zone).naive_local(),
+            EventStart::Floating(start) => start,
+            EventStart::Date(date) => date.and_time(NaiveTime::MIN),
+        };
+        lines.push(field("Repeats", recurrence.series_summary(instance_start)));
+    }
+    if let Some(location) = &event.location {
+        lines.push(field("Where", location.to_owned()));
+    }
+    if let Some(organizer) = &event.organizer {
+        let name = organizer
+            .display_name
+            .as_ref()
+            .or(organizer.email.as_ref())
+            .cloned()
+            .unwrap_or_default();
+        lines.push(field("Organizer", name));
+    }
+    lines.push(field(
+        "Calendar",
+        event
+            .calendar_title
+            .to_owned()
+            .unwrap_or(event.calendar_id.to_owned()),
+    ));
+    lines.push(field("Id", event.id.to_owned()));
+
+    if !event.attendees.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            format!("Attendees ({})", event.attendees.len()),
+            label_style,
+        ));
+        lines.extend(
+            event
+                .attendees
+                .iter()
+                .map(|attendee| attendee_line(attendee, theme)),
+        );
+    }
+
+    let links = event
+        .html_link
+        .iter()
+        .map(|link| ("Calendar", link))
+        .chain(event.hangout_link.iter().map(|link| ("Meet", link)))
+        .chain(
+            event
+                .conference_entry_points
+                .iter()
+                .filter(|entry_point| event.hangout_link.as_ref() != Some(&entry_point.uri))
+                .map(|entry_point| (entry_point.entry_point_type.as_str(), &entry_point.uri)),
+        )
+        .collect::<Vec<_>>();
+    if !links.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::styled("Links", label_style));
+        lines.extend(
+            links
+                .into_iter()
+                .map(|(label, link)| field(label, link.to_owned())),
+        );
+    }
+
+    if let Some(description) = &event.description {
+        lines.push(Line::default());
+        lines.extend(
+            strip_html(description)
+                .lines()
+                .map(|line| Line::raw(line.to_owned())),
+        );
+    }
+
+    lines
+}
+
+/// Example: "Mon 3 Jun 2024, 09:00 – 10:30 (1h 30m)"
+fn describe_time(time: &EventTime, config: &Config, display_timezone: DisplayTimezone) -> String {
+    let date_format = config.short_date_format.as_str();
+    let full_date_format = format!("{} %Y", date_format);
+    match time {
+        EventTime::AllDay { start, end } => {
+            let days = (*end - *start).num_days();
+            if days <= 1 {
+                format!("{}, all day", start.format(&full_date_format))
+            } else {
+                let last_day = *end - Duration::days(1);
+                format!(
+                    "{} – {} ({} days)",
+                    start.format(date_format),
+                    last_day.format(&full_date_format),
+                    days
+                )
+            }
+        }
+        _ => {
+            let start = display_timezone.to_local(&time.start_instant(display_timezone));
+            let end = display_timezone.to_local(&time.end_instant(display_timezone));
+            let date_time_format = config.date_time_format();
+            let end_format = if start.date() == end.date() {
+                config.time_format()
+            } else {
+                &date_time_format
+            };
+
+            format!(
+                "{}, {} – {} ({})",
+                start.format(&full_date_format),
+                start.format(config.time_format()),
+                end.format(end_format),
+                format_duration(time.duration())
+            )
+        }
+    }
+}
+
+/// Example: "1h 30m"
+fn format_duration(duration: Duration) -> String {
+    let hours = duration.num_hours();
+    let minutes = duration.num_minutes() % 60;
+
+    match (hours, minutes) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+/// Marker, description and style of a reply to an invitation
+pub fn response_marker(
+    response: ResponseStatus,
+    theme: &Theme,
+) -> (&'static str, &'static str, Style) {
+    match response {
+        ResponseStatus::Accepted => (theme.symbol("✓", "+"), "accepted", theme.success_style()),
+        ResponseStatus::Declined => (theme.symbol("✗", "x"), "declined", theme.error_style()),
+        ResponseStatus::Tentative => ("?", "maybe", theme.warning_style()),
+        ResponseStatus::NeedsAction => (
+            theme.symbol("·", "."),
+            "awaiting reply",
+            theme.muted_style(),
+        ),
+        ResponseStatus::Unknown => (theme.symbol("·", "."), "unknown reply", theme.muted_style()),
+    }
+}
+
+fn attendee_line(attendee: &Attendee, theme: &Theme) -> Line<'static> {
+    let (marker, response, marker_style) = response_marker(attendee.response_status, theme);
+
+    let name = attendee
+        .display_name
+        .as_ref()
+        .or(attendee.email.as_ref())
+        .cloned()
+        .unwrap_or_default();
+    let mut notes = vec![response];
+    if attendee.organizer {
+        notes.push("organizer");
+    }
+    if attendee.optional {
+        notes.push("optional");
+    }
+    if attendee.is_self {
+        notes.push("you");
+    }
+
+    Line::from(vec![
+        Span::styled(format!("{} ", marker), marker_style.bold()),
+        Span::raw(name),
+        Span::styled(format!(" ({})", notes.join(", ")), theme.muted_style()),
+    ])
+}
@@ -0,0 +1,204 @@
+use chrono::{Days, Duration, DurationRound};
+
+use crate::domain::{
+    calendar::Calendar,
+    events::{Event, EventTime},
+    timezone::DisplayTimezone,
+};
+
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+pub const TIME_FORMAT: &str = "%H:%M";
+
+/// Times offered when an all day event is switched to a timed one
+const DEFAULT_START_TIME: &str = "09:00";
+const DEFAULT_END_TIME: &str = "10:00";
+
+/// A new or existing event being filled in before it is sent to Google
+#[derive(Debug)]
+pub struct EventFormState {
+    /// The event as it was before being edited, none for new events
+    pub editing: Option<Box<Event>>,
+    /// Calendars the event can be created on, only those the account can write to
+    pub calendars: Vec<Calendar>,
+    pub selected_calendar: usize,
+    pub title: String,
+    pub start_date: String,
+    pub start_time: String,
+    pub end_date: String,
+    pub end_time: String,
+    pub all_day: bool,
+    pub location: String,
+    pub description: String,
+    /// Comma separated email addresses
+    pub attendees: String,
+    pub focused: FormField,
+    /// Why the last attempt to save the event failed
+    pub error: Option<String>,
+    /// A new event has been sent to Google and the form is waiting to hear back
+    pub saving: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormField {
+    Title,
+    Calendar,
+    StartDate,
+    StartTime,
+    EndDate,
+    EndTime,
+    AllDay,
+    Location,
+    Description,
+    Attendees,
+}
+
+const FIELD_ORDER: [FormField; 10] = [
+    FormField::Title,
+    FormField::Calendar,
+    FormField::StartDate,
+    FormField::StartTime,
+    FormField::EndDate,
+    FormField::EndTime,
+    FormField::AllDay,
+    FormField::Location,
+    FormField::Description,
+    FormField::Attendees,
+];
+
+impl EventFormState {
+    /// An empty form for an hour long event starting at the next hour
+    pub fn new(calendars: Vec<Calendar>, display_timezone: DisplayTimezone) -> Self {
+        let now = display_timezone.to_local(&chrono::Utc::now());
+        let start = now
+            .duration_trunc(Duration::hours(1))
+            .expect("an hour is a valid rounding")
+            + Duration::hours(1);
+        let end = start + Duration::hours(1);
+
+        Self {
+            editing: None,
+            calendars,
+            selected_calendar: 0,
+            title: String::new(),
+            start_date: start.format(DATE_FORMAT).to_string(),
+            start_time: start.format(TIME_FORMAT).to_string(),
+            end_date: end.format(DATE_FORMAT).to_string(),
+            end_time: end.format(TIME_FORMAT).to_string(),
+            all_day: false,
+            location: String::new(),
+            description: String::new(),
+            attendees: String::new(),
+            focused: FormField::Title,
+            error: None,
+            saving: false,
+        }
+    }
+
+    /// A form filled in with an existing event, which stays on its calendar
+    pub fn edit(event: Event, calendar: Calendar, display_timezone: DisplayTimezone) -> Self {
+        let (start_date, start_time, end_date, end_time) = match event.time {
+            EventTime::AllDay { start, end } => (
+                start.format(DATE_FORMAT).to_string(),
+                DEFAULT_START_TIME.to_owned(),
+                // Shown as the last day of the event rather than Google's exclusive end
+                (end - Days::new(1)).format(DATE_FORMAT).to_string(),
+                DEFAULT_END_TIME.to_owned(),
+            ),
+            _ => {
+                let start = display_timezone.to_local(&event.time.start_instant(display_timezone));
+                let end = display_timezone.to_local(&event.time.end_instant(display_timezone));
+                (
+                    start.format(DATE_FORMAT).to_string(),
+                    start.format(TIME_FORMAT).to_string(),
+                    end.format(DATE_FORMAT).to_string(),
+                    end.format(TIME_FORMAT).to_string(),
+                )
+            }
+        };
+        let attendees = event
+            .attendees
+            .iter()
+            .filter_map(|attendee| attendee.email.as_deref())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Self {
+            calendars: vec![calendar],
+            selected_calendar: 0,
+            title: event.title.to_owned(),
+            start_date,
+            start_time,
+            end_date,
+            end_time,
+            all_day: event.time.is_all_day(),
+            location: event.location.to_owned().unwrap_or_default(),
+            description: event.description.to_owned().unwrap_or_default(),
+            attendees,
+            focused: FormField::Title,
+            error: None,
+            saving: false,
+            editing: Some(Box::new(event)),
+        }
+    }
+
+    pub fn calendar(&self) -> Option<&Calendar> {
+        self.calendars.get(self.selected_calendar)
+    }
+
+    /// Whether the field is shown, times are hidden for all day events
+    pub fn is_visible(&self, field: FormField) -> bool {
+        !(self.all_day && matches!(field, FormField::StartTime | FormField::EndTime))
+    }
+
+    /// The text typed into the focused field, if it takes text
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        match self.focused {
+            FormField::Title => Some(&mut self.title),
+            FormField::StartDate => Some(&mut self.start_date),
+            FormField::StartTime => Some(&mut self.start_time),
+            FormField::EndDate => Some(&mut self.end_date),
+            FormField::EndTime => Some(&mut self.end_time),
+            FormField::Location => Some(&mut self.location),
+            FormField::Description => Some(&mut self.description),
+            FormField::Attendees => Some(&mut self.attendees),
+            FormField::Calendar | FormField::AllDay => None,
+        }
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focused = self.step_focus(1);
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focused = self.step_focus(FIELD_ORDER.len() - 1);
+    }
+
+    /// Move focus forward by the given number of fields, skipping hidden ones
+    fn step_focus(&self, step: usize) -> FormField {
+        let mut index = FIELD_ORDER
+            .iter()
+            .position(|field| *field == self.focused)
+            .unwrap_or(0);
+
+        loop {
+            index = (index + step) % FIELD_ORDER.len();
+            if self.is_visible(FIELD_ORDER[index]) {
+                return FIELD_ORDER[index];
+            }
+        }
+    }
+
+    /// Cycle through the writable calendars
+    pub fn cycle_calendar(&mut self, forwards: bool) {
+        if self.calendars.is_empty() {
+            return;
+        }
+
+        let count = self.calendars.len();
+        self.selected_calendar = if forwards {
+            (self.selected_calendar + 1) % count
+        } else {
+            (self.selected_calendar + count - 1) % count
+        };
+    }
+}
@@ -0,0 +1,467 @@
+use chrono::{DateTime, Days, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::domain::{
+    events::{Attendee, Event, EventTime, ResponseStatus},
+    timezone::DisplayTimezone,
+};
+
+use super::event_form_state::{EventFormState, DATE_FORMAT, TIME_FORMAT};
+
+// https://developers.google.com/calendar/api/v3/reference/events/insert
+// Optional fields are sent as null when empty so that patches clear them
+#[derive(Serialize, Debug, PartialEq)]
+pub struct EventRequest {
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub start: EventDateRequest,
+    pub end: EventDateRequest,
+    /// Left out when editing an event without changing who is invited, so
+    /// attendees keep their replies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attendees: Option<Vec<AttendeeRequest>>,
+}
+
+/// Only one of the fields is set, the other is sent as null so that switching
+/// an event between timed and all day replaces the old value
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventDateRequest {
+    pub date_time: Option<DateTime<Utc>>,
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttendeeRequest {
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<ResponseStatus>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    // Google replaces attendees when patching, so anything left out is lost
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub organizer: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub resource: bool,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub additional_guests: u32,
+    #[serde(rename = "self", skip_serializing_if = "std::ops::Not::not")]
+    pub is_self: bool,
+}
+
+impl From<&Attendee> for AttendeeRequest {
+    fn from(attendee: &Attendee) -> Self {
+        Self {
+            email: attendee.email.to_owned().unwrap_or_default(),
+            // Google wouldn't accept a reply it's told us about but we don't know
+            response_status: (attendee.response_status != ResponseStatus::Unknown)
+                .then_some(attendee.response_status),
+            optional: attendee.optional,
+            comment: attendee.comment.to_owned(),
+            display_name: attendee.display_name.to_owned(),
+            organizer: attendee.organizer,
+            resource: attendee.resource,
+            additional_guests: attendee.additional_guests,
+            is_self: attendee.is_self,
+        }
+    }
+}
+
+impl AttendeeRequest {
+    /// Someone not yet invited, Google fills in the rest
+    pub fn invite(email: &str) -> Self {
+        Self {
+            email: email.to_owned(),
+            response_status: None,
+            optional: false,
+            comment: None,
+            display_name: None,
+            organizer: false,
+            resource: false,
+            additional_guests: 0,
+            is_self: false,
+        }
+    }
+}
+
+fn is_zero(count: &u32) -> bool {
+    *count == 0
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EventFormError {
+    #[error("The event needs a title")]
+    MissingTitle,
+    #[error("There are no calendars the event can be added to")]
+    NoCalendar,
+    #[error("Invalid date \"{0}\", expected YYYY-MM-DD")]
+    InvalidDate(String),
+    #[error("Invalid time \"{0}\", expected HH:MM")]
+    InvalidTime(String),
+    #[error("The event must end after it starts")]
+    EndBeforeStart,
+    #[error("Invalid attendee email \"{0}\"")]
+    InvalidAttendee(String),
+}
+
+impl EventRequest {
+    /// Validate the form, turning it into the body of an insert or patch request.
+    /// Times are entered in the display time zone
+    pub fn from_form(
+        form: &EventFormState,
+        display_timezone: DisplayTimezone,
+    ) -> Result<Self, EventFormError> {
+        let summary = form.title.trim();
+        if summary.is_empty() {
+            return Err(EventFormError::MissingTitle);
+        }
+        if form.calendar().is_none() {
+            return Err(EventFormError::NoCalendar);
+        }
+
+        let start_date = parse_date(&form.start_date)?;
+        let end_date = parse_date(&form.end_date)?;
+
+        let (start, end) = if form.all_day {
+            if end_date < start_date {
+                return Err(EventFormError::EndBeforeStart);
+            }
+
+            // The form's end date is the last day of the event, Google's is exclusive
+            (
+                EventDateRequest::date(start_date),
+                EventDateRequest::date(end_date + Days::new(1)),
+            )
+        } else {
+            let start = NaiveDateTime::new(start_date, parse_time(&form.start_time)?);
+            let end = NaiveDateTime::new(end_date, parse_time(&form.end_time)?);
+            if end <= start {
+                return Err(EventFormError::EndBeforeStart);
+            }
+
+            (
+                EventDateRequest::date_time(display_timezone.to_utc(&start)),
+                EventDateRequest::date_time(display_timezone.to_utc(&end)),
+            )
+        };
+
+        let emails = form
+            .attendees
+            .split(',')
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(|email| {
+                if email.contains('@') {
+                    Ok(email)
+                } else {
+                    Err(EventFormError::InvalidAttendee(email.to_owned()))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let existing_attendees = form
+            .editing
+            .as_ref()
+            .map(|event| event.attendees.as_slice())
+            .unwrap_or_default();
+        let is_unchanged = emails.len() == existing_attendees.len()
+            && existing_attendees
+                .iter()
+                .all(|attendee| emails.contains(&attendee.email.as_deref().unwrap_or_default()));
+        let attendees = if is_unchanged {
+            None
+        } else {
+            Some(
+                emails
+                    .into_iter()
+                    .map(|email| {
+                        existing_attendees
+                            .iter()
+                            .find(|attendee| attendee.email.as_deref() == Some(email))
+                            .map(AttendeeRequest::from)
+                            .unwrap_or_else(|| AttendeeRequest::invite(email))
+                    })
+                    .collect(),
+            )
+        };
+
+        Ok(Self {
+            summary: summary.to_owned(),
+            location: non_empty(&form.location),
+            description: non_empty(&form.description),
+            start,
+            end,
+            attendees,
+        })
+    }
+
+    pub fn time(&self) -> EventTime {
+        match (
+            self.start.date_time,
+            self.end.date_time,
+            self.start.date,
+            self.end.date,
+        ) {
+            (Some(start), Some(end), _, _) => EventTime::Timed { start, end },
+            (_, _, Some(start), Some(end)) => EventTime::AllDay { start, end },
+            _ => unreachable!("requests are built with both a start and end"),
+        }
+    }
+
+    /// The event as it will be once the request has been applied, used to show
+    /// changes before Google has confirmed them
+    pub fn apply_to(&self, event: &Event) -> Event {
+        let mut updated = event.clone();
+        updated.title = self.summary.to_owned();
+        updated.location = self.location.to_owned();
+        updated.description = self.description.to_owned();
+        updated.time = self.time();
+
+        if let Some(attendees) = &self.attendees {
+            updated.attendees = attendees
+                .iter()
+                .map(|request| {
+                    event
+                        .attendees
+                        .iter()
+                        .find(|attendee| attendee.email.as_ref() == Some(&request.email))
+                        .cloned()
+                        .unwrap_or(Attendee {
+                            email: Some(request.email.to_owned()),
+                            display_name: None,
+                            response_status: ResponseStatus::NeedsAction,
+                            comment: None,
+                            optional: false,
+                            organizer: false,
+                            resource: false,
+                            additional_guests: 0,
+                            is_self: false,
+                        })
+                })
+                .collect();
+        }
+
+        updated
+    }
+}
+
+impl EventDateRequest {
+    pub fn date_time(date_time: DateTime<Utc>) -> Self {
+        Self {
+            date_time: Some(date_time),
+            date: None,
+        }
+    }
+
+    pub fn date(date: NaiveDate) -> Self {
+        Self {
+            date_time: None,
+            date: Some(date),
+        }
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, EventFormError> {
+    NaiveDate::parse_from_str(value.trim(), DATE_FORMAT)
+        .map_err(|_| EventFormError::InvalidDate(value.to_owned()))
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, EventFormError> {
+    NaiveTime::parse_from_str(value.trim(), TIME_FORMAT)
+        .map_err(|_| EventFormError::InvalidTime(value.to_owned()))
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    use super::{EventFormError, EventRequest};
+    use crate::domain::{
+        calendar::Calendar,
+        events::{Attendee, Event, EventTime, ResponseStatus},
+        timezone::DisplayTimezone,
+    };
+    use crate::features::event_form::event_form_state::EventFormState;
+
+    fn calendar() -> Calendar {
+        Calendar {
+            calendar_id: "test@test.com".into(),
+            account_id: 1,
+            title: "Work".into(),
+            color_id: None,
+            background_color: None,
+            colour_override: None,
+            access_role: Some("owner".into()),
+        }
+    }
+
+    fn form() -> EventFormState {
+        let mut form = EventFormState::new(vec![calendar()], DisplayTimezone::Local);
+        form.title = "Planning".into();
+        form.start_date = "2024-06-03".into();
+        form.start_time = "09:30".into();
+        form.end_date = "2024-06-03".into();
+        form.end_time = "10:00".into();
+        form
+    }
+
+    fn attendee(email: &str, response_status: ResponseStatus) -> Attendee {
+        Attendee {
+            email: Some(email.into()),
+            display_name: None,
+            response_status,
+            comment: None,
+            optional: false,
+            organizer: false,
+            resource: false,
+            additional_guests: 0,
+            is_self: false,
+        }
+    }
+
+    fn event(attendees: Vec<Attendee>) -> Event {
+        let start = DateTime::parse_from_rfc3339("2024-06-03T08:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        Event {
+            id: "planning".into(),
+            etag: Some("\"1\"".into()),
+            title: "Planning".into(),
+            time: EventTime::Timed {
+                start,
+                end: start + chrono::Duration::minutes(30),
+            },
+            location: Some("Room 1".into()),
+            attendees,
+            calendar_title: Some("Work".into()),
+            ..Event::test_default()
+        }
+    }
+
+    #[test]
+    fn timed_events_are_sent_in_utc() {
+        // Arrange
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+        let mut form = form();
+        form.location = " Room 1 ".into();
+        form.attendees = "alice@test.com, bob@test.com,".into();
+
+        // Act
+        let request = EventRequest::from_form(&form, london).unwrap();
+
+        // Assert
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({
+                "summary": "Planning",
+                "location": "Room 1",
+                "description": null,
+                "start": { "dateTime": "2024-06-03T08:30:00Z", "date": null },
+                "end": { "dateTime": "2024-06-03T09:00:00Z", "date": null },
+                "attendees": [{ "email": "alice@test.com" }, { "email": "bob@test.com" }],
+            })
+        );
+    }
+
+    #[test]
+    fn all_day_events_include_their_last_day() {
+        // Arrange
+        let mut form = form();
+        form.all_day = true;
+        form.end_date = "2024-06-05".into();
+
+        // Act
+        let request = EventRequest::from_form(&form, DisplayTimezone::Local).unwrap();
+
+        // Assert
+        assert_eq!(
+            serde_json::to_value(&request.start).unwrap(),
+            json!({ "dateTime": null, "date": "2024-06-03" })
+        );
+        assert_eq!(
+            serde_json::to_value(&request.end).unwrap(),
+            json!({ "dateTime": null, "date": "2024-06-06" })
+        );
+    }
+
+    #[test]
+    fn edits_keep_attendee_replies() {
+        // Arrange
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+        let event = event(vec![attendee("alice@test.com", ResponseStatus::Accepted)]);
+        let mut unchanged = EventFormState::edit(event.clone(), calendar(), london);
+        unchanged.location = String::new();
+        let mut invited = EventFormState::edit(event.clone(), calendar(), london);
+        invited.attendees.push_str(", bob@test.com");
+
+        // Act
+        let unchanged = EventRequest::from_form(&unchanged, london).unwrap();
+        let invited = EventRequest::from_form(&invited, london).unwrap();
+
+        // Assert
+        assert_eq!(unchanged.attendees, None);
+        assert_eq!(unchanged.apply_to(&event).location, None);
+        assert_eq!(unchanged.apply_to(&event).time, event.time);
+        assert_eq!(
+            serde_json::to_value(&invited.attendees).unwrap(),
+            json!([
+                { "email": "alice@test.com", "responseStatus": "accepted" },
+                { "email": "bob@test.com" },
+            ])
+        );
+        assert_eq!(
+            invited.apply_to(&event).attendees,
+            vec![
+                attendee("alice@test.com", ResponseStatus::Accepted),
+                attendee("bob@test.com", ResponseStatus::NeedsAction),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_forms() {
+        let london = DisplayTimezone::from_name("Europe/London").unwrap();
+
+        let mut untitled = form();
+        untitled.title = "  ".into();
+        assert_eq!(
+            EventRequest::from_form(&untitled, london),
+            Err(EventFormError::MissingTitle)
+        );
+
+        let mut backwards = form();
+        backwards.end_time = "09:00".into();
+        assert_eq!(
+            EventRequest::from_form(&backwards, london),
+            Err(EventFormError::EndBeforeStart)
+        );
+
+        let mut bad_time = form();
+        bad_time.start_time = "9.30".into();
+        assert_eq!(
+            EventRequest::from_form(&bad_time, london),
+            Err(EventFormError::InvalidTime("9.30".into()))
+        );
+
+        let mut bad_attendee = form();
+        bad_attendee.attendees = "alice".into();
+        assert_eq!(
+            EventRequest::from_form(&bad_attendee, london),
+            Err(EventFormError::InvalidAttendee("alice".into()))
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use color_eyre::eyre::Result;
+use eyre::Context;
+
+use crate::{
+    domain::events::Event,
+    features::{
+        fetch_events::deserialise_event_response::deserialise_event_response,
+        oauth_http_client::GoogleOAuthClient,
+    },
+};
+
+use super::event_request::EventRequest;
+
+/// Create an event on a calendar, returning the event as Google stored it
+#[tracing::instrument(name = "Insert event", skip(google_client))]
+pub async fn insert_event(
+    request: &EventRequest,
+    calendar_id: &str,
+    account_id: i64,
+    google_client: &GoogleOAuthClient,
+) -> Result<Event> {
+    let insert_url = format!(
+        "https://www.googleapis.com/calendar/v3/calendars/{}/events",
+        urlencoding::encode(calendar_id)
+    );
+
+    let http_client = reqwest::Client::new();
+    let mut insert_request = http_client.post(insert_url).json(request);
+    // Google only emails invitations when asked to
+    if request
+        .attendees
+        .as_ref()
+        .is_some_and(|attendees| !attendees.is_empty())
+    {
+        insert_request = insert_request.query(&[("sendUpdates", "all")]);
+    }
+
+    let response = google_client
+        .send(account_id, insert_request)
+        .await?
+        .error_for_status()
+        .wrap_err("Google rejected the new event")?;
+
+    deserialise_event_response(response, calendar_id, account_id).await
+}
@@ -0,0 +1,6 @@
+pub(crate) mod event_form_state;
+pub(crate) mod event_request;
+pub(crate) mod insert_event;
+pub(crate) mod retrieve_calendars;
+pub(crate) mod update_event_form;
+pub(crate) mod view;
@@ -0,0 +1,24 @@
+use color_eyre::eyre::Result;
+use eyre::Context;
+use sqlx::SqlitePool;
+
+use crate::domain::calendar::Calendar;
+
+/// Calendars the signed in accounts can create events on, primary calendars first
+pub async fn retrieve_writable_calendars(db: &SqlitePool) -> Result<Vec<Calendar>> {
+    let calendars = sqlx::query_as!(
+        Calendar,
+        "SELECT calendar_id, account_id, title, color_id, background_color, colour_override,
+            access_role
+        FROM calendars
+        ORDER BY primary_calendar DESC"
+    )
+    .fetch_all(db)
+    .await
+    .wrap_err("error while retrieving stored calendars")?;
+
+    Ok(calendars
+        .into_iter()
+        .filter(|calendar| calendar.is_writable())
+        .collect())
+}
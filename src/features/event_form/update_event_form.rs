@@ -0,0 +1,183 @@
+use color_eyre::eyre::Result;
+
+use crate::{
+    domain::{calendar::Calendar, events::Event},
+    features::{
+        calendar_colours::colour_palette::ColourPalette,
+        event_changes::update_event_changes::run_patch_event_task,
+    },
+    tui::model::{CurrentState, EventsState, Message, Model},
+};
+
+use super::{
+    event_form_state::{EventFormState, FormField},
+    event_request::EventRequest,
+    insert_event::insert_event,
+    retrieve_calendars::retrieve_writable_calendars,
+};
+
+/// Open an empty form for a new event
+pub async fn handle_new_event(model: &mut Model) -> Result<()> {
+    let calendars = retrieve_writable_calendars(&model.application.db).await?;
+    let form = EventFormState::new(calendars, model.application.display_timezone);
+
+    model.current_state = CurrentState::EventForm(Box::new(form));
+    Ok(())
+}
+
+pub fn handle_char_message(model: &mut Model, c: char) {
+    let CurrentState::EventForm(ref mut form) = model.current_state else {
+        return;
+    };
+
+    match form.focused {
+        FormField::AllDay if c == ' ' => form.all_day = !form.all_day,
+        FormField::Calendar if c == ' ' => form.cycle_calendar(true),
+        _ => {
+            if let Some(text) = form.focused_text_mut() {
+                text.push(c);
+            }
+        }
+    }
+}
+
+pub fn handle_backspace_message(model: &mut Model) {
+    let CurrentState::EventForm(ref mut form) = model.current_state else {
+        return;
+    };
+
+    if let Some(text) = form.focused_text_mut() {
+        text.pop();
+    }
+}
+
+pub fn handle_next_field_message(model: &mut Model) {
+    if let CurrentState::EventForm(ref mut form) = model.current_state {
+        form.focus_next();
+    }
+}
+
+pub fn handle_previous_field_message(model: &mut Model) {
+    if let CurrentState::EventForm(ref mut form) = model.current_state {
+        form.focus_previous();
+    }
+}
+
+/// Left and right pick between the options of the calendar and all day fields
+pub fn handle_horizontal_message(model: &mut Model, forwards: bool) {
+    let CurrentState::EventForm(ref mut form) = model.current_state else {
+        return;
+    };
+
+    match form.focused {
+        FormField::Calendar => form.cycle_calendar(forwards),
+        FormField::AllDay => form.all_day = !form.all_day,
+        _ => {}
+    }
+}
+
+/// Create the event, showing it straight away if it was saved or keeping the
+/// form open with the reason if not. Edits are shown before they are saved and
+/// rolled back if they fail
+pub fn handle_submit_message(model: &mut Model) {
+    let display_timezone = model.application.display_timezone;
+    let CurrentState::EventForm(ref mut form) = model.current_state else {
+        return;
+    };
+    if form.saving {
+        return;
+    }
+
+    let request = match EventRequest::from_form(form, display_timezone) {
+        Ok(request) => request,
+        Err(e) => {
+            form.error = Some(e.to_string());
+            return;
+        }
+    };
+
+    if let Some(original) = form.editing.take() {
+        model.current_state = CurrentState::calendar(model.application.config.default_view);
+        let updated = request.apply_to(&original);
+        run_patch_event_task(request, updated, *original, model);
+        return;
+    }
+
+    let calendar = form
+        .calendar()
+        .expect("validated forms have a calendar")
+        .to_owned();
+
+    form.error = None;
+    form.saving = true;
+    run_insert_event_task(request, calendar, model);
+}
+
+/// Create an event on Google in the background, keeping the form or prompt that
+/// described it open until it is saved
+pub fn run_insert_event_task(request: EventRequest, calendar: Calendar, model: &Model) {
+    let google_client = model.application.google_client.clone();
+    let message_channel = model.message_channel.clone();
+
+    tokio::spawn(async move {
+        let message = match insert_event(
+            &request,
+            &calendar.calendar_id,
+            calendar.account_id,
+            &google_client,
+        )
+        .await
+        {
+            Ok(event) => Message::EventCreated(Box::new(event), Box::new(calendar)),
+            Err(e) => {
+                tracing::error!("Failed to create event: {:?}", e);
+                Message::EventCreateFailed(format!("Failed to create event: {}", e))
+            }
+        };
+
+        message_channel
+            .send(message)
+            .expect("Message channel should never be closed");
+    });
+}
+
+/// Show the created event and close the form or prompt it came from
+pub fn handle_event_created(model: &mut Model, event: Event, calendar: &Calendar) {
+    show_created_event(model, event, calendar);
+    if matches!(
+        model.current_state,
+        CurrentState::EventForm(_) | CurrentState::QuickAdd(_)
+    ) {
+        model.current_state = CurrentState::calendar(model.application.config.default_view);
+    }
+}
+
+/// Keep the form or prompt open with why the event couldn't be created, or
+/// notify if it has already been closed
+pub fn handle_event_create_failed(model: &mut Model, error: String) {
+    match &mut model.current_state {
+        CurrentState::EventForm(form) if form.saving => {
+            form.saving = false;
+            form.error = Some(error);
+        }
+        CurrentState::QuickAdd(state) if state.saving => {
+            state.saving = false;
+            state.error = Some(error);
+        }
+        _ => model.notification = Some(error),
+    }
+}
+
+/// Add a newly created event to those shown, styled like the rest of its calendar
+pub fn show_created_event(model: &mut Model, mut event: Event, calendar: &Calendar) {
+    event.colour = ColourPalette::default().calendar_colour(calendar);
+    event.calendar_title = Some(calendar.title.to_owned());
+
+    if let EventsState::Ready(events) = &mut model.events_state {
+        events.push(event);
+    }
+}
+
+pub fn handle_back_message(model: &mut Model) {
+    model.current_state = CurrentState::calendar(model.application.config.default_view);
+}
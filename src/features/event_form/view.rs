@@ -0,0 +1,109 @@
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::domain::theme::Theme;
+use crate::tui::util::centered_popup;
+
+use super::event_form_state::{EventFormState, FormField};
+
+const POPUP_WIDTH: u16 = 64;
+const POPUP_HEIGHT: u16 = 16;
+
+pub fn render(frame: &mut Frame, state: &EventFormState, theme: &Theme) {
+    let screen = frame.size();
+    let popup_area = centered_popup(
+        screen,
+        POPUP_WIDTH.min(screen.width.saturating_sub(4)),
+        POPUP_HEIGHT.min(screen.height.saturating_sub(2)),
+    );
+
+    let block = Block::bordered()
+        .title(if state.editing.is_some() {
+            " Edit event "
+        } else {
+            " New event "
+        })
+        .title_bottom(Line::from(" tab next · enter save · esc cancel ").right_aligned())
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.popup).fg(theme.text));
+
+    let calendar = match state.calendar() {
+        Some(calendar) if state.calendars.len() > 1 => format!("◂ {} ▸", calendar.title),
+        Some(calendar) => calendar.title.to_owned(),
+        None => "No writable calendars".to_owned(),
+    };
+    let all_day = if state.all_day { "[x]" } else { "[ ]" }.to_owned();
+
+    let fields = [
+        (FormField::Title, "Title", state.title.to_owned()),
+        (FormField::Calendar, "Calendar", calendar),
+        (
+            FormField::StartDate,
+            "Start date",
+            state.start_date.to_owned(),
+        ),
+        (
+            FormField::StartTime,
+            "Start time",
+            state.start_time.to_owned(),
+        ),
+        (FormField::EndDate, "End date", state.end_date.to_owned()),
+        (FormField::EndTime, "End time", state.end_time.to_owned()),
+        (FormField::AllDay, "All day", all_day),
+        (FormField::Location, "Location", state.location.to_owned()),
+        (
+            FormField::Description,
+            "Notes",
+            state.description.to_owned(),
+        ),
+        (
+            FormField::Attendees,
+            "Attendees",
+            state.attendees.to_owned(),
+        ),
+    ];
+
+    let label_style = theme.muted_style();
+    let focused_style = theme.selected_style();
+
+    let mut lines = fields
+        .into_iter()
+        .filter(|(field, _, _)| state.is_visible(*field))
+        .map(|(field, label, value)| {
+            let is_focused = field == state.focused;
+            // Text fields show a cursor at the end of what has been typed
+            let cursor = if is_focused && !matches!(field, FormField::Calendar | FormField::AllDay)
+            {
+                "▏"
+            } else {
+                ""
+            };
+            let line = Line::from(vec![
+                Span::styled(format!("{:<12}", label), label_style),
+                Span::raw(value),
+                Span::raw(cursor),
+            ]);
+
+            if is_focused {
+                line.style(focused_style)
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if state.saving {
+        lines.push(Line::default());
+        lines.push(Line::styled("Saving…", theme.muted_style().italic()));
+    } else if let Some(error) = &state.error {
+        lines.push(Line::default());
+        lines.push(Line::styled(error.to_owned(), theme.error_style()));
+    }
+
+    let form = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(form, popup_area);
+}
@@ -1,13 +1,33 @@
-use chrono::{DateTime, NaiveDate, Utc};
-use color_eyre::eyre::Result;
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use color_eyre::eyre::{eyre, Result};
 use reqwest::Response;
 use serde::Deserialize;
 
-use crate::domain::events::{DayEvent, Event};
+use crate::domain::{
+    events::{
+        Attendee, ConferenceEntryPoint, Event, EventStart, EventStatus, EventTime, EventType,
+        Person, Reminder, ResponseStatus, Transparency, Visibility,
+    },
+    recurrence::Recurrence,
+    timezone::localise,
+};
 
+/// Title given to events Google returns without a summary, e.g. private events
+/// on shared calendars
+pub const UNTITLED_EVENT_TITLE: &str = "(No title)";
+
+// Items are kept as raw json so that one malformed event can be skipped without
+// losing the rest of the calendar
 #[derive(Deserialize, Debug)]
 pub struct EventListResponse {
-    items: Vec<EventResource>,
+    #[serde(rename = "timeZone")]
+    time_zone: Option<String>,
+    /// Reminders for events on the calendar which don't set their own
+    #[serde(rename = "defaultReminders", default)]
+    default_reminders: Vec<Reminder>,
+    #[serde(default)]
+    items: Vec<serde_json::Value>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -31,30 +51,48 @@ pub enum ConfirmedEvent {
 #[derive(Deserialize, Debug)]
 pub struct ConfirmedEventResource {
     id: String,
-    summary: String,
+    summary: Option<String>,
     description: Option<String>,
     start: DateObject,
     end: DateObject,
+    #[serde(flatten)]
+    details: EventDetailsResource,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct ConfirmedDayEventResource {
     id: String,
-    summary: String,
+    summary: Option<String>,
     description: Option<String>,
     start: DayDateObject,
     end: DayDateObject,
+    #[serde(flatten)]
+    details: EventDetailsResource,
 }
 
 #[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct CancelledEventResource {
     id: String,
+    recurring_event_id: Option<String>,
+    original_start_time: Option<OriginalStartObject>,
 }
 
 #[derive(Deserialize, Debug)]
 struct DateObject {
     #[serde(rename = "dateTime")]
-    date_time: DateTime<Utc>,
+    date_time: DateTimeValue,
+    #[serde(rename = "timeZone")]
+    time_zone: Option<String>,
+}
+
+/// Google normally includes an offset in date times but they can be floating
+/// wall clock times in the event or calendar time zone
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum DateTimeValue {
+    Instant(DateTime<FixedOffset>),
+    Floating(NaiveDateTime),
 }
 
 #[derive(Deserialize, Debug)]
@@ -62,60 +100,454 @@ struct DayDateObject {
     date: NaiveDate,
 }
 
-impl From<&ConfirmedEventResource> for Event {
-    fn from(event_resource: &ConfirmedEventResource) -> Self {
+#[derive(Deserialize, Debug)]
+struct OriginalStartObject {
+    #[serde(rename = "dateTime")]
+    date_time: Option<DateTimeValue>,
+    date: Option<NaiveDate>,
+    #[serde(rename = "timeZone")]
+    time_zone: Option<String>,
+}
+
+impl DateTimeValue {
+    /// The instant this time happens at, floating times need a time zone
+    fn to_utc(&self, time_zone: Option<Tz>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Instant(date_time) => Some(date_time.with_timezone(&Utc)),
+            Self::Floating(date_time) => time_zone.map(|tz| localise(&tz, date_time)),
+        }
+    }
+
+    /// Wall clock time in the given time zone, or as written without one
+    fn to_local(&self, time_zone: Option<Tz>) -> NaiveDateTime {
+        match (self, time_zone) {
+            (Self::Instant(date_time), Some(tz)) => date_time.with_timezone(&tz).naive_local(),
+            (Self::Instant(date_time), None) => date_time.naive_local(),
+            (Self::Floating(date_time), _) => *date_time,
+        }
+    }
+
+    fn to_start(&self, time_zone: Option<Tz>) -> EventStart {
+        match self.to_utc(time_zone) {
+            Some(instant) => EventStart::Instant(instant),
+            None => EventStart::Floating(self.to_local(time_zone)),
+        }
+    }
+}
+
+impl DateObject {
+    /// Time zone of the event, falling back to the calendar's when the event
+    /// doesn't have one or it isn't recognised
+    fn time_zone(&self, calendar_time_zone: Option<Tz>) -> Option<Tz> {
+        parse_time_zone(self.time_zone.as_deref()).or(calendar_time_zone)
+    }
+}
+
+impl OriginalStartObject {
+    fn to_start(&self, calendar_time_zone: Option<Tz>) -> Option<EventStart> {
+        let time_zone = parse_time_zone(self.time_zone.as_deref()).or(calendar_time_zone);
+
+        match (&self.date_time, self.date) {
+            (Some(date_time), _) => Some(date_time.to_start(time_zone)),
+            (None, Some(date)) => Some(EventStart::Date(date)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Times are only left floating when neither the event nor its calendar say
+/// which time zone they are in
+fn event_time(start: &DateObject, end: &DateObject, calendar_time_zone: Option<Tz>) -> EventTime {
+    let start_zone = start.time_zone(calendar_time_zone);
+    let end_zone = end.time_zone(calendar_time_zone);
+
+    match (
+        start.date_time.to_utc(start_zone),
+        end.date_time.to_utc(end_zone),
+    ) {
+        (Some(start), Some(end)) => EventTime::Timed { start, end },
+        _ => EventTime::Floating {
+            start: start.date_time.to_local(start_zone),
+            end: end.date_time.to_local(end_zone),
+        },
+    }
+}
+
+fn parse_time_zone(name: Option<&str>) -> Option<Tz> {
+    name.and_then(|name| name.parse().ok())
+}
+
+/// Fields shared between timed and all day events
+// https://developers.google.com/calendar/api/v3/reference/events#resource
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct EventDetailsResource {
+    etag: Option<String>,
+    color_id: Option<String>,
+    location: Option<String>,
+    organizer: Option<PersonResource>,
+    #[serde(default)]
+    attendees: Vec<AttendeeResource>,
+    html_link: Option<String>,
+    hangout_link: Option<String>,
+    conference_data: Option<ConferenceDataResource>,
+    #[serde(default)]
+    transparency: Transparency,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    event_type: EventType,
+    #[serde(default)]
+    recurrence: Vec<String>,
+    recurring_event_id: Option<String>,
+    original_start_time: Option<OriginalStartObject>,
+    reminders: Option<RemindersResource>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RemindersResource {
+    #[serde(default)]
+    use_default: bool,
+    #[serde(default)]
+    overrides: Vec<Reminder>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PersonResource {
+    email: Option<String>,
+    display_name: Option<String>,
+    #[serde(rename = "self", default)]
+    is_self: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct AttendeeResource {
+    email: Option<String>,
+    display_name: Option<String>,
+    #[serde(default)]
+    response_status: ResponseStatus,
+    comment: Option<String>,
+    #[serde(default)]
+    optional: bool,
+    #[serde(default)]
+    organizer: bool,
+    #[serde(default)]
+    resource: bool,
+    #[serde(default)]
+    additional_guests: u32,
+    #[serde(rename = "self", default)]
+    is_self: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ConferenceDataResource {
+    #[serde(default)]
+    entry_points: Vec<EntryPointResource>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct EntryPointResource {
+    entry_point_type: String,
+    uri: String,
+    label: Option<String>,
+}
+
+impl From<&PersonResource> for Person {
+    fn from(person: &PersonResource) -> Self {
         Self {
-            id: event_resource.id.to_owned(),
-            title: event_resource.summary.to_owned(),
-            description: event_resource.description.to_owned(),
-            start_time: event_resource.start.date_time,
-            end_time: event_resource.end.date_time,
+            email: person.email.to_owned(),
+            display_name: person.display_name.to_owned(),
+            is_self: person.is_self,
         }
     }
 }
 
-impl From<&ConfirmedDayEventResource> for DayEvent {
-    fn from(event_resource: &ConfirmedDayEventResource) -> Self {
+impl From<&AttendeeResource> for Attendee {
+    fn from(attendee: &AttendeeResource) -> Self {
         Self {
-            id: event_resource.id.to_owned(),
-            title: event_resource.summary.to_owned(),
-            description: event_resource.description.to_owned(),
-            date: event_resource.start.date,
+            email: attendee.email.to_owned(),
+            display_name: attendee.display_name.to_owned(),
+            response_status: attendee.response_status,
+            comment: attendee.comment.to_owned(),
+            optional: attendee.optional,
+            organizer: attendee.organizer,
+            resource: attendee.resource,
+            additional_guests: attendee.additional_guests,
+            is_self: attendee.is_self,
         }
     }
 }
 
+impl From<&EntryPointResource> for ConferenceEntryPoint {
+    fn from(entry_point: &EntryPointResource) -> Self {
+        Self {
+            entry_point_type: entry_point.entry_point_type.to_owned(),
+            uri: entry_point.uri.to_owned(),
+            label: entry_point.label.to_owned(),
+        }
+    }
+}
+
+impl EventDetailsResource {
+    /// The recurrence of a series starting at the given time, dropping any rules we
+    /// can't understand so the first instance is still shown
+    fn recurrence(
+        &self,
+        event_id: &str,
+        start: NaiveDateTime,
+        time_zone: Tz,
+    ) -> Option<Recurrence> {
+        if self.recurrence.is_empty() {
+            return None;
+        }
+
+        match Recurrence::parse(&self.recurrence, start, time_zone) {
+            Ok(recurrence) => Some(recurrence),
+            Err(e) => {
+                tracing::warn!(event_id, "Failed to parse event recurrence: {}", e);
+                None
+            }
+        }
+    }
+
+    /// The event's own reminders, or the calendar's if it uses the defaults or
+    /// doesn't say
+    fn reminders(&self, default_reminders: &[Reminder]) -> Vec<Reminder> {
+        match &self.reminders {
+            Some(reminders) if !reminders.use_default => reminders.overrides.to_owned(),
+            _ => default_reminders.to_vec(),
+        }
+    }
+
+    fn conference_entry_points(&self) -> Vec<ConferenceEntryPoint> {
+        self.conference_data
+            .iter()
+            .flat_map(|data| data.entry_points.iter())
+            .map(ConferenceEntryPoint::from)
+            .collect()
+    }
+}
+
+impl ConfirmedEvent {
+    fn to_event(
+        &self,
+        status: EventStatus,
+        calendar_id: &str,
+        account_id: i64,
+        calendar_time_zone: Option<Tz>,
+        default_reminders: &[Reminder],
+    ) -> Event {
+        // Series repeat on the wall clock of the time zone they were created in
+        let (id, summary, description, details, time, recurrence_start, recurrence_zone) =
+            match self {
+                Self::Event(x) => {
+                    let time_zone = x.start.time_zone(calendar_time_zone);
+                    (
+                        &x.id,
+                        &x.summary,
+                        &x.description,
+                        &x.details,
+                        event_time(&x.start, &x.end, calendar_time_zone),
+                        x.start.date_time.to_local(time_zone),
+                        time_zone,
+                    )
+                }
+                Self::DayEvent(x) => (
+                    &x.id,
+                    &x.summary,
+                    &x.description,
+                    &x.details,
+                    EventTime::AllDay {
+                        start: x.start.date,
+                        // Google's end date is exclusive, guard against it not being after the start
+                        end: x.end.date.max(x.start.date + Days::new(1)),
+                    },
+                    x.start.date.and_time(NaiveTime::MIN),
+                    calendar_time_zone,
+                ),
+            };
+
+        Event {
+            id: id.to_owned(),
+            etag: details.etag.to_owned(),
+            title: summary
+                .to_owned()
+                .unwrap_or(UNTITLED_EVENT_TITLE.to_string()),
+            description: description.to_owned(),
+            status,
+            time,
+            colour_id: details.color_id.to_owned(),
+            colour: None,
+            location: details.location.to_owned(),
+            organizer: details.organizer.as_ref().map(Person::from),
+            attendees: details.attendees.iter().map(Attendee::from).collect(),
+            html_link: details.html_link.to_owned(),
+            hangout_link: details.hangout_link.to_owned(),
+            conference_entry_points: details.conference_entry_points(),
+            transparency: details.transparency,
+            visibility: details.visibility,
+            event_type: details.event_type,
+            calendar_id: calendar_id.to_owned(),
+            calendar_title: None,
+            account_id,
+            // Floating series have no zone to convert to so are expanded as written
+            recurrence: details.recurrence(
+                id,
+                recurrence_start,
+                recurrence_zone.unwrap_or(Tz::UTC),
+            ),
+            recurring_event_id: details.recurring_event_id.to_owned(),
+            original_start: details
+                .original_start_time
+                .as_ref()
+                .and_then(|original| original.to_start(calendar_time_zone)),
+            reminders: details.reminders(default_reminders),
+        }
+    }
+}
+
+/// The events listed from a calendar, recurring events are returned as the
+/// first instance of their series along with any modified instances
+#[derive(Debug, Clone)]
+pub struct EventList {
+    pub events: Vec<Event>,
+    pub cancelled_instances: Vec<CancelledInstance>,
+}
+
+/// A single instance removed from a recurring series
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelledInstance {
+    pub recurring_event_id: String,
+    pub original_start: EventStart,
+}
+
+impl CancelledEventResource {
+    fn cancelled_instance(&self, calendar_time_zone: Option<Tz>) -> Option<CancelledInstance> {
+        Some(CancelledInstance {
+            recurring_event_id: self.recurring_event_id.to_owned()?,
+            original_start: self
+                .original_start_time
+                .as_ref()?
+                .to_start(calendar_time_zone)?,
+        })
+    }
+}
+
+/// Deserialise a page of events from the given calendar
+/// calendar_id: Google id of the calendar the events were listed from
+/// account_id: id of the account the calendar belongs to
 pub async fn deserialise_event_list_response(
     response: Response,
-) -> Result<(Vec<Event>, Vec<DayEvent>)> {
+    calendar_id: &str,
+    account_id: i64,
+) -> Result<EventList> {
     let event_list: EventListResponse = response.json().await?;
+    // Times without an offset are in the calendar's time zone
+    let calendar_time_zone = parse_time_zone(event_list.time_zone.as_deref());
 
-    let events: Vec<Event> = event_list
+    let event_resources: Vec<EventResource> = event_list
         .items
-        .iter()
-        .filter_map(|event| match event {
-            EventResource::Confirmed(ConfirmedEvent::Event(x)) => Some(x),
-            _ => None,
+        .into_iter()
+        .filter_map(|item| deserialise_event_item(item, calendar_id))
+        .collect();
+
+    // Cancelled events are dropped, tentative ones are kept but marked as such
+    let active_events = event_resources.iter().filter_map(|event| match event {
+        EventResource::Confirmed(x) => Some((EventStatus::Confirmed, x)),
+        EventResource::Tentative(x) => Some((EventStatus::Tentative, x)),
+        EventResource::Cancelled(_) => None,
+    });
+
+    let events = active_events
+        .map(|(status, event)| {
+            event.to_event(
+                status,
+                calendar_id,
+                account_id,
+                calendar_time_zone,
+                &event_list.default_reminders,
+            )
         })
-        .map(|event| Event::from(event))
         .collect();
 
-    let day_events: Vec<DayEvent> = event_list
-        .items
+    let cancelled_instances = event_resources
         .iter()
         .filter_map(|event| match event {
-            EventResource::Confirmed(ConfirmedEvent::DayEvent(x)) => Some(x),
+            EventResource::Cancelled(x) => x.cancelled_instance(calendar_time_zone),
             _ => None,
         })
-        .map(|event| DayEvent::from(event))
         .collect();
 
-    Ok((events, day_events))
+    Ok(EventList {
+        events,
+        cancelled_instances,
+    })
+}
+
+/// Deserialise a single event, as returned when creating or updating one
+/// calendar_id: Google id of the calendar the event is on
+/// account_id: id of the account the calendar belongs to
+pub async fn deserialise_event_response(
+    response: Response,
+    calendar_id: &str,
+    account_id: i64,
+) -> Result<Event> {
+    let event: EventResource = response.json().await?;
+
+    // Times of single events always include their offset or time zone. The
+    // calendar's default reminders aren't included so events using them have none
+    match event {
+        EventResource::Confirmed(x) => {
+            Ok(x.to_event(EventStatus::Confirmed, calendar_id, account_id, None, &[]))
+        }
+        EventResource::Tentative(x) => {
+            Ok(x.to_event(EventStatus::Tentative, calendar_id, account_id, None, &[]))
+        }
+        EventResource::Cancelled(_) => Err(eyre!("Event has been cancelled")),
+    }
+}
+
+/// Deserialise a single item of an event list, logging and skipping it if it
+/// isn't an event we understand
+fn deserialise_event_item(item: serde_json::Value, calendar_id: &str) -> Option<EventResource> {
+    let event_id = item
+        .get("id")
+        .and_then(|id| id.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    match serde_json::from_value::<EventResource>(item) {
+        Ok(event) => Some(event),
+        Err(e) => {
+            tracing::warn!(
+                event_id,
+                calendar_id,
+                "Skipping event which failed to deserialise: {}",
+                e
+            );
+            None
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::deserialise_event_list_response;
+    use super::{
+        deserialise_event_list_response, CancelledInstance, EventList, UNTITLED_EVENT_TITLE,
+    };
+    use crate::domain::{
+        events::{
+            EventStart, EventStatus, EventTime, EventType, Reminder, ReminderMethod,
+            ResponseStatus, Transparency, Visibility,
+        },
+        timezone::DisplayTimezone,
+    };
+    use chrono::{DateTime, NaiveDate, Utc};
     use reqwest::Response;
 
     #[tokio::test]
@@ -179,13 +611,391 @@ mod test {
         let response = construct_response(raw_json);
 
         // Act
-        let (events, day_events) = deserialise_event_list_response(response).await.unwrap();
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
 
         // Assert
         assert_eq!(events[0].title, "Super cool test event");
         assert_eq!(events[0].id, "test_id");
+        assert_eq!(events[0].etag.as_deref(), Some("etag"));
         assert_eq!(events[0].description, None);
-        assert_eq!(day_events.len(), 0);
+        assert_eq!(events[0].colour_id.as_deref(), Some("8"));
+        assert!(!events[0].time.is_all_day());
+    }
+
+    #[tokio::test]
+    async fn parse_event_details() {
+        // Arrange
+        let raw_json = r#"
+{
+    "kind": "calendar#events",
+    "summary": "test@test.com",
+    "timeZone": "Europe/London",
+    "items": [
+        {
+            "kind": "calendar#event",
+            "id": "test_id",
+            "status": "confirmed",
+            "htmlLink": "https://www.google.com/calendar/event?eid=test",
+            "summary": "Planning",
+            "location": "Meeting room 1",
+            "organizer": {
+                "email": "boss@test.com",
+                "displayName": "The Boss"
+            },
+            "start": {
+                "dateTime": "2024-06-03T09:00:00Z"
+            },
+            "end": {
+                "dateTime": "2024-06-03T10:00:00Z"
+            },
+            "transparency": "transparent",
+            "visibility": "confidential",
+            "attendees": [
+                {
+                    "email": "boss@test.com",
+                    "organizer": true,
+                    "responseStatus": "accepted"
+                },
+                {
+                    "email": "test@test.com",
+                    "self": true,
+                    "responseStatus": "tentative",
+                    "comment": "Might be late"
+                },
+                {
+                    "email": "other@test.com",
+                    "optional": true,
+                    "responseStatus": "needsAction"
+                }
+            ],
+            "hangoutLink": "https://meet.google.com/abc-defg-hij",
+            "conferenceData": {
+                "entryPoints": [
+                    {
+                        "entryPointType": "video",
+                        "uri": "https://meet.google.com/abc-defg-hij",
+                        "label": "meet.google.com/abc-defg-hij"
+                    },
+                    {
+                        "entryPointType": "phone",
+                        "uri": "tel:+44-20-1234-5678"
+                    }
+                ]
+            },
+            "eventType": "focusTime"
+        }
+    ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        let event = &events[0];
+        assert_eq!(event.location.as_deref(), Some("Meeting room 1"));
+        assert_eq!(
+            event.organizer.as_ref().unwrap().display_name.as_deref(),
+            Some("The Boss")
+        );
+        assert_eq!(event.attendees.len(), 3);
+        assert!(event.attendees[0].organizer);
+        assert!(event.attendees[1].is_self);
+        assert_eq!(
+            event.attendees[1].response_status,
+            ResponseStatus::Tentative
+        );
+        assert_eq!(event.attendees[1].comment.as_deref(), Some("Might be late"));
+        assert!(event.attendees[2].optional);
+        assert_eq!(
+            event.attendees[2].response_status,
+            ResponseStatus::NeedsAction
+        );
+        assert_eq!(
+            event.hangout_link.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+        assert_eq!(event.conference_entry_points.len(), 2);
+        assert_eq!(event.conference_entry_points[1].entry_point_type, "phone");
+        assert_eq!(event.transparency, Transparency::Transparent);
+        assert_eq!(event.visibility, Visibility::Confidential);
+        assert_eq!(event.event_type, EventType::FocusTime);
+        assert_eq!(event.calendar_id, "test@test.com");
+        assert_eq!(event.account_id, 1);
+    }
+
+    #[tokio::test]
+    async fn values_added_to_the_api_since_are_kept_as_unknown() {
+        // Arrange
+        let raw_json = r#"
+{
+    "timeZone": "UTC",
+    "items": [
+        {
+            "id": "future",
+            "status": "confirmed",
+            "summary": "From the future",
+            "start": { "dateTime": "2024-06-03T09:00:00Z" },
+            "end": { "dateTime": "2024-06-03T10:00:00Z" },
+            "visibility": "secret",
+            "attendees": [
+                {
+                    "email": "test@test.com",
+                    "self": true,
+                    "responseStatus": "delegated"
+                }
+            ]
+        }
+    ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].visibility, Visibility::Unknown);
+        assert_eq!(
+            events[0].attendees[0].response_status,
+            ResponseStatus::Unknown
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_tentative_events() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "Europe/London",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "tentative_id",
+      "status": "tentative",
+      "summary": "Maybe happening",
+      "start": {
+        "dateTime": "2024-06-03T09:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-03T10:00:00Z"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "confirmed_id",
+      "status": "confirmed",
+      "summary": "Definitely happening",
+      "start": {
+        "date": "2024-06-03"
+      },
+      "end": {
+        "date": "2024-06-04"
+      }
+    }
+  ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(events[0].id, "tentative_id");
+        assert_eq!(events[0].status, EventStatus::Tentative);
+        assert_eq!(events[1].id, "confirmed_id");
+        assert_eq!(events[1].status, EventStatus::Confirmed);
+    }
+
+    #[tokio::test]
+    async fn parse_recurring_series_and_instances() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "Europe/London",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "series_id",
+      "status": "confirmed",
+      "summary": "Weekly sync",
+      "start": {
+        "dateTime": "2024-06-04T09:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-04T09:30:00Z"
+      },
+      "recurrence": [
+        "RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=20",
+        "EXDATE:20240611T090000Z"
+      ]
+    },
+    {
+      "kind": "calendar#event",
+      "id": "series_id_20240618T090000Z",
+      "status": "confirmed",
+      "summary": "Weekly sync (moved)",
+      "start": {
+        "dateTime": "2024-06-19T09:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-19T09:30:00Z"
+      },
+      "recurringEventId": "series_id",
+      "originalStartTime": {
+        "dateTime": "2024-06-18T09:00:00Z"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "series_id_20240625T090000Z",
+      "status": "cancelled",
+      "recurringEventId": "series_id",
+      "originalStartTime": {
+        "dateTime": "2024-06-25T09:00:00Z"
+      }
+    }
+  ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList {
+            events,
+            cancelled_instances,
+            ..
+        } = deserialise_event_list_response(response, "test@test.com", 1)
+            .await
+            .unwrap();
+
+        // Assert
+        let recurrence = events[0].recurrence.as_ref().unwrap();
+        assert_eq!(recurrence.rules.len(), 1);
+        assert_eq!(recurrence.exception_dates.len(), 1);
+        assert_eq!(events[0].recurring_event_id, None);
+
+        assert_eq!(events[1].recurrence, None);
+        assert_eq!(events[1].recurring_event_id.as_deref(), Some("series_id"));
+        assert_eq!(
+            events[1].original_start,
+            Some(EventStart::Instant(
+                DateTime::parse_from_rfc3339("2024-06-18T09:00:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            ))
+        );
+
+        assert_eq!(
+            cancelled_instances,
+            vec![CancelledInstance {
+                recurring_event_id: "series_id".into(),
+                original_start: EventStart::Instant(
+                    DateTime::parse_from_rfc3339("2024-06-25T09:00:00Z")
+                        .unwrap()
+                        .with_timezone(&Utc)
+                ),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn floating_times_use_event_or_calendar_time_zone() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "Europe/London",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "calendar_zone_id",
+      "status": "confirmed",
+      "summary": "Floating in calendar zone",
+      "start": {
+        "dateTime": "2024-06-03T09:00:00"
+      },
+      "end": {
+        "dateTime": "2024-06-03T10:00:00"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "event_zone_id",
+      "status": "confirmed",
+      "summary": "Floating in event zone",
+      "start": {
+        "dateTime": "2024-06-03T09:00:00",
+        "timeZone": "America/New_York"
+      },
+      "end": {
+        "dateTime": "2024-06-03T10:00:00",
+        "timeZone": "America/New_York"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "offset_id",
+      "status": "confirmed",
+      "summary": "With offset",
+      "start": {
+        "dateTime": "2024-06-03T09:00:00+02:00",
+        "timeZone": "Europe/Paris"
+      },
+      "end": {
+        "dateTime": "2024-06-03T10:00:00+02:00",
+        "timeZone": "Europe/Paris"
+      }
+    }
+  ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        let start_times: Vec<String> = events
+            .iter()
+            .map(|event| {
+                event
+                    .time
+                    .start_instant(DisplayTimezone::Local)
+                    .to_rfc3339()
+            })
+            .collect();
+        assert_eq!(
+            start_times,
+            vec![
+                "2024-06-03T08:00:00+00:00",
+                "2024-06-03T13:00:00+00:00",
+                "2024-06-03T07:00:00+00:00",
+            ]
+        );
     }
 
     #[tokio::test]
@@ -219,11 +1029,13 @@ mod test {
         let response = construct_response(raw_json);
 
         // Act
-        let (events, day_events) = deserialise_event_list_response(response).await.unwrap();
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
 
         // Assert
         assert_eq!(events.len(), 0);
-        assert_eq!(day_events.len(), 0);
     }
 
     #[tokio::test]
@@ -291,15 +1103,276 @@ mod test {
         let response = construct_response(raw_json);
 
         // Act
-        let (events, day_events) = deserialise_event_list_response(response).await.unwrap();
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(events[0].title, "Super cool all day test event");
+        assert_eq!(events[0].id, "test_id");
+        assert_eq!(events[0].description, None);
+        assert_eq!(events[0].transparency, Transparency::Transparent);
+        assert_eq!(events[0].event_type, EventType::WorkingLocation);
+        assert_eq!(
+            events[0].time,
+            EventTime::AllDay {
+                start: NaiveDate::from_ymd_opt(2023, 6, 19).unwrap(),
+                end: NaiveDate::from_ymd_opt(2023, 6, 20).unwrap(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_multi_day_events() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "Europe/London",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "offsite_id",
+      "status": "confirmed",
+      "summary": "Team off-site",
+      "start": {
+        "date": "2024-06-03"
+      },
+      "end": {
+        "date": "2024-06-08"
+      }
+    }
+  ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(
+            events[0].time,
+            EventTime::AllDay {
+                start: NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 6, 8).unwrap(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn untitled_events_use_placeholder_title() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "colleague@test.com",
+  "timeZone": "Europe/London",
+  "accessRole": "freeBusyReader",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "private_id",
+      "status": "confirmed",
+      "start": {
+        "dateTime": "2024-06-03T09:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-03T10:00:00Z"
+      },
+      "visibility": "private"
+    },
+    {
+      "kind": "calendar#event",
+      "id": "untitled_day_id",
+      "status": "confirmed",
+      "start": {
+        "date": "2024-06-03"
+      },
+      "end": {
+        "date": "2024-06-04"
+      }
+    }
+  ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "colleague@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(events[0].id, "private_id");
+        assert_eq!(events[0].title, UNTITLED_EVENT_TITLE);
+        assert_eq!(events[1].id, "untitled_day_id");
+        assert_eq!(events[1].title, UNTITLED_EVENT_TITLE);
+    }
+
+    #[tokio::test]
+    async fn skips_malformed_events_but_keeps_the_rest() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "Europe/London",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "missing_start_id",
+      "status": "confirmed",
+      "summary": "No start",
+      "end": {
+        "dateTime": "2024-06-03T10:00:00Z"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "bad_date_id",
+      "status": "confirmed",
+      "summary": "Bad date",
+      "start": {
+        "dateTime": "not a date"
+      },
+      "end": {
+        "dateTime": "2024-06-03T10:00:00Z"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "unknown_status_id",
+      "status": "something_new",
+      "summary": "Unknown status"
+    },
+    {
+      "kind": "calendar#event",
+      "id": "good_id",
+      "status": "confirmed",
+      "summary": "Good event",
+      "start": {
+        "dateTime": "2024-06-03T12:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-03T13:00:00Z"
+      }
+    }
+  ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, "good_id");
+    }
+
+    #[tokio::test]
+    async fn parse_calendar_without_items() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "Europe/London"
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
 
         // Assert
-        assert_eq!(day_events[0].title, "Super cool all day test event");
-        assert_eq!(day_events[0].id, "test_id");
-        assert_eq!(day_events[0].description, None);
         assert_eq!(events.len(), 0);
     }
 
+    #[tokio::test]
+    async fn events_use_calendar_reminders_unless_they_have_their_own() {
+        // Arrange
+        let raw_json = r#"
+{
+    "kind": "calendar#events",
+    "timeZone": "Europe/London",
+    "defaultReminders": [
+        {
+            "method": "popup",
+            "minutes": 10
+        }
+    ],
+    "items": [
+        {
+            "id": "default_reminders",
+            "status": "confirmed",
+            "start": { "dateTime": "2024-06-03T09:00:00Z" },
+            "end": { "dateTime": "2024-06-03T10:00:00Z" },
+            "reminders": { "useDefault": true }
+        },
+        {
+            "id": "own_reminders",
+            "status": "confirmed",
+            "start": { "dateTime": "2024-06-03T11:00:00Z" },
+            "end": { "dateTime": "2024-06-03T12:00:00Z" },
+            "reminders": {
+                "useDefault": false,
+                "overrides": [
+                    { "method": "email", "minutes": 1440 },
+                    { "method": "popup", "minutes": 5 }
+                ]
+            }
+        },
+        {
+            "id": "no_reminders",
+            "status": "confirmed",
+            "start": { "dateTime": "2024-06-03T13:00:00Z" },
+            "end": { "dateTime": "2024-06-03T14:00:00Z" },
+            "reminders": { "useDefault": false }
+        }
+    ]
+}
+        "#;
+        let response = construct_response(raw_json);
+
+        // Act
+        let EventList { events, .. } =
+            deserialise_event_list_response(response, "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Assert
+        let popup = |minutes| Reminder {
+            method: ReminderMethod::Popup,
+            minutes,
+        };
+        assert_eq!(events[0].reminders, vec![popup(10)]);
+        assert_eq!(
+            events[1].reminders,
+            vec![
+                Reminder {
+                    method: ReminderMethod::Email,
+                    minutes: 1440
+                },
+                popup(5)
+            ]
+        );
+        assert_eq!(events[2].reminders, vec![]);
+    }
+
     fn construct_response(body: &str) -> Response {
         let response = http::Response::builder()
             .status(200)
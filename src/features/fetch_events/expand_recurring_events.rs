@@ -0,0 +1,373 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::domain::{
+    events::{Event, EventStart, EventTime},
+    timezone::localise,
+};
+
+use super::deserialise_event_response::{CancelledInstance, EventList};
+
+/// Replace each recurring series in the list with its instances inside the
+/// given range, applying any modified or cancelled instances. Only for ranges
+/// which couldn't be fetched, Google expands series itself otherwise
+/// range_start: start of the range to expand series over
+/// range_end: exclusive end of the range to expand series over
+pub fn expand_recurring_events(
+    event_list: EventList,
+    range_start: DateTime<Utc>,
+    range_end: DateTime<Utc>,
+) -> Vec<Event> {
+    let EventList {
+        events,
+        cancelled_instances,
+    } = event_list;
+
+    let (series, mut expanded_events): (Vec<Event>, Vec<Event>) = events
+        .into_iter()
+        .partition(|event| event.recurrence.is_some());
+
+    for series in series.iter() {
+        attach_series_to_overrides(series, &mut expanded_events);
+        expanded_events.append(&mut expand_event_series(
+            series,
+            &expanded_events,
+            &cancelled_instances,
+            range_start,
+            range_end,
+        ));
+    }
+
+    expanded_events
+}
+
+fn expand_event_series(
+    series: &Event,
+    overrides: &[Event],
+    cancelled_instances: &[CancelledInstance],
+    range_start: DateTime<Utc>,
+    range_end: DateTime<Utc>,
+) -> Vec<Event> {
+    let Some(recurrence) = &series.recurrence else {
+        return vec![];
+    };
+    let time_zone = recurrence.time_zone;
+
+    // Series repeat on the wall clock of their time zone so expand there, including
+    // instances which started before the range but are still running
+    recurrence
+        .occurrences_between(
+            (range_start - series.time.duration())
+                .with_timezone(&time_zone)
+                .naive_local(),
+            range_end.with_timezone(&time_zone).naive_local(),
+        )
+        .into_iter()
+        .map(|start| instance_time(&series.time, start, time_zone))
+        .filter(|time| {
+            !overrides.iter().any(|instance| {
+                instance.recurring_event_id.as_ref() == Some(&series.id)
+                    && instance.original_start == Some(time.start())
+            })
+        })
+        .filter(|time| {
+            !cancelled_instances.iter().any(|cancelled| {
+                cancelled.recurring_event_id == series.id
+                    && cancelled.original_start == time.start()
+            })
+        })
+        .map(|time| Event {
+            id: format!("{}_{}", series.id, instance_id_suffix(time.start())),
+            // Instances only exist locally so have no version of their own
+            etag: None,
+            time,
+            recurring_event_id: Some(series.id.to_owned()),
+            original_start: Some(time.start()),
+            ..series.clone()
+        })
+        .collect()
+}
+
+/// Time of the instance of a series beginning at the given wall clock time
+fn instance_time(series_time: &EventTime, start: NaiveDateTime, time_zone: Tz) -> EventTime {
+    let duration = series_time.duration();
+
+    match series_time {
+        EventTime::Timed { .. } => {
+            let start = localise(&time_zone, &start);
+            EventTime::Timed {
+                start,
+                end: start + duration,
+            }
+        }
+        EventTime::Floating { .. } => EventTime::Floating {
+            start,
+            end: start + duration,
+        },
+        EventTime::AllDay { .. } => EventTime::AllDay {
+            start: start.date(),
+            end: start.date() + duration,
+        },
+    }
+}
+
+/// Google suffixes instance ids with their original start
+fn instance_id_suffix(start: EventStart) -> String {
+    match start {
+        EventStart::Instant(start) => start.format("%Y%m%dT%H%M%SZ").to_string(),
+        EventStart::Floating(start) => start.format("%Y%m%dT%H%M%S").to_string(),
+        EventStart::Date(date) => date.format("%Y%m%d").to_string(),
+    }
+}
+
+/// Give instances expanded by Google the recurrence of the series in the list
+/// they belong to
+pub fn attach_series(event_list: &EventList, instances: &mut [Event]) {
+    event_list
+        .events
+        .iter()
+        .filter(|event| event.recurrence.is_some())
+        .for_each(|series| attach_series_to_overrides(series, instances));
+}
+
+/// Modified instances don't carry the recurrence of their series so copy it
+/// across to allow them to describe the series they belong to
+fn attach_series_to_overrides(series: &Event, overrides: &mut [Event]) {
+    overrides
+        .iter_mut()
+        .filter(|instance| instance.recurring_event_id.as_ref() == Some(&series.id))
+        .for_each(|instance| instance.recurrence = series.recurrence.clone());
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use reqwest::Response;
+
+    use super::{attach_series, expand_recurring_events};
+    use crate::domain::{
+        events::{EventStart, EventTime},
+        timezone::DisplayTimezone,
+    };
+    use crate::features::fetch_events::deserialise_event_response::deserialise_event_list_response;
+
+    fn datetime(value: &str) -> DateTime<Utc> {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc()
+    }
+
+    #[tokio::test]
+    async fn expands_series_applying_modified_and_cancelled_instances() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "UTC",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "standup",
+      "status": "confirmed",
+      "summary": "Standup",
+      "start": {
+        "dateTime": "2024-06-03T09:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-03T09:15:00Z"
+      },
+      "recurrence": [
+        "RRULE:FREQ=DAILY;COUNT=5"
+      ]
+    },
+    {
+      "kind": "calendar#event",
+      "id": "standup_20240604T090000Z",
+      "status": "confirmed",
+      "summary": "Late standup",
+      "start": {
+        "dateTime": "2024-06-04T11:00:00Z"
+      },
+      "end": {
+        "dateTime": "2024-06-04T11:15:00Z"
+      },
+      "recurringEventId": "standup",
+      "originalStartTime": {
+        "dateTime": "2024-06-04T09:00:00Z"
+      }
+    },
+    {
+      "kind": "calendar#event",
+      "id": "standup_20240605T090000Z",
+      "status": "cancelled",
+      "recurringEventId": "standup",
+      "originalStartTime": {
+        "dateTime": "2024-06-05T09:00:00Z"
+      }
+    }
+  ]
+}
+        "#;
+        let response = http::Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(raw_json.to_string())
+            .unwrap();
+        let event_list =
+            deserialise_event_list_response(Response::from(response), "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Act
+        let mut events = expand_recurring_events(
+            event_list,
+            datetime("2024-06-04 00:00"),
+            datetime("2024-06-07 00:00"),
+        );
+        events.sort_by_key(|event| event.time.start_instant(DisplayTimezone::Local));
+
+        // Assert
+        let titles: Vec<&str> = events.iter().map(|event| event.title.as_str()).collect();
+        assert_eq!(titles, vec!["Late standup", "Standup"]);
+
+        assert_eq!(
+            events[0].time.start(),
+            EventStart::Instant(datetime("2024-06-04 11:00"))
+        );
+        assert!(events[0].recurrence.is_some());
+
+        assert_eq!(events[1].id, "standup_20240606T090000Z");
+        assert_eq!(events[1].recurring_event_id.as_deref(), Some("standup"));
+        assert_eq!(
+            events[1].time,
+            EventTime::Timed {
+                start: datetime("2024-06-06 09:00"),
+                end: datetime("2024-06-06 09:15"),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn expands_all_day_series_keeping_their_span() {
+        // Arrange
+        let raw_json = r#"
+{
+  "kind": "calendar#events",
+  "summary": "test@test.com",
+  "timeZone": "UTC",
+  "items": [
+    {
+      "kind": "calendar#event",
+      "id": "retreat",
+      "status": "confirmed",
+      "summary": "Retreat",
+      "start": {
+        "date": "2024-06-03"
+      },
+      "end": {
+        "date": "2024-06-05"
+      },
+      "recurrence": [
+        "RRULE:FREQ=WEEKLY;COUNT=3"
+      ]
+    }
+  ]
+}
+        "#;
+        let response = http::Response::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .body(raw_json.to_string())
+            .unwrap();
+        let event_list =
+            deserialise_event_list_response(Response::from(response), "test@test.com", 1)
+                .await
+                .unwrap();
+
+        // Act
+        let events = expand_recurring_events(
+            event_list,
+            datetime("2024-06-11 00:00"),
+            datetime("2024-06-14 00:00"),
+        );
+
+        // Assert
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, "retreat_20240610");
+        assert_eq!(
+            events[0].time,
+            EventTime::AllDay {
+                start: NaiveDate::from_ymd_opt(2024, 6, 10).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 6, 12).unwrap(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn instances_expanded_by_google_are_given_their_series_recurrence() {
+        // Arrange
+        let list = |raw_json: &'static str| async move {
+            let response = http::Response::builder()
+                .status(200)
+                .header("content-type", "application/json")
+                .body(raw_json.to_string())
+                .unwrap();
+            deserialise_event_list_response(Response::from(response), "test@test.com", 1)
+                .await
+                .unwrap()
+        };
+        let series = list(
+            r#"
+{
+  "timeZone": "UTC",
+  "items": [
+    {
+      "id": "standup",
+      "status": "confirmed",
+      "summary": "Standup",
+      "start": { "dateTime": "2024-06-03T09:00:00Z" },
+      "end": { "dateTime": "2024-06-03T09:15:00Z" },
+      "recurrence": ["RRULE:FREQ=DAILY;COUNT=5"]
+    }
+  ]
+}
+            "#,
+        )
+        .await;
+        let mut instances = list(
+            r#"
+{
+  "timeZone": "UTC",
+  "items": [
+    {
+      "id": "standup_20240604T090000Z",
+      "status": "confirmed",
+      "summary": "Standup",
+      "start": { "dateTime": "2024-06-04T09:00:00Z" },
+      "end": { "dateTime": "2024-06-04T09:15:00Z" },
+      "recurringEventId": "standup",
+      "originalStartTime": { "dateTime": "2024-06-04T09:00:00Z" }
+    },
+    {
+      "id": "lunch",
+      "status": "confirmed",
+      "summary": "Lunch",
+      "start": { "dateTime": "2024-06-04T12:00:00Z" },
+      "end": { "dateTime": "2024-06-04T13:00:00Z" }
+    }
+  ]
+}
+            "#,
+        )
+        .await
+        .events;
+
+        // Act
+        attach_series(&series, &mut instances);
+
+        // Assert
+        assert_eq!(instances[0].recurrence, series.events[0].recurrence);
+        assert!(instances[1].recurrence.is_none());
+    }
+}
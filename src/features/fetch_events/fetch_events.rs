@@ -1,28 +1,40 @@
-use chrono::{DateTime, Local};
-use color_eyre::eyre::Result;
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::{bail, Result};
 use eyre::Context;
 use sqlx::SqlitePool;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
-    domain::{
-        calendar::Calendar,
-        events::{DayEvent, Event},
+    domain::{calendar::Calendar, colour::Colour, events::Event},
+    features::{
+        calendar_colours::colour_palette::{fetch_colour_palette, ColourPalette},
+        oauth_http_client::GoogleOAuthClient,
+    },
+    tui::{
+        model::{Message, Model},
+        MessageSender,
     },
-    features::oauth_http_client::GoogleOAuthClient,
-    tui::model::{Message, Model},
 };
 
-use super::deserialise_event_response::deserialise_event_list_response;
+use super::{
+    deserialise_event_response::{deserialise_event_list_response, EventList},
+    expand_recurring_events::attach_series,
+};
+
+/// Events fetched from every calendar, along with the recurring series they
+/// belong to so instances past the fetched range can be expanded locally
+#[derive(Debug, Default)]
+pub(crate) struct FetchedEvents {
+    pub events: Vec<Event>,
+    /// Series, modified and cancelled instances by calendar
+    pub series: Vec<EventList>,
+}
 
 /// Begin a fetch of calendar events which will send a message with
 /// the results, or an error message
 /// start_time: start time to fetch events
 /// end_time: exclusive end time to fetch events
-pub fn run_fetch_events_task(
-    start_time: DateTime<Local>,
-    end_time: DateTime<Local>,
-    model: &Model,
-) {
+pub fn run_fetch_events_task(start_time: DateTime<Utc>, end_time: DateTime<Utc>, model: &Model) {
     let db = model.application.db.clone();
     let google_client = model.application.google_client.clone();
     let message_channel = model.message_channel.clone();
@@ -31,7 +43,7 @@ pub fn run_fetch_events_task(
         match fetch_events(start_time, end_time, db, google_client).await {
             Ok(result) => {
                 message_channel
-                    .send(Message::EventsReady(result.0, result.1))
+                    .send(Message::EventsReady(result))
                     .expect("Message channel should never be closed");
             }
             Err(e) => {
@@ -44,47 +56,164 @@ pub fn run_fetch_events_task(
     });
 }
 
+/// Fetch events again every `refresh_interval` so changes made elsewhere show up
+pub fn run_refresh_ticker(
+    refresh_interval: std::time::Duration,
+    message_sender: MessageSender,
+    cancellation_token: CancellationToken,
+) {
+    tokio::spawn(async move {
+        // Events are fetched when the calendar opens, so wait before the first refresh
+        let mut interval = tokio::time::interval_at(
+            tokio::time::Instant::now() + refresh_interval,
+            refresh_interval,
+        );
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    if message_sender.send(Message::Refresh).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+pub(crate) async fn fetch_events(
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    db: SqlitePool,
+    google_client: GoogleOAuthClient,
+) -> Result<Vec<Event>> {
+    Ok(
+        fetch_events_and_series(start_time, end_time, db, google_client)
+            .await?
+            .events,
+    )
+}
+
 #[tracing::instrument(name = "fetch_events", skip(db, google_client))]
-async fn fetch_events(
-    start_time: DateTime<Local>,
-    end_time: DateTime<Local>,
+pub(crate) async fn fetch_events_and_series(
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
     db: SqlitePool,
     google_client: GoogleOAuthClient,
-) -> Result<(Vec<Event>, Vec<DayEvent>)> {
+) -> Result<FetchedEvents> {
     let calendars = retrieve_calendars(&db).await?;
+    let calendar_count = calendars.len();
+
+    // The colour palette is the same for every account so only needs fetching once
+    let palette = match calendars.first() {
+        Some(calendar) => fetch_colour_palette(calendar.account_id, &google_client)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to fetch colour palette: {:?}", e);
+                ColourPalette::default()
+            }),
+        None => ColourPalette::default(),
+    };
 
-    let mut all_events = vec![];
-    let mut all_day_events = vec![];
+    let mut fetched = FetchedEvents::default();
+    let mut failed_calendars = 0;
     for calendar in calendars.into_iter() {
+        let calendar_colour = palette.calendar_colour(&calendar);
+
         // TODO: make these all run in parallel
-        // TODO: some of these might fail
-        let (mut events, mut day_events) =
-            retrieve_calendar_events(start_time, end_time, calendar, &google_client).await?;
-        all_events.append(&mut events);
-        all_day_events.append(&mut day_events);
+        let calendar_id = calendar.calendar_id.clone();
+        let calendar_title = calendar.title.clone();
+        let (mut events, mut series) =
+            match retrieve_calendar_events(start_time, end_time, calendar, &google_client).await {
+                Ok(events) => events,
+                Err(e) => {
+                    // One unreachable calendar shouldn't stop the others from showing
+                    tracing::error!(calendar_id, "Failed to fetch calendar events: {:?}", e);
+                    failed_calendars += 1;
+                    continue;
+                }
+            };
+
+        for event in events.iter_mut().chain(series.events.iter_mut()) {
+            event.colour =
+                resolve_event_colour(event.colour_id.as_deref(), &palette).or(calendar_colour);
+            event.calendar_title = Some(calendar_title.clone());
+        }
+
+        fetched.events.append(&mut events);
+        fetched.series.push(series);
+    }
+
+    // Likely offline, which shouldn't look the same as a calendar with nothing on
+    if calendar_count > 0 && failed_calendars == calendar_count {
+        bail!("Failed to fetch events from every calendar");
     }
 
     tracing::info!(
-        fetch_events.day_events = all_events.len(),
-        fetch_events.all_day_events = all_day_events.len()
+        fetch_events.events = fetched.events.len(),
+        fetch_events.all_day_events = fetched
+            .events
+            .iter()
+            .filter(|event| event.time.is_all_day())
+            .count()
     );
-    Ok((all_events, all_day_events))
+    Ok(fetched)
+}
+
+fn resolve_event_colour(colour_id: Option<&str>, palette: &ColourPalette) -> Option<Colour> {
+    colour_id.and_then(|colour_id| palette.event_colour(colour_id))
 }
 
 async fn retrieve_calendars(db: &SqlitePool) -> Result<Vec<Calendar>> {
-    sqlx::query_as!(Calendar, "SELECT calendar_id, account_id FROM calendars")
-        .fetch_all(db)
-        .await
-        .wrap_err("error while retrieving stored calendars")
+    sqlx::query_as!(
+        Calendar,
+        "SELECT calendar_id, account_id, title, color_id, background_color, colour_override,
+            access_role
+        FROM calendars"
+    )
+    .fetch_all(db)
+    .await
+    .wrap_err("error while retrieving stored calendars")
 }
 
+/// Events of the calendar with recurring series expanded by Google, and the
+/// series themselves
 #[tracing::instrument(name = "Retrieve calendar events")]
 async fn retrieve_calendar_events(
-    start_time: DateTime<Local>,
-    end_time: DateTime<Local>,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
     calendar: Calendar,
     google_client: &GoogleOAuthClient,
-) -> Result<(Vec<Event>, Vec<DayEvent>)> {
+) -> Result<(Vec<Event>, EventList)> {
+    let mut events = list_calendar_events(start_time, end_time, &calendar, true, google_client)
+        .await?
+        .events;
+
+    // Instances don't say how their series repeats, so list the series too
+    let series = list_calendar_events(start_time, end_time, &calendar, false, google_client)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to fetch recurring series: {:?}", e);
+            EventList {
+                events: vec![],
+                cancelled_instances: vec![],
+            }
+        });
+    attach_series(&series, &mut events);
+
+    Ok((events, series))
+}
+
+/// single_events: whether Google should expand recurring series into their
+/// instances, rather than listing each series once
+async fn list_calendar_events(
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    calendar: &Calendar,
+    single_events: bool,
+    google_client: &GoogleOAuthClient,
+) -> Result<EventList> {
     let events_from = start_time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
     let events_until = end_time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
 
@@ -97,13 +226,12 @@ async fn retrieve_calendar_events(
     let calendar_list_request = http_client.get(events_list_url.to_string()).query(&[
         ("timeMin", events_from.as_str()),
         ("timeMax", events_until.as_str()),
+        ("singleEvents", if single_events { "true" } else { "false" }),
     ]);
 
     let response = google_client
         .send(calendar.account_id, calendar_list_request)
         .await?;
 
-    let events = deserialise_event_list_response(response).await?;
-
-    Ok(events)
+    deserialise_event_list_response(response, &calendar.calendar_id, calendar.account_id).await
 }
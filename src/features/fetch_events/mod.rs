@@ -1,2 +1,3 @@
-mod deserialise_event_response;
+pub(crate) mod deserialise_event_response;
+pub(crate) mod expand_recurring_events;
 pub(crate) mod fetch_events;
@@ -0,0 +1,235 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::domain::{events::Event, timezone::DisplayTimezone, working_hours::WorkingHours};
+
+/// A gap between busy events within working hours
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FreeSlot {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl FreeSlot {
+    /// Example: "09:00–10:30"
+    pub fn format_times(&self, time_format: &str, display_timezone: DisplayTimezone) -> String {
+        format!(
+            "{}–{}",
+            display_timezone.format(&self.start, time_format),
+            display_timezone.format(&self.end, time_format)
+        )
+    }
+}
+
+/// Free time of at least `min_length` during working hours on each day from
+/// `first_day` to `last_day` inclusive, ignoring anything before `now`. Every
+/// busy event counts whichever calendar it is from
+pub fn find_free_slots(
+    events: &[Event],
+    first_day: NaiveDate,
+    last_day: NaiveDate,
+    working_hours: &WorkingHours,
+    min_length: Duration,
+    now: DateTime<Utc>,
+    display_timezone: DisplayTimezone,
+) -> Vec<FreeSlot> {
+    let mut busy = events
+        .iter()
+        .filter(|event| event.is_busy())
+        .map(|event| {
+            (
+                event.time.start_instant(display_timezone),
+                event.time.end_instant(display_timezone),
+            )
+        })
+        .collect::<Vec<_>>();
+    busy.sort();
+
+    let mut slots = vec![];
+    for day in first_day.iter_days().take_while(|day| *day <= last_day) {
+        if !working_hours.is_working_day(day) {
+            continue;
+        }
+
+        let day_end = display_timezone.to_utc(&day.and_time(working_hours.end));
+        let mut free_from = display_timezone
+            .to_utc(&day.and_time(working_hours.start))
+            .max(now);
+
+        for (start, end) in busy.iter() {
+            if *end <= free_from || *start >= day_end {
+                continue;
+            }
+            if *start - free_from >= min_length {
+                slots.push(FreeSlot {
+                    start: free_from,
+                    end: *start,
+                });
+            }
+            free_from = free_from.max(*end);
+        }
+
+        if day_end - free_from >= min_length {
+            slots.push(FreeSlot {
+                start: free_from,
+                end: day_end,
+            });
+        }
+    }
+
+    slots
+}
+
+/// Slots grouped by the day they're on, in order
+pub fn slots_by_day(
+    slots: &[FreeSlot],
+    display_timezone: DisplayTimezone,
+) -> Vec<(NaiveDate, Vec<&FreeSlot>)> {
+    let mut days: Vec<(NaiveDate, Vec<&FreeSlot>)> = vec![];
+    for slot in slots {
+        let day = display_timezone.to_local(&slot.start).date();
+        match days.last_mut() {
+            Some((last_day, day_slots)) if *last_day == day => day_slots.push(slot),
+            _ => days.push((day, vec![slot])),
+        }
+    }
+
+    days
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+    use super::{find_free_slots, FreeSlot};
+    use crate::domain::{
+        events::{Attendee, Event, EventTime, ResponseStatus, Transparency},
+        timezone::DisplayTimezone,
+        working_hours::WorkingHours,
+    };
+
+    fn utc(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap()
+    }
+
+    fn slot(start: &str, end: &str) -> FreeSlot {
+        FreeSlot {
+            start: utc(start),
+            end: utc(end),
+        }
+    }
+
+    fn event(start: &str, end: &str) -> Event {
+        Event {
+            id: start.into(),
+            title: "Busy".into(),
+            time: EventTime::Timed {
+                start: utc(start),
+                end: utc(end),
+            },
+            ..Event::test_default()
+        }
+    }
+
+    #[test]
+    fn finds_gaps_between_overlapping_events_across_calendars() {
+        // Arrange
+        let mut other_calendar = event("2024-06-03T10:30:00Z", "2024-06-03T12:00:00Z");
+        other_calendar.calendar_id = "other@test.com".into();
+        let events = vec![
+            event("2024-06-03T10:00:00Z", "2024-06-03T11:00:00Z"),
+            other_calendar,
+            event("2024-06-03T12:20:00Z", "2024-06-03T13:00:00Z"),
+            event("2024-06-03T16:30:00Z", "2024-06-03T18:00:00Z"),
+        ];
+
+        // Act
+        let slots = find_free_slots(
+            &events,
+            day(3),
+            day(3),
+            &WorkingHours::default(),
+            Duration::minutes(30),
+            utc("2024-06-03T00:00:00Z"),
+            DisplayTimezone::from_name("UTC").unwrap(),
+        );
+
+        // Assert
+        assert_eq!(
+            slots,
+            vec![
+                slot("2024-06-03T09:00:00Z", "2024-06-03T10:00:00Z"),
+                slot("2024-06-03T13:00:00Z", "2024-06-03T16:30:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn free_and_declined_events_are_not_busy() {
+        // Arrange
+        let mut free = event("2024-06-03T09:00:00Z", "2024-06-03T17:00:00Z");
+        free.transparency = Transparency::Transparent;
+        let mut declined = event("2024-06-03T09:00:00Z", "2024-06-03T17:00:00Z");
+        declined.attendees = vec![Attendee {
+            email: Some("me@test.com".into()),
+            display_name: None,
+            response_status: ResponseStatus::Declined,
+            comment: None,
+            optional: false,
+            organizer: false,
+            resource: false,
+            additional_guests: 0,
+            is_self: true,
+        }];
+
+        // Act
+        let slots = find_free_slots(
+            &[free, declined],
+            day(3),
+            day(3),
+            &WorkingHours::default(),
+            Duration::minutes(30),
+            utc("2024-06-03T00:00:00Z"),
+            DisplayTimezone::from_name("UTC").unwrap(),
+        );
+
+        // Assert
+        assert_eq!(
+            slots,
+            vec![slot("2024-06-03T09:00:00Z", "2024-06-03T17:00:00Z")]
+        );
+    }
+
+    #[test]
+    fn respects_working_hours_and_time_zone() {
+        // Arrange
+        let working_hours = WorkingHours::from_spec("mon-fri 09:00-12:00").unwrap();
+
+        // Act
+        // 7th June is a Friday
+        let slots = find_free_slots(
+            &[],
+            day(7),
+            day(10),
+            &working_hours,
+            Duration::minutes(30),
+            utc("2024-06-07T09:45:00Z"),
+            DisplayTimezone::from_name("Europe/London").unwrap(),
+        );
+
+        // Assert
+        assert_eq!(
+            slots,
+            vec![
+                slot("2024-06-07T09:45:00Z", "2024-06-07T11:00:00Z"),
+                slot("2024-06-10T08:00:00Z", "2024-06-10T11:00:00Z"),
+            ]
+        );
+    }
+}
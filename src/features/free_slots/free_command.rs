@@ -0,0 +1,64 @@
+use chrono::{Days, Duration, NaiveDate, Utc};
+use color_eyre::eyre::{bail, Result};
+
+use crate::{configuration::Application, features::fetch_events::fetch_events::fetch_events};
+
+use super::find_free_slots::{find_free_slots, slots_by_day};
+
+/// Print free time in working hours across every calendar, one line per day
+/// or as JSON
+pub async fn run_free_command(
+    application: &Application,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    min_minutes: i64,
+    json: bool,
+) -> Result<()> {
+    let display_timezone = application.display_timezone;
+    let first_day = from.unwrap_or(display_timezone.today());
+    let last_day = to.unwrap_or(first_day + Days::new(4));
+    if last_day < first_day {
+        bail!("The last day must not be before the first day");
+    }
+
+    let events = fetch_events(
+        display_timezone.start_of_day(first_day),
+        display_timezone.start_of_day(last_day + Days::new(1)),
+        application.db.clone(),
+        application.google_client.clone(),
+    )
+    .await?;
+
+    let slots = find_free_slots(
+        &events,
+        first_day,
+        last_day,
+        &application.config.working_hours,
+        Duration::minutes(min_minutes),
+        Utc::now(),
+        display_timezone,
+    );
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&slots)?);
+        return Ok(());
+    }
+
+    if slots.is_empty() {
+        println!("No free time in working hours");
+    }
+    for (day, day_slots) in slots_by_day(&slots, display_timezone) {
+        let times = day_slots
+            .iter()
+            .map(|slot| slot.format_times(application.config.time_format(), display_timezone))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{}: {}",
+            day.format(&application.config.short_date_format),
+            times
+        );
+    }
+
+    Ok(())
+}
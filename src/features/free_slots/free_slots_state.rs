@@ -0,0 +1,31 @@
+use crate::tui::model::EventsState;
+
+/// Days searched for free time, starting today
+pub const SEARCH_DAYS: u64 = 7;
+
+/// Shortest free slots which can be looked for, in minutes
+pub const SLOT_LENGTHS: [i64; 6] = [15, 30, 45, 60, 90, 120];
+
+#[derive(Debug)]
+pub struct FreeSlotsState {
+    /// Events over the days being searched, fetched separately to those shown in
+    /// the calendar as they cover more days
+    pub events: EventsState,
+    /// Position in `SLOT_LENGTHS` of the shortest slot to show
+    pub length: usize,
+}
+
+impl Default for FreeSlotsState {
+    fn default() -> Self {
+        Self {
+            events: EventsState::Loading,
+            length: 1,
+        }
+    }
+}
+
+impl FreeSlotsState {
+    pub fn min_length(&self) -> chrono::Duration {
+        chrono::Duration::minutes(SLOT_LENGTHS[self.length])
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod find_free_slots;
+pub(crate) mod free_command;
+pub(crate) mod free_slots_state;
+pub(crate) mod update_free_slots;
+pub(crate) mod view;
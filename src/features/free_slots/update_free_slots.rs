@@ -0,0 +1,68 @@
+use chrono::Days;
+
+use crate::{
+    domain::events::Event,
+    features::{
+        fetch_events::fetch_events::fetch_events, month_view::month_view_state::MonthViewState,
+    },
+    tui::model::{CurrentState, EventsState, Message, Model},
+};
+
+use super::free_slots_state::{FreeSlotsState, SEARCH_DAYS, SLOT_LENGTHS};
+
+/// Open the free time panel and fetch events over the days it searches
+pub fn handle_open_free_slots(model: &mut Model) {
+    model.current_state = CurrentState::FreeSlots(FreeSlotsState::default());
+
+    let display_timezone = model.application.display_timezone;
+    let today = display_timezone.today();
+    let start_time = display_timezone.start_of_day(today);
+    let end_time = display_timezone.start_of_day(today + Days::new(SEARCH_DAYS));
+
+    let db = model.application.db.clone();
+    let google_client = model.application.google_client.clone();
+    let message_channel = model.message_channel.clone();
+
+    tokio::spawn(async move {
+        let message = match fetch_events(start_time, end_time, db, google_client).await {
+            Ok(events) => Message::FreeSlotsReady(events),
+            Err(e) => {
+                tracing::error!("Failed to fetch events for free time: {:?}", e);
+                Message::FreeSlotsError
+            }
+        };
+
+        message_channel
+            .send(message)
+            .expect("Message channel should never be closed");
+    });
+}
+
+pub fn handle_free_slots_ready(model: &mut Model, events: Vec<Event>) {
+    if let CurrentState::FreeSlots(ref mut state) = model.current_state {
+        state.events = EventsState::Ready(events);
+    }
+}
+
+pub fn handle_free_slots_error(model: &mut Model) {
+    if let CurrentState::FreeSlots(ref mut state) = model.current_state {
+        state.events = EventsState::Error("Failed to fetch events".to_owned());
+    }
+}
+
+/// Left and right change the shortest slot shown
+pub fn handle_horizontal_message(model: &mut Model, longer: bool) {
+    let CurrentState::FreeSlots(ref mut state) = model.current_state else {
+        return;
+    };
+
+    state.length = if longer {
+        (state.length + 1).min(SLOT_LENGTHS.len() - 1)
+    } else {
+        state.length.saturating_sub(1)
+    };
+}
+
+pub fn handle_back_message(model: &mut Model) {
+    model.current_state = CurrentState::MonthView(MonthViewState::default());
+}
@@ -0,0 +1,89 @@
+use chrono::{Days, Utc};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::domain::{config::Config, theme::Theme, timezone::DisplayTimezone};
+use crate::tui::model::EventsState;
+
+use super::find_free_slots::{find_free_slots, slots_by_day};
+use super::free_slots_state::{FreeSlotsState, SEARCH_DAYS, SLOT_LENGTHS};
+
+pub fn render(
+    frame: &mut Frame,
+    state: &FreeSlotsState,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
+    let block = Block::bordered()
+        .title(format!(
+            " Free time · at least {} min ",
+            SLOT_LENGTHS[state.length]
+        ))
+        .title_bottom(Line::from(" ←/→ shortest slot · esc back ").right_aligned())
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.panel).fg(theme.text));
+    let label_style = theme.muted_style();
+
+    let events = match &state.events {
+        EventsState::Ready(events) => events,
+        EventsState::Loading => {
+            let loading = Paragraph::new("Loading events...").block(block);
+            frame.render_widget(loading, frame.size());
+            return;
+        }
+        EventsState::Error(error) => {
+            let error = Paragraph::new(error.to_owned())
+                .style(theme.error_style())
+                .block(block);
+            frame.render_widget(error, frame.size());
+            return;
+        }
+    };
+
+    let today = display_timezone.today();
+    let slots = find_free_slots(
+        events,
+        today,
+        today + Days::new(SEARCH_DAYS - 1),
+        &config.working_hours,
+        state.min_length(),
+        Utc::now(),
+        display_timezone,
+    );
+
+    if slots.is_empty() {
+        let placeholder = Paragraph::new(format!(
+            "No free time in working hours over the next {} days",
+            SEARCH_DAYS
+        ))
+        .style(label_style)
+        .block(block);
+        frame.render_widget(placeholder, frame.size());
+        return;
+    }
+
+    let lines = slots_by_day(&slots, display_timezone)
+        .into_iter()
+        .map(|(day, day_slots)| {
+            let times = day_slots
+                .iter()
+                .map(|slot| slot.format_times(config.time_format(), display_timezone))
+                .collect::<Vec<_>>()
+                .join(" · ");
+
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<12}", day.format(&config.short_date_format)),
+                    label_style,
+                ),
+                Span::raw(times),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let free_time = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(free_time, frame.size());
+}
@@ -0,0 +1,80 @@
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::domain::keymap::{format_keys, Action, KeyScope, Keymap};
+use crate::domain::theme::Theme;
+use crate::tui::util::centered_popup;
+
+const POPUP_WIDTH: u16 = 52;
+const KEYS_WIDTH: usize = 16;
+
+/// Every key binding which applies to the current view, read from the keymap
+/// so changes in the config file show up
+pub fn render(frame: &mut Frame, keymap: &Keymap, scope: KeyScope, theme: &Theme) {
+    // Group the keys for each action, keeping the view's own bindings first
+    let mut actions: Vec<(Action, Vec<String>)> = vec![];
+    for binding in keymap.bindings(scope) {
+        let keys = format_keys(&binding.keys);
+        match actions
+            .iter_mut()
+            .find(|(action, _)| *action == binding.action)
+        {
+            Some((_, action_keys)) => action_keys.push(keys),
+            None => actions.push((binding.action, vec![keys])),
+        }
+    }
+
+    let key_style = theme.accent_style().bold();
+    let lines = actions
+        .into_iter()
+        .map(|(action, keys)| {
+            Line::from(vec![
+                Span::styled(format!("{:<KEYS_WIDTH$}", keys.join(" ")), key_style),
+                Span::raw(action.description()),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let screen = frame.size();
+    let height = u16::try_from(lines.len())
+        .unwrap_or(u16::MAX)
+        .saturating_add(2);
+    let popup_area = centered_popup(
+        screen,
+        POPUP_WIDTH.min(screen.width.saturating_sub(4)),
+        height.min(screen.height.saturating_sub(2)),
+    );
+
+    // Either key closes help, though only one may still be bound
+    let close_keys = [Action::Help, Action::Back]
+        .into_iter()
+        .filter_map(|action| keymap.keys_for(scope, action))
+        .collect::<Vec<_>>();
+    let close_hint = if close_keys.is_empty() {
+        String::new()
+    } else {
+        format!(" {} close ", close_keys.join(" or "))
+    };
+
+    let block = Block::bordered()
+        .title(format!(" Keys · {} ", scope_title(scope)))
+        .title_bottom(Line::from(close_hint).right_aligned())
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.popup).fg(theme.text));
+    let help = Paragraph::new(lines).block(block);
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(help, popup_area);
+}
+
+fn scope_title(scope: KeyScope) -> &'static str {
+    match scope {
+        KeyScope::Global => "everywhere",
+        KeyScope::Month => "calendar",
+        KeyScope::Days => "days",
+        KeyScope::Invitations => "invitations",
+        KeyScope::FreeSlots => "free time",
+        KeyScope::Accounts => "accounts",
+        KeyScope::SignUp => "sign in",
+    }
+}
@@ -0,0 +1,19 @@
+use crate::tui::model::EventsState;
+
+#[derive(Debug)]
+pub struct InvitationsState {
+    /// Events over the days being searched, fetched separately to those shown in
+    /// the calendar as invitations are often sent well ahead
+    pub events: EventsState,
+    /// Position of the highlighted invitation
+    pub selected: usize,
+}
+
+impl Default for InvitationsState {
+    fn default() -> Self {
+        Self {
+            events: EventsState::Loading,
+            selected: 0,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod invitations_state;
+pub(crate) mod pending_invitations;
+pub(crate) mod update_invitations;
+pub(crate) mod view;
@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    events::{Event, ResponseStatus, Transparency},
+    timezone::DisplayTimezone,
+};
+
+/// An event we haven't replied to yet
+#[derive(Debug)]
+pub struct Invitation<'a> {
+    pub event: &'a Event,
+    /// Events we're already going to which overlap the invitation
+    pub conflicts: Vec<&'a Event>,
+}
+
+/// Every event which hasn't finished yet and is waiting for our reply, soonest
+/// first
+pub fn pending_invitations(
+    events: &[Event],
+    now: DateTime<Utc>,
+    display_timezone: DisplayTimezone,
+) -> Vec<Invitation<'_>> {
+    let mut invitations = events
+        .iter()
+        .filter(|event| event.own_response() == Some(ResponseStatus::NeedsAction))
+        .filter(|event| event.time.end_instant(display_timezone) > now)
+        .map(|event| Invitation {
+            event,
+            conflicts: conflicts(event, events, display_timezone),
+        })
+        .collect::<Vec<_>>();
+
+    invitations.sort_by_key(|invitation| invitation.event.time.start_instant(display_timezone));
+    invitations
+}
+
+/// Busy events we've accepted, or organised without inviting ourselves, which
+/// overlap the given event
+fn conflicts<'a>(
+    invitation: &Event,
+    events: &'a [Event],
+    display_timezone: DisplayTimezone,
+) -> Vec<&'a Event> {
+    let start = invitation.time.start_instant(display_timezone);
+    let end = invitation.time.end_instant(display_timezone);
+
+    events
+        .iter()
+        .filter(|event| event.id != invitation.id)
+        .filter(|event| event.transparency == Transparency::Opaque)
+        .filter(|event| match event.own_response() {
+            Some(response) => response == ResponseStatus::Accepted,
+            None => event
+                .organizer
+                .as_ref()
+                .map(|organizer| organizer.is_self)
+                .unwrap_or(true),
+        })
+        // Touching events don't conflict
+        .filter(|event| {
+            event.time.start_instant(display_timezone) < end
+                && event.time.end_instant(display_timezone) > start
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::pending_invitations;
+    use crate::domain::{
+        events::{Attendee, Event, EventTime, ResponseStatus, Transparency},
+        timezone::DisplayTimezone,
+    };
+
+    fn time(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn event(id: &str, start: &str, response: Option<ResponseStatus>) -> Event {
+        let start = time(start);
+
+        Event {
+            id: id.into(),
+            title: id.into(),
+            time: EventTime::Timed {
+                start,
+                end: start + Duration::hours(1),
+            },
+            attendees: response
+                .map(|response_status| Attendee {
+                    email: Some("me@test.com".into()),
+                    display_name: None,
+                    response_status,
+                    comment: None,
+                    optional: false,
+                    organizer: false,
+                    resource: false,
+                    additional_guests: 0,
+                    is_self: true,
+                })
+                .into_iter()
+                .collect(),
+            calendar_id: "me@test.com".into(),
+            ..Event::test_default()
+        }
+    }
+
+    #[test]
+    fn lists_upcoming_invitations_soonest_first() {
+        // Arrange
+        let events = vec![
+            event(
+                "friday",
+                "2024-06-07T09:00:00Z",
+                Some(ResponseStatus::NeedsAction),
+            ),
+            event(
+                "past",
+                "2024-06-02T09:00:00Z",
+                Some(ResponseStatus::NeedsAction),
+            ),
+            event(
+                "answered",
+                "2024-06-04T09:00:00Z",
+                Some(ResponseStatus::Declined),
+            ),
+            event(
+                "tuesday",
+                "2024-06-04T09:00:00Z",
+                Some(ResponseStatus::NeedsAction),
+            ),
+        ];
+
+        // Act
+        let invitations = pending_invitations(
+            &events,
+            time("2024-06-03T09:00:00Z"),
+            DisplayTimezone::Local,
+        );
+
+        // Assert
+        let ids = invitations
+            .iter()
+            .map(|invitation| invitation.event.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["tuesday", "friday"]);
+    }
+
+    #[test]
+    fn flags_overlapping_events_we_are_going_to() {
+        // Arrange
+        let mut free_time = event("focus", "2024-06-04T09:00:00Z", None);
+        free_time.transparency = Transparency::Transparent;
+        let events = vec![
+            event(
+                "invite",
+                "2024-06-04T09:00:00Z",
+                Some(ResponseStatus::NeedsAction),
+            ),
+            event(
+                "accepted",
+                "2024-06-04T09:30:00Z",
+                Some(ResponseStatus::Accepted),
+            ),
+            event("own", "2024-06-04T08:30:00Z", None),
+            event(
+                "declined",
+                "2024-06-04T09:00:00Z",
+                Some(ResponseStatus::Declined),
+            ),
+            event(
+                "adjacent",
+                "2024-06-04T10:00:00Z",
+                Some(ResponseStatus::Accepted),
+            ),
+            free_time,
+        ];
+
+        // Act
+        let invitations = pending_invitations(
+            &events,
+            time("2024-06-03T09:00:00Z"),
+            DisplayTimezone::Local,
+        );
+
+        // Assert
+        let conflicts = invitations[0]
+            .conflicts
+            .iter()
+            .map(|event| event.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(conflicts, vec!["accepted", "own"]);
+    }
+}
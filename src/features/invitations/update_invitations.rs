@@ -0,0 +1,154 @@
+use chrono::Utc;
+
+use crate::{
+    domain::events::{Event, ResponseStatus},
+    features::{
+        event_changes::update_event_changes::run_patch_event_task,
+        fetch_events::fetch_events::fetch_events, month_view::month_view_state::MonthViewState,
+        rsvp::rsvp_request::Rsvp,
+    },
+    tui::model::{CurrentState, EventsState, Message, Model},
+};
+
+use super::{
+    invitations_state::InvitationsState,
+    pending_invitations::{pending_invitations, Invitation},
+};
+
+/// Open the invitations inbox and fetch events over the days it searches
+pub fn handle_open_invitations(model: &mut Model) {
+    model.current_state = CurrentState::Invitations(InvitationsState::default());
+
+    let (start_time, end_time) = model
+        .application
+        .config
+        .invitation_window(model.application.display_timezone);
+
+    let db = model.application.db.clone();
+    let google_client = model.application.google_client.clone();
+    let message_channel = model.message_channel.clone();
+
+    tokio::spawn(async move {
+        let message = match fetch_events(start_time, end_time, db, google_client).await {
+            Ok(events) => Message::InvitationsReady(events),
+            Err(e) => {
+                tracing::error!("Failed to fetch events for invitations: {:?}", e);
+                Message::InvitationsError
+            }
+        };
+
+        message_channel
+            .send(message)
+            .expect("Message channel should never be closed");
+    });
+}
+
+pub fn handle_invitations_ready(model: &mut Model, events: Vec<Event>) {
+    if let CurrentState::Invitations(ref mut state) = model.current_state {
+        state.events = EventsState::Ready(events);
+    }
+}
+
+pub fn handle_invitations_error(model: &mut Model) {
+    if let CurrentState::Invitations(ref mut state) = model.current_state {
+        state.events = EventsState::Error("Failed to fetch events".to_owned());
+    }
+}
+
+pub fn handle_up_message(model: &mut Model) {
+    let count = invitation_count(model);
+    let CurrentState::Invitations(ref mut state) = model.current_state else {
+        return;
+    };
+    if count == 0 {
+        return;
+    }
+
+    let current_index = state.selected.min(count - 1);
+    state.selected = if current_index == 0 {
+        count - 1
+    } else {
+        current_index - 1
+    };
+}
+
+pub fn handle_down_message(model: &mut Model) {
+    let count = invitation_count(model);
+    let CurrentState::Invitations(ref mut state) = model.current_state else {
+        return;
+    };
+    if count == 0 {
+        return;
+    }
+
+    let current_index = state.selected.min(count - 1);
+    state.selected = if current_index == count - 1 {
+        0
+    } else {
+        current_index + 1
+    };
+}
+
+/// Select the first or last invitation
+pub fn handle_jump_message(model: &mut Model, last: bool) {
+    let count = invitation_count(model);
+    let CurrentState::Invitations(ref mut state) = model.current_state else {
+        return;
+    };
+
+    state.selected = if last { count.saturating_sub(1) } else { 0 };
+}
+
+/// Reply to the highlighted invitation, it leaves the list straight away and
+/// comes back if the reply fails
+pub fn handle_reply_message(model: &mut Model, response: ResponseStatus) {
+    let CurrentState::Invitations(ref state) = model.current_state else {
+        return;
+    };
+    let selected = state.selected;
+
+    let Some(original) = invitations(model).and_then(|invitations| {
+        let last_index = invitations.len().checked_sub(1)?;
+        invitations
+            .get(selected.min(last_index))
+            .map(|invitation| invitation.event.to_owned())
+    }) else {
+        return;
+    };
+
+    let rsvp = Rsvp {
+        response,
+        comment: None,
+    };
+    match rsvp.request(&original).and_then(|request| {
+        let updated = rsvp.apply_to(&original)?;
+        Ok((request, updated))
+    }) {
+        Ok((request, updated)) => run_patch_event_task(request, updated, original, model),
+        Err(e) => model.notification = Some(e.to_string()),
+    }
+}
+
+pub fn handle_back_message(model: &mut Model) {
+    model.current_state = CurrentState::MonthView(MonthViewState::default());
+}
+
+fn invitations(model: &Model) -> Option<Vec<Invitation<'_>>> {
+    let CurrentState::Invitations(InvitationsState {
+        events: EventsState::Ready(events),
+        ..
+    }) = &model.current_state
+    else {
+        return None;
+    };
+
+    Some(pending_invitations(
+        events,
+        Utc::now(),
+        model.application.display_timezone,
+    ))
+}
+
+fn invitation_count(model: &Model) -> usize {
+    invitations(model).map_or(0, |invitations| invitations.len())
+}
@@ -0,0 +1,128 @@
+use chrono::Utc;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::domain::{config::Config, events::EventTime, theme::Theme, timezone::DisplayTimezone};
+use crate::tui::model::EventsState;
+
+use super::invitations_state::InvitationsState;
+use super::pending_invitations::{pending_invitations, Invitation};
+
+pub fn render(
+    frame: &mut Frame,
+    state: &InvitationsState,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
+    let block = Block::bordered()
+        .title(" Invitations ")
+        .title_bottom(
+            Line::from(" ↑/↓ select · y accept · m maybe · x decline · esc back ").right_aligned(),
+        )
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.panel).fg(theme.text));
+
+    let events = match &state.events {
+        EventsState::Ready(events) => events,
+        EventsState::Loading => {
+            let loading = Paragraph::new("Loading events...").block(block);
+            frame.render_widget(loading, frame.size());
+            return;
+        }
+        EventsState::Error(error) => {
+            let error = Paragraph::new(error.to_owned())
+                .style(theme.error_style())
+                .block(block);
+            frame.render_widget(error, frame.size());
+            return;
+        }
+    };
+
+    let invitations = pending_invitations(events, Utc::now(), display_timezone);
+    if invitations.is_empty() {
+        let placeholder = Paragraph::new("No invitations are waiting for a reply")
+            .style(theme.muted_style())
+            .block(block);
+        frame.render_widget(placeholder, frame.size());
+        return;
+    }
+
+    let items = invitations
+        .iter()
+        .map(|invitation| invitation_item(invitation, config, theme, display_timezone))
+        .collect::<Vec<_>>();
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.selected.min(invitations.len() - 1)));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme.selected_style())
+        .direction(ListDirection::TopToBottom);
+
+    frame.render_stateful_widget(list, frame.size(), &mut list_state);
+}
+
+fn invitation_item(
+    invitation: &Invitation,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) -> ListItem<'static> {
+    let event = invitation.event;
+    let label_style = theme.muted_style();
+
+    let when = match event.time {
+        EventTime::AllDay { start, .. } => {
+            format!("{} all day", start.format(&config.short_date_format))
+        }
+        _ => display_timezone.format(
+            &event.time.start_instant(display_timezone),
+            &config.date_time_format(),
+        ),
+    };
+    let from = event
+        .organizer
+        .as_ref()
+        .and_then(|organizer| organizer.display_name.as_ref().or(organizer.email.as_ref()))
+        .map(|organizer| format!("from {}", organizer))
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{:<18}", when), label_style),
+            Span::styled(event.title.to_owned(), Style::new().bold()),
+        ]),
+        Line::from(vec![
+            Span::raw(" ".repeat(18)),
+            Span::styled(
+                [event.calendar_title.to_owned().unwrap_or_default(), from]
+                    .into_iter()
+                    .filter(|detail| !detail.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" · "),
+                label_style,
+            ),
+        ]),
+    ];
+
+    if !invitation.conflicts.is_empty() {
+        let titles = invitation
+            .conflicts
+            .iter()
+            .map(|conflict| conflict.title.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(Line::from(vec![
+            Span::raw(" ".repeat(18)),
+            Span::styled(
+                format!("{} Conflicts with {}", theme.symbol("⚠", "!"), titles),
+                theme.warning_style(),
+            ),
+        ]));
+    }
+
+    lines.push(Line::default());
+    ListItem::new(lines)
+}
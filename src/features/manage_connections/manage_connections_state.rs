@@ -14,3 +14,17 @@ pub enum ManageConnectionPanes {
     Accounts,
     Calendars,
 }
+
+impl ManageConnectionsState {
+    /// Calendars belonging to the currently selected account
+    pub fn account_calendars(&self) -> Vec<&Calendar> {
+        let Some(selected_account) = self.accounts.get(self.selected_account_index) else {
+            return vec![];
+        };
+
+        self.calendars
+            .iter()
+            .filter(|cal| cal.account_id == selected_account.id)
+            .collect()
+    }
+}
@@ -2,38 +2,77 @@ use ratatui::prelude::*;
 use ratatui::widgets::block::Title;
 use ratatui::widgets::*;
 
+use crate::domain::colour::Colour;
+use crate::domain::keymap::{Action, KeyScope, Keymap};
+use crate::domain::theme::Theme;
+use crate::util::colour_helpers::nearest_terminal_colour;
+
 use super::manage_connections_state::ManageConnectionsState;
 
-pub fn render(state: &ManageConnectionsState, frame: &mut Frame<'_>) {
+pub fn render(
+    state: &ManageConnectionsState,
+    keymap: &Keymap,
+    theme: &Theme,
+    frame: &mut Frame<'_>,
+) {
+    let help_hint = keymap
+        .keys_for(KeyScope::Accounts, Action::Help)
+        .map(|keys| format!("{}: keys", keys))
+        .unwrap_or_default();
+    let colour_hint = keymap
+        .keys_for(KeyScope::Accounts, Action::CycleColour)
+        .map(|keys| format!("{}: change colour", keys))
+        .unwrap_or_default();
+
     if state.accounts.len() == 0 {
         let account_placeholder = Paragraph::new("Connect an account...");
         frame.render_widget(account_placeholder, frame.size());
         return;
     }
 
-    let selected_account = &state.accounts[state.selected_account_index];
-
     let accounts = state.accounts.iter().map(|acc| acc.email.to_string());
-    let filtered_calendars = state
-        .calendars
-        .iter()
-        .filter(|cal| cal.account_id == selected_account.id)
-        .map(|cal| cal.title.to_string());
+    let filtered_calendars = state.account_calendars().into_iter().map(|cal| {
+        let colour = cal
+            .display_colour()
+            .and_then(Colour::from_hex)
+            .map(|colour| nearest_terminal_colour(&colour));
+
+        Line::from(vec![
+            Span::styled(
+                theme.symbol("● ", "* "),
+                theme.marker_style(colour, Color::Reset),
+            ),
+            Span::raw(cal.title.to_string()),
+        ])
+    });
 
     let mut account_list_state = ListState::default();
     account_list_state.select(Some(state.selected_account_index));
 
+    let mut calendar_list_state = ListState::default();
+    calendar_list_state.select(state.selected_calendar_index);
+
     let vertical_split =
         Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(frame.size());
 
     let accounts_block = Block::bordered()
         .border_type(BorderType::Thick)
-        .title(Title::from("Accounts").alignment(Alignment::Left));
+        .title(Title::from("Accounts").alignment(Alignment::Left))
+        .title(
+            Title::from(help_hint)
+                .alignment(Alignment::Right)
+                .position(block::Position::Bottom),
+        );
 
     let calendars_block = Block::bordered()
         .border_type(BorderType::Thick)
-        .title(Title::from("Calendars").alignment(Alignment::Left));
+        .title(Title::from("Calendars").alignment(Alignment::Left))
+        .title(
+            Title::from(colour_hint)
+                .alignment(Alignment::Right)
+                .position(block::Position::Bottom),
+        );
 
     let account_list = List::new(accounts)
         .block(accounts_block)
@@ -46,5 +85,5 @@ pub fn render(state: &ManageConnectionsState, frame: &mut Frame<'_>) {
         .direction(ListDirection::TopToBottom);
 
     frame.render_stateful_widget(account_list, vertical_split[0], &mut account_list_state);
-    frame.render_widget(calendar_list, vertical_split[1]);
+    frame.render_stateful_widget(calendar_list, vertical_split[1], &mut calendar_list_state);
 }
@@ -1,6 +1,6 @@
 pub mod manage_connections_state;
 pub(crate) mod manage_connections_view;
-mod retrieve_accounts;
+pub(crate) mod retrieve_accounts;
 mod retrieve_calendars;
 pub(crate) mod update_manage_connections;
 
@@ -16,4 +16,15 @@ pub struct Calendar {
     account_id: i64,
     title: String,
     description: Option<String>,
+    background_color: Option<String>,
+    colour_override: Option<String>,
+}
+
+impl Calendar {
+    /// Hex colour to show for the calendar, preferring the user's override
+    pub fn display_colour(&self) -> Option<&str> {
+        self.colour_override
+            .as_deref()
+            .or(self.background_color.as_deref())
+    }
 }
@@ -6,7 +6,8 @@ use super::Calendar;
 pub async fn retrieve_calendars(db: &SqlitePool) -> Result<Vec<Calendar>> {
     let calendars: Vec<Calendar> = sqlx::query_as!(
         Calendar,
-        r#"SELECT id, account_id, title, description FROM calendars"#
+        r#"SELECT id, account_id, title, description, background_color, colour_override
+        FROM calendars"#
     )
     .fetch_all(db)
     .await?;
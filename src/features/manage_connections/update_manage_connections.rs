@@ -1,4 +1,9 @@
-use crate::tui::model::{CurrentState, Model};
+use crate::{
+    features::calendar_colours::{
+        next_colour_override, store_colour_override::store_colour_override,
+    },
+    tui::model::{CurrentState, Model},
+};
 use color_eyre::eyre::Result;
 
 use super::{
@@ -29,14 +34,53 @@ pub fn handle_up_message(model: &mut Model) {
         return;
     };
 
-    if internal_state.focused_pane == ManageConnectionPanes::Accounts {
-        let current_index = internal_state.selected_account_index;
-        let max_index = internal_state.accounts.len() - 1;
+    match internal_state.focused_pane {
+        ManageConnectionPanes::Accounts => {
+            let current_index = internal_state.selected_account_index;
+            let max_index = internal_state.accounts.len() - 1;
+
+            if current_index == 0 {
+                internal_state.selected_account_index = max_index;
+            } else {
+                internal_state.selected_account_index = current_index - 1;
+            }
+        }
+        ManageConnectionPanes::Calendars => {
+            let Some(current_index) = internal_state.selected_calendar_index else {
+                return;
+            };
+            let max_index = internal_state.account_calendars().len() - 1;
+
+            if current_index == 0 {
+                internal_state.selected_calendar_index = Some(max_index);
+            } else {
+                internal_state.selected_calendar_index = Some(current_index - 1);
+            }
+        }
+    }
+}
+
+/// Select the first or last account or calendar in the focused pane
+pub fn handle_jump_message(model: &mut Model, last: bool) {
+    let CurrentState::ManageConnections(ref mut internal_state) = model.current_state else {
+        return;
+    };
 
-        if current_index == 0 {
-            internal_state.selected_account_index = max_index;
-        } else {
-            internal_state.selected_account_index = current_index - 1;
+    match internal_state.focused_pane {
+        ManageConnectionPanes::Accounts => {
+            internal_state.selected_account_index = if last {
+                internal_state.accounts.len().saturating_sub(1)
+            } else {
+                0
+            };
+        }
+        ManageConnectionPanes::Calendars => {
+            if internal_state.selected_calendar_index.is_none() {
+                return;
+            }
+            let count = internal_state.account_calendars().len();
+            internal_state.selected_calendar_index =
+                Some(if last { count.saturating_sub(1) } else { 0 });
         }
     }
 }
@@ -46,14 +90,86 @@ pub fn handle_down_message(model: &mut Model) {
         return;
     };
 
-    if internal_state.focused_pane == ManageConnectionPanes::Accounts {
-        let current_index = internal_state.selected_account_index;
-        let max_index = internal_state.accounts.len() - 1;
+    match internal_state.focused_pane {
+        ManageConnectionPanes::Accounts => {
+            let current_index = internal_state.selected_account_index;
+            let max_index = internal_state.accounts.len() - 1;
 
-        if current_index == max_index {
-            internal_state.selected_account_index = 0;
-        } else {
-            internal_state.selected_account_index = current_index + 1;
+            if current_index == max_index {
+                internal_state.selected_account_index = 0;
+            } else {
+                internal_state.selected_account_index = current_index + 1;
+            }
         }
+        ManageConnectionPanes::Calendars => {
+            let Some(current_index) = internal_state.selected_calendar_index else {
+                return;
+            };
+            let max_index = internal_state.account_calendars().len() - 1;
+
+            if current_index == max_index {
+                internal_state.selected_calendar_index = Some(0);
+            } else {
+                internal_state.selected_calendar_index = Some(current_index + 1);
+            }
+        }
+    }
+}
+
+pub fn handle_left_message(model: &mut Model) {
+    let CurrentState::ManageConnections(ref mut internal_state) = model.current_state else {
+        return;
+    };
+
+    internal_state.focused_pane = ManageConnectionPanes::Accounts;
+    internal_state.selected_calendar_index = None;
+}
+
+pub fn handle_right_message(model: &mut Model) {
+    let CurrentState::ManageConnections(ref mut internal_state) = model.current_state else {
+        return;
+    };
+
+    if internal_state.account_calendars().is_empty() {
+        return;
     }
+
+    internal_state.focused_pane = ManageConnectionPanes::Calendars;
+    internal_state.selected_calendar_index = Some(0);
+}
+
+/// Step the selected calendar on to the next colour override and persist it
+pub async fn handle_cycle_colour_message(model: &mut Model) -> Result<()> {
+    let CurrentState::ManageConnections(ref mut internal_state) = model.current_state else {
+        return Ok(());
+    };
+
+    let Some(selected_calendar_index) = internal_state.selected_calendar_index else {
+        return Ok(());
+    };
+    let Some(selected_calendar_id) = internal_state
+        .account_calendars()
+        .get(selected_calendar_index)
+        .map(|cal| cal.id)
+    else {
+        return Ok(());
+    };
+    let Some(calendar) = internal_state
+        .calendars
+        .iter_mut()
+        .find(|cal| cal.id == selected_calendar_id)
+    else {
+        return Ok(());
+    };
+
+    let colour_override = next_colour_override(calendar.colour_override.as_deref());
+    store_colour_override(
+        calendar.id,
+        colour_override.as_deref(),
+        &model.application.db,
+    )
+    .await?;
+    calendar.colour_override = colour_override;
+
+    Ok(())
 }
@@ -1,7 +1,19 @@
+pub(crate) mod calendar_colours;
+pub(crate) mod daemon;
 pub(crate) mod day_summary;
 pub(crate) mod days_view;
+pub(crate) mod event_changes;
+pub(crate) mod event_details;
+pub(crate) mod event_form;
 pub(crate) mod fetch_events;
+pub(crate) mod free_slots;
+pub(crate) mod help;
+pub(crate) mod invitations;
 pub(crate) mod manage_connections;
 pub(crate) mod month_view;
 pub(crate) mod new_account;
 pub mod oauth_http_client;
+pub(crate) mod quick_add;
+pub(crate) mod reminders;
+pub(crate) mod rsvp;
+pub(crate) mod scheduling;
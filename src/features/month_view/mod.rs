@@ -1 +1,2 @@
+pub(crate) mod month_view_state;
 pub(crate) mod view;
@@ -0,0 +1,7 @@
+use crate::features::day_summary::day_summary_state::DaySummaryState;
+
+#[derive(Debug, Default)]
+pub struct MonthViewState {
+    /// Today's events listed beside the month
+    pub summary: DaySummaryState,
+}
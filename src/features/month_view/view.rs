@@ -1,25 +1,70 @@
-use chrono::Local;
 use ratatui::prelude::*;
-use ratatui::style::palette::tailwind;
 use ratatui::widgets::*;
 
+use crate::domain::{
+    config::Config,
+    keymap::{Action, KeyScope},
+    theme::Theme,
+    timezone::DisplayTimezone,
+};
 use crate::features::day_summary::components::day_summary_widget::DaySummaryWidget;
-use crate::tui::model::EventsState;
+use crate::features::event_details;
+use crate::tui::model::{EventFilters, EventsState};
 
-pub fn render(frame: &mut Frame, events_state: &EventsState) {
-    let (events, day_events) = match events_state {
-        EventsState::Ready(events, day_events) => (events, day_events),
-        _ => return,
+use super::month_view_state::MonthViewState;
+
+pub fn render(
+    frame: &mut Frame,
+    state: &MonthViewState,
+    events_state: &EventsState,
+    event_filters: &EventFilters,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
+    let events = match events_state {
+        EventsState::Ready(events) => events,
+        EventsState::Loading => return,
+        EventsState::Error(error) => {
+            let error = Paragraph::new(error.to_owned())
+                .centered()
+                .style(theme.error_style())
+                .block(Block::default().style(Style::new().bg(theme.background)));
+            frame.render_widget(error, frame.size());
+            return;
+        }
     };
 
     let main_layout =
         Layout::horizontal([Constraint::Fill(3), Constraint::Fill(1)]).split(frame.size());
 
-    let month_block = Block::default().style(Style::new().bg(tailwind::STONE.c200));
+    let mut month_block = Block::default().style(Style::new().bg(theme.background).fg(theme.text));
+    if let Some(keys) = config.keymap.keys_for(KeyScope::Month, Action::Help) {
+        month_block = month_block.title_bottom(format!(" {} keys ", keys));
+    }
     let month_view_placeholder = Paragraph::new("month view").block(month_block);
 
-    let today_widget = DaySummaryWidget::new(Local::now().date_naive(), events, day_events);
+    let today_widget = DaySummaryWidget::new(
+        display_timezone.today(),
+        events,
+        config,
+        theme,
+        display_timezone,
+    )
+    .hide_tentative(event_filters.hide_tentative)
+    .selected(Some(state.summary.selected_event));
 
     frame.render_widget(month_view_placeholder, main_layout[0]);
     frame.render_widget(today_widget, main_layout[1]);
+
+    if let Some(event_details) = &state.summary.event_details {
+        event_details::view::render(
+            frame,
+            event_details,
+            KeyScope::Month,
+            config,
+            theme,
+            display_timezone,
+        );
+    }
 }
@@ -19,6 +19,12 @@ struct CalendarResource {
     primary: Option<bool>,
     summary: String,
     description: Option<String>,
+    #[serde(rename = "colorId")]
+    color_id: Option<String>,
+    #[serde(rename = "backgroundColor")]
+    background_color: Option<String>,
+    #[serde(rename = "accessRole")]
+    access_role: Option<String>,
 }
 
 struct Calendar {
@@ -26,6 +32,9 @@ struct Calendar {
     primary_calendar: bool,
     title: String,
     description: Option<String>,
+    color_id: Option<String>,
+    background_color: Option<String>,
+    access_role: Option<String>,
 }
 
 impl From<CalendarResource> for Calendar {
@@ -35,6 +44,9 @@ impl From<CalendarResource> for Calendar {
             primary_calendar: calendar.primary.unwrap_or(false),
             title: calendar.summary,
             description: calendar.description,
+            color_id: calendar.color_id,
+            background_color: calendar.background_color,
+            access_role: calendar.access_role,
         }
     }
 }
@@ -89,17 +101,23 @@ async fn store_calendars(
 async fn store_row(calendar: Calendar, account_id: i64, db: &SqlitePool) -> Result<()> {
     let _ = sqlx::query!(
         "INSERT INTO calendars
-        (calendar_id, account_id, title, description, primary_calendar)
-        VALUES ($1, $2, $3, $4, $5)
+        (calendar_id, account_id, title, description, primary_calendar, color_id, background_color, access_role)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         ON CONFLICT (calendar_id)
         DO UPDATE SET title=excluded.title,
             description=excluded.description,
-            primary_calendar=excluded.primary_calendar",
+            primary_calendar=excluded.primary_calendar,
+            color_id=excluded.color_id,
+            background_color=excluded.background_color,
+            access_role=excluded.access_role",
         calendar.id,
         account_id,
         calendar.title,
         calendar.description,
-        calendar.primary_calendar
+        calendar.primary_calendar,
+        calendar.color_id,
+        calendar.background_color,
+        calendar.access_role
     )
     .execute(db)
     .await?;
@@ -1,10 +1,11 @@
+use crate::domain::theme::Theme;
 use crate::tui::util::centered_popup;
 
 use ratatui::layout::Flex;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-pub fn render_sign_in_options(frame: &mut Frame, selected_index: usize) {
+pub fn render_sign_in_options(frame: &mut Frame, selected_index: usize, theme: &Theme) {
     // Need to render the account page first to render selections as popup over them
     //todo!();
 
@@ -19,10 +20,10 @@ pub fn render_sign_in_options(frame: &mut Frame, selected_index: usize) {
     let title = Paragraph::new("Log in").centered();
 
     let list = List::new(vec!["Open browser", "Copy link to clipboard"])
-        .style(Style::default().fg(Color::Magenta))
+        .style(Style::default().fg(theme.accent))
         .highlight_style(
             Style::default()
-                .fg(Color::Green)
+                .fg(theme.success)
                 .add_modifier(Modifier::ITALIC),
         )
         .highlight_symbol(">")
@@ -0,0 +1,5 @@
+pub(crate) mod parse_quick_add;
+pub(crate) mod quick_add_command;
+pub(crate) mod quick_add_state;
+pub(crate) mod update_quick_add;
+pub(crate) mod view;
@@ -0,0 +1,736 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, Duration, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use thiserror::Error;
+
+use crate::{
+    domain::{calendar::Calendar, config::Config, timezone::DisplayTimezone},
+    features::event_form::event_request::{EventDateRequest, EventRequest},
+};
+
+const DEFAULT_DURATION_MINUTES: i64 = 60;
+
+/// An event described in a single line, e.g. "Lunch with Sam tomorrow 1pm for
+/// 1h @work"
+#[derive(Debug, PartialEq, Eq)]
+pub struct QuickAdd {
+    pub title: String,
+    pub time: QuickAddTime,
+    /// Name of the calendar to add the event to, given after an `@`
+    pub calendar: Option<String>,
+}
+
+/// Wall clock times in the display time zone
+#[derive(Debug, PartialEq, Eq)]
+pub enum QuickAddTime {
+    Timed {
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    },
+    /// Ends at the start of `end`, the day after the last
+    AllDay { start: NaiveDate, end: NaiveDate },
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum QuickAddError {
+    #[error("The event needs a title")]
+    MissingTitle,
+    #[error("No writable calendar matches \"{0}\"")]
+    UnknownCalendar(String),
+    #[error("There are no calendars the event can be added to")]
+    NoCalendar,
+    #[error("The event would end too far in the future")]
+    TooLong,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+/// A time as written, before it is known whether it is morning or afternoon
+#[derive(Debug, Clone, Copy)]
+struct ClockTime {
+    hour: u32,
+    minute: u32,
+    meridiem: Option<Meridiem>,
+    /// Just a number, e.g. the "1" of "1-2pm", which isn't a time on its own
+    is_bare: bool,
+}
+
+/// Pieces of the event found so far, the first of each kind wins and any
+/// repeats are left in the title
+#[derive(Default)]
+struct Parts {
+    date: Option<NaiveDate>,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+    duration: Option<Duration>,
+    all_day: bool,
+    calendar: Option<String>,
+}
+
+/// Parse a line describing an event. Dates and times are relative to `now`, a
+/// wall clock time in the display time zone, so parsing is repeatable
+///
+/// Understands:
+/// - dates: "today", "tomorrow", weekdays ("friday" is the next Friday, today
+///   included, "next friday" is the Friday of next week), "next week" for the
+///   first day of next week, "in 3 days", "3 jun", "june 3rd" and "2024-06-03"
+/// - times: "1pm", "1:30pm", "13:00", "noon", ranges such as "1-2pm" or "9am to
+///   10:30am", optionally after "at" or "from"
+/// - durations: "for 1h", "for 1h30m", "for 90 minutes", "for 3 days"
+/// - "all day", and "@name" to pick a calendar
+///
+/// Events without a time are all day. Times without a date are today, or
+/// tomorrow if the time has already passed
+/// week_start: first day of the week, for "next week" and "next friday"
+pub fn parse_quick_add(
+    input: &str,
+    now: NaiveDateTime,
+    week_start: Weekday,
+) -> Result<QuickAdd, QuickAddError> {
+    let today = now.date();
+    let words = input.split_whitespace().collect::<Vec<_>>();
+
+    let mut parts = Parts::default();
+    let mut title = vec![];
+    let mut index = 0;
+    while index < words.len() {
+        match parts.parse(&words[index..], today, week_start) {
+            Some(consumed) => index += consumed,
+            None => {
+                title.push(words[index]);
+                index += 1;
+            }
+        }
+    }
+
+    let title = title.join(" ");
+    if title.is_empty() {
+        return Err(QuickAddError::MissingTitle);
+    }
+
+    let time = match parts.start {
+        Some(start) if !parts.all_day => {
+            let date = parts.date.unwrap_or(if today.and_time(start) > now {
+                today
+            } else {
+                today + Days::new(1)
+            });
+            let start = date.and_time(start);
+            let end = match parts.end {
+                // "10pm-1am" ends the next day
+                Some(end) if date.and_time(end) <= start => {
+                    date.and_time(end).checked_add_signed(Duration::days(1))
+                }
+                Some(end) => Some(date.and_time(end)),
+                None => start.checked_add_signed(
+                    parts
+                        .duration
+                        .unwrap_or(Duration::minutes(DEFAULT_DURATION_MINUTES)),
+                ),
+            };
+
+            QuickAddTime::Timed {
+                start,
+                end: end.ok_or(QuickAddError::TooLong)?,
+            }
+        }
+        _ => {
+            let start = parts.date.unwrap_or(today);
+            let days = parts
+                .duration
+                .map(|duration| duration.num_days().max(1) as u64)
+                .unwrap_or(1);
+
+            QuickAddTime::AllDay {
+                start,
+                end: start
+                    .checked_add_days(Days::new(days))
+                    .ok_or(QuickAddError::TooLong)?,
+            }
+        }
+    };
+
+    Ok(QuickAdd {
+        title,
+        time,
+        calendar: parts.calendar,
+    })
+}
+
+impl Parts {
+    /// Try to parse the start of the words, returning how many were used
+    fn parse(&mut self, words: &[&str], today: NaiveDate, week_start: Weekday) -> Option<usize> {
+        let word = normalise(words[0]);
+
+        if let Some(name) = words[0].strip_prefix('@') {
+            if !name.is_empty() && self.calendar.is_none() {
+                self.calendar = Some(name.to_owned());
+                return Some(1);
+            }
+        }
+
+        if word == "all" && words.get(1).map(|word| normalise(word)).as_deref() == Some("day")
+            || word == "allday"
+        {
+            if !self.all_day {
+                self.all_day = true;
+                return Some(if word == "all" { 2 } else { 1 });
+            }
+            return None;
+        }
+
+        if word == "for" && self.duration.is_none() {
+            if let Some((duration, consumed)) = parse_duration(&words[1..]) {
+                self.duration = Some(duration);
+                return Some(consumed + 1);
+            }
+        }
+
+        // Words joining a date or time onto the title are dropped along with it
+        let (skipped, rest) = match word.as_str() {
+            "on" | "at" | "from" if words.len() > 1 => (1, &words[1..]),
+            _ => (0, words),
+        };
+
+        if self.date.is_none() {
+            if let Some((date, consumed)) = parse_date(rest, today, week_start) {
+                self.date = Some(date);
+                return Some(skipped + consumed);
+            }
+        }
+
+        if self.start.is_none() {
+            if let Some((start, end, consumed)) = parse_time_range(rest) {
+                self.start = Some(start);
+                self.end = end;
+                return Some(skipped + consumed);
+            }
+        }
+
+        None
+    }
+}
+
+fn normalise(word: &str) -> String {
+    word.trim_end_matches([',', '.', '!', '?']).to_lowercase()
+}
+
+fn parse_date(words: &[&str], today: NaiveDate, week_start: Weekday) -> Option<(NaiveDate, usize)> {
+    let word = normalise(words.first()?);
+    let next_word = words.get(1).map(|word| normalise(word));
+
+    match word.as_str() {
+        "today" | "tonight" => return Some((today, 1)),
+        "tomorrow" | "tmrw" | "tmr" => return Some((today.checked_add_days(Days::new(1))?, 1)),
+        "next" => {
+            let next_week = today.checked_add_days(Days::new(u64::from(
+                7 - today.weekday().days_since(week_start),
+            )))?;
+            if next_word.as_deref() == Some("week") {
+                return Some((next_week, 2));
+            }
+            let weekday = Weekday::from_str(next_word.as_deref()?).ok()?;
+            return Some((next_weekday(next_week, weekday)?, 2));
+        }
+        "in" => {
+            let count: u64 = next_word?.parse().ok()?;
+            let unit = normalise(words.get(2)?);
+            let days = match unit.as_str() {
+                "day" | "days" => count,
+                "week" | "weeks" => count.checked_mul(7)?,
+                _ => return None,
+            };
+            return Some((today.checked_add_days(Days::new(days))?, 3));
+        }
+        _ => {}
+    }
+
+    if let Ok(weekday) = Weekday::from_str(&word) {
+        return Some((next_weekday(today, weekday)?, 1));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(&word, "%Y-%m-%d") {
+        return Some((date, 1));
+    }
+
+    // "3 jun" or "june 3rd", in the next year if the day has passed
+    let next_word = next_word?;
+    let (day, month) = match (parse_day(&word), Month::from_str(&word)) {
+        (Some(day), _) => (day, Month::from_str(&next_word).ok()?),
+        (_, Ok(month)) => (parse_day(&next_word)?, month),
+        _ => return None,
+    };
+    let date = NaiveDate::from_ymd_opt(today.year(), month.number_from_month(), day)?;
+    if date < today {
+        return Some((date.with_year(today.year() + 1)?, 2));
+    }
+    Some((date, 2))
+}
+
+/// The first given weekday on or after the date
+fn next_weekday(from: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let days_until =
+        (7 + weekday.num_days_from_monday() - from.weekday().num_days_from_monday()) % 7;
+    from.checked_add_days(Days::new(days_until.into()))
+}
+
+/// Day of the month, e.g. "3" or "3rd"
+fn parse_day(word: &str) -> Option<u32> {
+    let day = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    if day.len() == word.len() || ["st", "nd", "rd", "th"].contains(&&word[day.len()..]) {
+        day.parse().ok().filter(|day| (1..=31).contains(day))
+    } else {
+        None
+    }
+}
+
+fn parse_clock(word: &str) -> Option<ClockTime> {
+    let word = normalise(word);
+    match word.as_str() {
+        "noon" | "midday" => {
+            return Some(ClockTime {
+                hour: 12,
+                minute: 0,
+                meridiem: Some(Meridiem::Pm),
+                is_bare: false,
+            })
+        }
+        "midnight" => {
+            return Some(ClockTime {
+                hour: 12,
+                minute: 0,
+                meridiem: Some(Meridiem::Am),
+                is_bare: false,
+            })
+        }
+        _ => {}
+    }
+
+    let (body, meridiem) = if let Some(body) = word.strip_suffix("am") {
+        (body, Some(Meridiem::Am))
+    } else if let Some(body) = word.strip_suffix("pm") {
+        (body, Some(Meridiem::Pm))
+    } else {
+        (word.as_str(), None)
+    };
+
+    let (hour, minute, has_minutes) = match body.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => {
+            (hour.parse().ok()?, minute.parse().ok()?, true)
+        }
+        Some(_) => return None,
+        None => (body.parse().ok()?, 0, false),
+    };
+    let valid_hours = if meridiem.is_some() { 1..=12 } else { 0..=23 };
+    if !valid_hours.contains(&hour) || minute >= 60 {
+        return None;
+    }
+
+    Some(ClockTime {
+        hour,
+        minute,
+        meridiem,
+        is_bare: meridiem.is_none() && !has_minutes,
+    })
+}
+
+impl ClockTime {
+    fn resolve(&self, meridiem: Option<Meridiem>) -> Option<NaiveTime> {
+        let hour = match self.meridiem.or(meridiem) {
+            Some(_) if self.hour > 12 => return None,
+            Some(Meridiem::Am) => self.hour % 12,
+            Some(Meridiem::Pm) => self.hour % 12 + 12,
+            None => self.hour,
+        };
+
+        NaiveTime::from_hms_opt(hour, self.minute, 0)
+    }
+}
+
+/// A time, or a range of times, e.g. "1pm", "1-2pm" or "9am to 10am"
+fn parse_time_range(words: &[&str]) -> Option<(NaiveTime, Option<NaiveTime>, usize)> {
+    let first = words.first()?;
+
+    if let Some((start, end)) = first.split_once('-') {
+        let (start, end) = resolve_range(parse_clock(start)?, parse_clock(end)?)?;
+        return Some((start, Some(end), 1));
+    }
+
+    let start = parse_clock(first)?;
+    if let (Some(separator), Some(end)) = (words.get(1), words.get(2)) {
+        if matches!(normalise(separator).as_str(), "-" | "to" | "until" | "till") {
+            if let Some((start, end)) = parse_clock(end).and_then(|end| resolve_range(start, end)) {
+                return Some((start, Some(end), 3));
+            }
+        }
+    }
+
+    if start.is_bare {
+        return None;
+    }
+    Some((start.resolve(None)?, None, 1))
+}
+
+fn resolve_range(start: ClockTime, end: ClockTime) -> Option<(NaiveTime, NaiveTime)> {
+    if end.is_bare {
+        return None;
+    }
+    let end_time = end.resolve(None)?;
+
+    // The start takes the end's am or pm unless that would put it after the
+    // end, so "1-2pm" starts at 1pm but "11-1pm" starts at 11am
+    if let (None, Some(meridiem)) = (start.meridiem, end.meridiem) {
+        if let Some(start_time) = start
+            .resolve(Some(meridiem))
+            .filter(|time| *time < end_time)
+        {
+            return Some((start_time, end_time));
+        }
+        let opposite = match meridiem {
+            Meridiem::Am => Meridiem::Pm,
+            Meridiem::Pm => Meridiem::Am,
+        };
+        return Some((start.resolve(Some(opposite))?, end_time));
+    }
+
+    Some((start.resolve(None)?, end_time))
+}
+
+/// A length of time, e.g. "1h", "1h30m", "90 minutes", "an hour" or "3 days"
+fn parse_duration(words: &[&str]) -> Option<(Duration, usize)> {
+    let first = normalise(words.first()?);
+    if let Some(duration) = parse_compact_duration(&first) {
+        return Some((duration, 1));
+    }
+
+    let unit = normalise(words.get(1)?);
+    let count = match first.as_str() {
+        "a" | "an" => "1",
+        count => count,
+    };
+    parse_compact_duration(&format!("{}{}", count, unit)).map(|duration| (duration, 2))
+}
+
+fn parse_compact_duration(value: &str) -> Option<Duration> {
+    let mut total = Duration::zero();
+    let mut rest = value;
+    let mut had_hours = false;
+
+    while !rest.is_empty() {
+        let digits_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let count: i64 = rest[..digits_end].parse().ok()?;
+        rest = &rest[digits_end..];
+
+        let unit_end = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit = &rest[..unit_end];
+        rest = &rest[unit_end..];
+
+        // Numbers too large for a duration mean it isn't one
+        let duration = match unit {
+            "d" | "day" | "days" => Duration::try_days(count)?,
+            "h" | "hr" | "hrs" | "hour" | "hours" => {
+                had_hours = true;
+                Duration::try_hours(count)?
+            }
+            "m" | "min" | "mins" | "minute" | "minutes" => Duration::try_minutes(count)?,
+            // The minutes of "1h30"
+            "" if had_hours => Duration::try_minutes(count)?,
+            _ => return None,
+        };
+        total = total.checked_add(&duration)?;
+    }
+
+    (total > Duration::zero()).then_some(total)
+}
+
+impl QuickAdd {
+    /// The calendar named after the `@`, matching its whole title first, then the
+    /// start of it, then any part. Without a name the first calendar is used
+    pub fn resolve_calendar<'a>(
+        &self,
+        calendars: &'a [Calendar],
+    ) -> Result<&'a Calendar, QuickAddError> {
+        let Some(name) = &self.calendar else {
+            return calendars.first().ok_or(QuickAddError::NoCalendar);
+        };
+        let name = name.to_lowercase();
+        let titles = calendars
+            .iter()
+            .map(|calendar| (calendar, calendar.title.to_lowercase()))
+            .collect::<Vec<_>>();
+
+        titles
+            .iter()
+            .find(|(_, title)| *title == name)
+            .or_else(|| titles.iter().find(|(_, title)| title.starts_with(&name)))
+            .or_else(|| titles.iter().find(|(_, title)| title.contains(&name)))
+            .map(|(calendar, _)| *calendar)
+            .ok_or(QuickAddError::UnknownCalendar(name))
+    }
+
+    pub fn to_request(&self, display_timezone: DisplayTimezone) -> EventRequest {
+        let (start, end) = match self.time {
+            QuickAddTime::Timed { start, end } => (
+                EventDateRequest::date_time(display_timezone.to_utc(&start)),
+                EventDateRequest::date_time(display_timezone.to_utc(&end)),
+            ),
+            QuickAddTime::AllDay { start, end } => {
+                (EventDateRequest::date(start), EventDateRequest::date(end))
+            }
+        };
+
+        EventRequest {
+            summary: self.title.to_owned(),
+            location: None,
+            description: None,
+            start,
+            end,
+            attendees: None,
+        }
+    }
+
+    /// Example: "Lunch with Sam, Tue 4 Jun 13:00 – 14:00"
+    pub fn summary(&self, config: &Config) -> String {
+        let date_format = config.short_date_format.as_str();
+        let when = match self.time {
+            QuickAddTime::Timed { start, end } => {
+                let date_time_format = config.date_time_format();
+                let end_format = if end.date() == start.date() {
+                    config.time_format()
+                } else {
+                    &date_time_format
+                };
+                format!(
+                    "{} – {}",
+                    start.format(&date_time_format),
+                    end.format(end_format)
+                )
+            }
+            QuickAddTime::AllDay { start, end } if start.succ_opt() == Some(end) => {
+                format!("{}, all day", start.format(date_format))
+            }
+            QuickAddTime::AllDay { start, end } => format!(
+                "{} – {}, all day",
+                start.format(date_format),
+                end.pred_opt().unwrap_or(end).format(date_format)
+            ),
+        };
+
+        format!("{}, {}", self.title, when)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDate, NaiveDateTime, Weekday};
+
+    use super::{parse_quick_add, QuickAdd, QuickAddError, QuickAddTime};
+    use crate::domain::calendar::Calendar;
+
+    /// Monday 3rd June 2024, 10:00
+    fn now() -> NaiveDateTime {
+        date(2024, 6, 3).and_hms_opt(10, 0, 0).unwrap()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn timed(date: NaiveDate, hour: u32, minute: u32, minutes: i64) -> QuickAddTime {
+        let start = date.and_hms_opt(hour, minute, 0).unwrap();
+        QuickAddTime::Timed {
+            start,
+            end: start + Duration::minutes(minutes),
+        }
+    }
+
+    fn calendar(title: &str) -> Calendar {
+        Calendar {
+            calendar_id: format!("{}@test.com", title),
+            account_id: 1,
+            title: title.into(),
+            color_id: None,
+            background_color: None,
+            colour_override: None,
+            access_role: Some("owner".into()),
+        }
+    }
+
+    #[test]
+    fn parses_title_time_duration_and_calendar() {
+        let quick_add = parse_quick_add(
+            "Lunch with Sam tomorrow 1pm for 1h @work",
+            now(),
+            Weekday::Mon,
+        );
+
+        assert_eq!(
+            quick_add,
+            Ok(QuickAdd {
+                title: "Lunch with Sam".into(),
+                time: timed(date(2024, 6, 4), 13, 0, 60),
+                calendar: Some("work".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_dates() {
+        let cases = [
+            ("Review today 3pm", date(2024, 6, 3)),
+            ("Review on friday at 3pm", date(2024, 6, 7)),
+            ("Review monday 3pm", date(2024, 6, 3)),
+            ("Review next monday 3pm", date(2024, 6, 10)),
+            ("Review in 2 weeks 3pm", date(2024, 6, 17)),
+            ("Review 12 jun 3pm", date(2024, 6, 12)),
+            ("Review June 1st 3pm", date(2025, 6, 1)),
+            ("Review 2024-07-01 3pm", date(2024, 7, 1)),
+        ];
+
+        for (input, expected) in cases {
+            let quick_add = parse_quick_add(input, now(), Weekday::Mon).unwrap();
+            assert_eq!(quick_add.title, "Review", "{}", input);
+            assert_eq!(quick_add.time, timed(expected, 15, 0, 60), "{}", input);
+        }
+    }
+
+    #[test]
+    fn next_week_starts_on_the_configured_day() {
+        let cases = [
+            ("Review next week 3pm", Weekday::Mon, date(2024, 6, 10)),
+            ("Review next week 3pm", Weekday::Sun, date(2024, 6, 9)),
+            ("Review next sunday 3pm", Weekday::Mon, date(2024, 6, 16)),
+            ("Review next sunday 3pm", Weekday::Sun, date(2024, 6, 9)),
+            ("Review next friday 3pm", Weekday::Sat, date(2024, 6, 14)),
+        ];
+
+        for (input, week_start, expected) in cases {
+            let quick_add = parse_quick_add(input, now(), week_start).unwrap();
+            assert_eq!(quick_add.title, "Review", "{}", input);
+            assert_eq!(quick_add.time, timed(expected, 15, 0, 60), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parses_times_and_ranges() {
+        let today = date(2024, 6, 3);
+        let cases = [
+            ("Call at 13:30", timed(today, 13, 30, 60)),
+            ("Call noon for 30 mins", timed(today, 12, 0, 30)),
+            ("Call 1-2:30pm", timed(today, 13, 0, 90)),
+            ("Call 11-1pm", timed(today, 11, 0, 120)),
+            ("Call from 4pm to 5pm", timed(today, 16, 0, 60)),
+            ("Call 3pm for 1h30", timed(today, 15, 0, 90)),
+            ("Call 10pm-1am", timed(today, 22, 0, 180)),
+            // Already passed today
+            ("Call 9am", timed(date(2024, 6, 4), 9, 0, 60)),
+        ];
+
+        for (input, expected) in cases {
+            let quick_add = parse_quick_add(input, now(), Weekday::Mon).unwrap();
+            assert_eq!(quick_add.title, "Call", "{}", input);
+            assert_eq!(quick_add.time, expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn events_without_times_are_all_day() {
+        assert_eq!(
+            parse_quick_add("Offsite wednesday for 3 days", now(), Weekday::Mon)
+                .unwrap()
+                .time,
+            QuickAddTime::AllDay {
+                start: date(2024, 6, 5),
+                end: date(2024, 6, 8)
+            }
+        );
+        assert_eq!(
+            parse_quick_add("Conference tomorrow 9am all day", now(), Weekday::Mon)
+                .unwrap()
+                .time,
+            QuickAddTime::AllDay {
+                start: date(2024, 6, 4),
+                end: date(2024, 6, 5)
+            }
+        );
+    }
+
+    #[test]
+    fn words_which_are_not_dates_or_times_stay_in_the_title() {
+        let quick_add =
+            parse_quick_add("Coffee at Joe's for 2 people 3pm", now(), Weekday::Mon).unwrap();
+
+        assert_eq!(quick_add.title, "Coffee at Joe's for 2 people");
+        assert_eq!(quick_add.time, timed(date(2024, 6, 3), 15, 0, 60));
+    }
+
+    #[test]
+    fn numbers_too_large_for_a_date_or_duration_are_not_parsed() {
+        // Arrange
+        let cases = [
+            ("Trip in 99999999999 days", "Trip in 99999999999 days"),
+            (
+                "Trip in 9999999999999999999 weeks",
+                "Trip in 9999999999999999999 weeks",
+            ),
+            (
+                "Lunch 1pm for 9999999999999 hours",
+                "Lunch for 9999999999999 hours",
+            ),
+        ];
+
+        for (input, expected_title) in cases {
+            // Act
+            let quick_add = parse_quick_add(input, now(), Weekday::Mon);
+
+            // Assert
+            assert_eq!(quick_add.unwrap().title, expected_title, "{}", input);
+        }
+        assert_eq!(
+            parse_quick_add("Lunch 1pm for 99999999999 hours", now(), Weekday::Mon),
+            Err(QuickAddError::TooLong)
+        );
+        assert_eq!(
+            parse_quick_add("Sabbatical for 99999999999 days", now(), Weekday::Mon),
+            Err(QuickAddError::TooLong)
+        );
+    }
+
+    #[test]
+    fn needs_a_title() {
+        assert_eq!(
+            parse_quick_add("tomorrow 1pm @work", now(), Weekday::Mon),
+            Err(QuickAddError::MissingTitle)
+        );
+    }
+
+    #[test]
+    fn resolves_calendars_by_name() {
+        let calendars = vec![
+            calendar("Personal"),
+            calendar("Team work"),
+            calendar("Work"),
+        ];
+        let resolve = |input: &str| {
+            parse_quick_add(input, now(), Weekday::Mon)
+                .unwrap()
+                .resolve_calendar(&calendars)
+                .map(|calendar| calendar.title.as_str())
+        };
+
+        assert_eq!(resolve("Gym"), Ok("Personal"));
+        assert_eq!(resolve("Standup @work"), Ok("Work"));
+        assert_eq!(resolve("Standup @team"), Ok("Team work"));
+        assert_eq!(
+            resolve("Standup @home"),
+            Err(QuickAddError::UnknownCalendar("home".into()))
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::Utc;
+use color_eyre::eyre::Result;
+
+use crate::{
+    configuration::Application,
+    features::event_form::{
+        insert_event::insert_event, retrieve_calendars::retrieve_writable_calendars,
+    },
+};
+
+use super::parse_quick_add::parse_quick_add;
+
+/// Create an event described in a single line from the command line, printing
+/// what was understood first
+pub async fn run_quick_add_command(
+    application: &Application,
+    input: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let display_timezone = application.display_timezone;
+    let calendars = retrieve_writable_calendars(&application.db).await?;
+
+    let now = display_timezone.to_local(&Utc::now());
+    let quick_add = parse_quick_add(input, now, application.config.week_start)?;
+    let calendar = quick_add.resolve_calendar(&calendars)?;
+    println!(
+        "{} on {}",
+        quick_add.summary(&application.config),
+        calendar.title
+    );
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let event = insert_event(
+        &quick_add.to_request(display_timezone),
+        &calendar.calendar_id,
+        calendar.account_id,
+        &application.google_client,
+    )
+    .await?;
+    println!("Created event {}", event.id);
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use crate::domain::calendar::Calendar;
+
+/// A single line describing an event, e.g. "Lunch with Sam tomorrow 1pm @work"
+#[derive(Debug)]
+pub struct QuickAddState {
+    pub input: String,
+    /// Calendars the event can be created on, only those the account can write to
+    pub calendars: Vec<Calendar>,
+    /// Why the last attempt to create the event failed
+    pub error: Option<String>,
+    /// The event has been sent to Google and the prompt is waiting to hear back
+    pub saving: bool,
+}
+
+impl QuickAddState {
+    pub fn new(calendars: Vec<Calendar>) -> Self {
+        Self {
+            input: String::new(),
+            calendars,
+            error: None,
+            saving: false,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::Utc;
+use color_eyre::eyre::Result;
+
+use crate::{
+    features::event_form::{
+        retrieve_calendars::retrieve_writable_calendars, update_event_form::run_insert_event_task,
+    },
+    tui::model::{CurrentState, Model},
+};
+
+use super::{parse_quick_add::parse_quick_add, quick_add_state::QuickAddState};
+
+pub async fn handle_open_quick_add(model: &mut Model) -> Result<()> {
+    let calendars = retrieve_writable_calendars(&model.application.db).await?;
+    model.current_state = CurrentState::QuickAdd(QuickAddState::new(calendars));
+    Ok(())
+}
+
+pub fn handle_char_message(model: &mut Model, c: char) {
+    if let CurrentState::QuickAdd(ref mut state) = model.current_state {
+        state.input.push(c);
+        state.error = None;
+    }
+}
+
+pub fn handle_backspace_message(model: &mut Model) {
+    if let CurrentState::QuickAdd(ref mut state) = model.current_state {
+        state.input.pop();
+        state.error = None;
+    }
+}
+
+/// Create the parsed event in the background, keeping the prompt open until
+/// Google replies
+pub fn handle_submit_message(model: &mut Model) {
+    let display_timezone = model.application.display_timezone;
+    let week_start = model.application.config.week_start;
+    let CurrentState::QuickAdd(ref mut state) = model.current_state else {
+        return;
+    };
+    if state.saving {
+        return;
+    }
+
+    let now = display_timezone.to_local(&Utc::now());
+    let parsed = parse_quick_add(&state.input, now, week_start).and_then(|quick_add| {
+        let calendar = quick_add.resolve_calendar(&state.calendars)?.to_owned();
+        Ok((quick_add, calendar))
+    });
+    let (quick_add, calendar) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            state.error = Some(e.to_string());
+            return;
+        }
+    };
+
+    state.error = None;
+    state.saving = true;
+    run_insert_event_task(quick_add.to_request(display_timezone), calendar, model);
+}
+
+pub fn handle_back_message(model: &mut Model) {
+    model.current_state = CurrentState::calendar(model.application.config.default_view);
+}
@@ -0,0 +1,72 @@
+use chrono::Utc;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::{
+    domain::{config::Config, theme::Theme, timezone::DisplayTimezone},
+    tui::util::centered_popup,
+};
+
+use super::{parse_quick_add::parse_quick_add, quick_add_state::QuickAddState};
+
+const POPUP_WIDTH: u16 = 64;
+const POPUP_HEIGHT: u16 = 6;
+
+pub fn render(
+    frame: &mut Frame,
+    state: &QuickAddState,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
+    let screen = frame.size();
+    let popup_area = centered_popup(
+        screen,
+        POPUP_WIDTH.min(screen.width.saturating_sub(4)),
+        POPUP_HEIGHT.min(screen.height.saturating_sub(2)),
+    );
+
+    let block = Block::bordered()
+        .title(" Quick add ")
+        .title_bottom(Line::from(" enter create · esc cancel ").right_aligned())
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.popup).fg(theme.text));
+
+    let input = Line::from(vec![
+        Span::styled("> ", theme.muted_style()),
+        Span::raw(state.input.to_owned()),
+        Span::raw("▏"),
+    ]);
+
+    // Preview what will be created as it is typed
+    let now = display_timezone.to_local(&Utc::now());
+    let preview = if state.saving {
+        Line::styled("Saving…", theme.muted_style().italic())
+    } else if let Some(error) = &state.error {
+        Line::styled(error.to_owned(), theme.error_style())
+    } else if state.input.trim().is_empty() {
+        Line::styled(
+            "e.g. Lunch with Sam tomorrow 1pm for 1h @work",
+            theme.muted_style().italic(),
+        )
+    } else {
+        match parse_quick_add(&state.input, now, config.week_start).and_then(|quick_add| {
+            let calendar = quick_add.resolve_calendar(&state.calendars)?;
+            Ok(format!(
+                "{} on {}",
+                quick_add.summary(config),
+                calendar.title
+            ))
+        }) {
+            Ok(summary) => Line::raw(summary),
+            Err(e) => Line::styled(e.to_string(), theme.muted_style()),
+        }
+    };
+
+    let prompt = Paragraph::new(vec![input, Line::default(), preview])
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(prompt, popup_area);
+}
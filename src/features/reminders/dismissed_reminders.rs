@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use chrono::DateTime;
+use color_eyre::eyre::Result;
+use eyre::Context;
+use sqlx::SqlitePool;
+
+use super::reminders_state::ReminderKey;
+
+pub async fn retrieve_dismissed_reminders(db: &SqlitePool) -> Result<HashSet<ReminderKey>> {
+    let rows = sqlx::query!("SELECT event_id, remind_at FROM dismissed_reminders")
+        .fetch_all(db)
+        .await
+        .wrap_err("Error while retrieving dismissed reminders")?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(ReminderKey {
+                event_id: row.event_id,
+                remind_at: DateTime::parse_from_rfc3339(&row.remind_at).ok()?.into(),
+            })
+        })
+        .collect())
+}
+
+pub async fn store_dismissed_reminder(key: &ReminderKey, db: &SqlitePool) -> Result<()> {
+    let remind_at = key.remind_at.to_rfc3339();
+
+    sqlx::query!(
+        "INSERT OR IGNORE INTO dismissed_reminders (event_id, remind_at) VALUES ($1, $2)",
+        key.event_id,
+        remind_at
+    )
+    .execute(db)
+    .await
+    .wrap_err("Error while storing dismissed reminder")?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{
+    events::{Event, ReminderMethod},
+    timezone::DisplayTimezone,
+};
+
+use super::reminders_state::ReminderKey;
+
+/// Popup reminders which have gone off for events which haven't finished yet,
+/// earliest first
+pub fn due_reminders(
+    events: &[Event],
+    now: DateTime<Utc>,
+    display_timezone: DisplayTimezone,
+) -> Vec<(ReminderKey, &Event)> {
+    let mut due = events
+        .iter()
+        .filter(|event| event.time.end_instant(display_timezone) > now)
+        .flat_map(|event| {
+            let start = event.time.start_instant(display_timezone);
+
+            event
+                .reminders
+                .iter()
+                .filter(|reminder| reminder.method == ReminderMethod::Popup)
+                .map(move |reminder| start - Duration::minutes(reminder.minutes))
+                .filter(|remind_at| *remind_at <= now)
+                .map(move |remind_at| {
+                    let key = ReminderKey {
+                        event_id: event.id.to_owned(),
+                        remind_at,
+                    };
+                    (key, event)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    due.sort_by_key(|(key, _)| key.remind_at);
+    due
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::due_reminders;
+    use crate::domain::{
+        events::{Event, EventTime, Reminder, ReminderMethod},
+        timezone::DisplayTimezone,
+    };
+
+    fn utc(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn event(id: &str, start: &str, reminders: Vec<Reminder>) -> Event {
+        let start = utc(start);
+        Event {
+            id: id.into(),
+            title: id.into(),
+            time: EventTime::Timed {
+                start,
+                end: start + Duration::hours(1),
+            },
+            reminders,
+            ..Event::test_default()
+        }
+    }
+
+    fn reminder(method: ReminderMethod, minutes: i64) -> Reminder {
+        Reminder { method, minutes }
+    }
+
+    #[test]
+    fn popup_reminders_are_due_until_the_event_ends() {
+        // Arrange
+        let events = vec![
+            event(
+                "standup",
+                "2024-06-03T10:00:00Z",
+                vec![
+                    reminder(ReminderMethod::Popup, 10),
+                    reminder(ReminderMethod::Popup, 30),
+                    reminder(ReminderMethod::Email, 60),
+                ],
+            ),
+            event(
+                "lunch",
+                "2024-06-03T12:00:00Z",
+                vec![reminder(ReminderMethod::Popup, 10)],
+            ),
+            event(
+                "finished",
+                "2024-06-03T08:00:00Z",
+                vec![reminder(ReminderMethod::Popup, 10)],
+            ),
+        ];
+
+        // Act
+        let due = due_reminders(
+            &events,
+            utc("2024-06-03T09:55:00Z"),
+            DisplayTimezone::from_name("UTC").unwrap(),
+        );
+
+        // Assert
+        let due = due
+            .iter()
+            .map(|(key, event)| (event.id.as_str(), key.remind_at))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            due,
+            vec![
+                ("standup", utc("2024-06-03T09:30:00Z")),
+                ("standup", utc("2024-06-03T09:50:00Z")),
+            ]
+        );
+    }
+}
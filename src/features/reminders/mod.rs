@@ -0,0 +1,5 @@
+pub(crate) mod dismissed_reminders;
+pub(crate) mod due_reminders;
+pub(crate) mod reminders_state;
+pub(crate) mod update_reminders;
+pub(crate) mod view;
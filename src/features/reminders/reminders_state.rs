@@ -0,0 +1,40 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::events::Event;
+
+pub const SHORT_SNOOZE_MINUTES: i64 = 5;
+pub const LONG_SNOOZE_MINUTES: i64 = 10;
+
+/// Identifies one reminder of one event, so it is only shown once
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReminderKey {
+    pub event_id: String,
+    pub remind_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub struct DueReminder {
+    pub key: ReminderKey,
+    pub event: Event,
+}
+
+/// Reminders of events while the calendar is open, kept whichever view is shown
+#[derive(Debug, Default)]
+pub struct RemindersState {
+    /// Reminders waiting to be dismissed or snoozed, the first is shown
+    pub showing: Vec<DueReminder>,
+    /// When snoozed reminders should be shown again
+    pub snoozed: HashMap<ReminderKey, DateTime<Utc>>,
+    /// Reminders which shouldn't be shown again, kept across restarts
+    pub dismissed: HashSet<ReminderKey>,
+    /// Whether dismissals from previous runs have been loaded
+    pub loaded_dismissed: bool,
+}
+
+impl RemindersState {
+    pub fn is_showing(&self) -> bool {
+        !self.showing.is_empty()
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::{stdout, Write};
+
+use chrono::{Duration, Utc};
+use color_eyre::eyre::Result;
+use tokio_util::sync::CancellationToken;
+
+use crate::tui::{
+    model::{EventsState, Message, Model},
+    MessageSender,
+};
+
+use super::{
+    dismissed_reminders::{retrieve_dismissed_reminders, store_dismissed_reminder},
+    due_reminders::due_reminders,
+    reminders_state::DueReminder,
+};
+
+/// How often to look for reminders which have gone off
+const CHECK_INTERVAL_SECONDS: u64 = 15;
+
+/// Look for reminders which have gone off while the calendar is open
+pub fn run_reminder_ticker(message_sender: MessageSender, cancellation_token: CancellationToken) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECONDS));
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    if message_sender.send(Message::CheckReminders).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Show reminders which have gone off, ringing the terminal bell for new ones
+pub async fn handle_check_reminders(model: &mut Model) -> Result<()> {
+    if !model.reminders.loaded_dismissed {
+        model.reminders.dismissed = retrieve_dismissed_reminders(&model.application.db).await?;
+        model.reminders.loaded_dismissed = true;
+    }
+
+    let EventsState::Ready(events) = &model.events_state else {
+        return Ok(());
+    };
+    let now = Utc::now();
+    let state = &mut model.reminders;
+    state.snoozed.retain(|_, until| *until > now);
+
+    let mut has_new = false;
+    for (key, event) in due_reminders(events, now, model.application.display_timezone) {
+        let is_known = state.dismissed.contains(&key)
+            || state.snoozed.contains_key(&key)
+            || state.showing.iter().any(|due| due.key == key);
+        if is_known {
+            continue;
+        }
+
+        state.showing.push(DueReminder {
+            key,
+            event: event.to_owned(),
+        });
+        has_new = true;
+    }
+
+    if has_new {
+        ring_bell();
+    }
+    Ok(())
+}
+
+/// Hide the shown reminder until the given number of minutes from now
+pub fn handle_snooze_message(model: &mut Model, minutes: i64) {
+    let state = &mut model.reminders;
+    if !state.is_showing() {
+        return;
+    }
+
+    let due = state.showing.remove(0);
+    state
+        .snoozed
+        .insert(due.key, Utc::now() + Duration::minutes(minutes));
+}
+
+/// Hide the shown reminder for good, including after a restart
+pub async fn handle_dismiss_message(model: &mut Model) -> Result<()> {
+    let state = &mut model.reminders;
+    if !state.is_showing() {
+        return Ok(());
+    }
+
+    let due = state.showing.remove(0);
+    store_dismissed_reminder(&due.key, &model.application.db).await?;
+    state.dismissed.insert(due.key);
+
+    Ok(())
+}
+
+fn ring_bell() {
+    let mut stdout = stdout();
+    if let Err(e) = stdout.write_all(b"\x07").and_then(|_| stdout.flush()) {
+        tracing::warn!("Failed to ring terminal bell: {:?}", e);
+    }
+}
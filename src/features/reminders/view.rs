@@ -0,0 +1,79 @@
+use chrono::Utc;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+use crate::domain::{config::Config, events::EventTime, theme::Theme, timezone::DisplayTimezone};
+use crate::tui::util::centered_popup;
+
+use super::reminders_state::{RemindersState, LONG_SNOOZE_MINUTES, SHORT_SNOOZE_MINUTES};
+
+const POPUP_WIDTH: u16 = 56;
+const POPUP_HEIGHT: u16 = 7;
+
+/// The earliest reminder waiting to be dismissed, over whatever is shown
+pub fn render(
+    frame: &mut Frame,
+    state: &RemindersState,
+    config: &Config,
+    theme: &Theme,
+    display_timezone: DisplayTimezone,
+) {
+    let Some(due) = state.showing.first() else {
+        return;
+    };
+    let event = &due.event;
+
+    let screen = frame.size();
+    let popup_area = centered_popup(
+        screen,
+        POPUP_WIDTH.min(screen.width.saturating_sub(4)),
+        POPUP_HEIGHT.min(screen.height.saturating_sub(2)),
+    );
+
+    let title = match state.showing.len() {
+        1 => " Reminder ".to_owned(),
+        count => format!(" Reminder (1 of {}) ", count),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .title_bottom(
+            Line::from(format!(
+                " s snooze {}m · S snooze {}m · enter dismiss ",
+                SHORT_SNOOZE_MINUTES, LONG_SNOOZE_MINUTES
+            ))
+            .right_aligned(),
+        )
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(theme.reminder).fg(theme.text));
+
+    let start = event.time.start_instant(display_timezone);
+    let minutes_until = (start - Utc::now()).num_minutes();
+    let when = match event.time {
+        EventTime::AllDay { start, .. } => {
+            format!("All day on {}", start.format(&config.short_date_format))
+        }
+        _ if minutes_until > 0 => format!(
+            "Starts at {}, in {} min",
+            display_timezone.format(&start, config.time_format()),
+            minutes_until
+        ),
+        _ => format!(
+            "Started at {}",
+            display_timezone.format(&start, config.time_format())
+        ),
+    };
+
+    let label_style = theme.muted_style();
+    let mut lines = vec![
+        Line::styled(event.title.to_owned(), Style::new().bold()),
+        Line::styled(when, label_style),
+    ];
+    if let Some(location) = &event.location {
+        lines.push(Line::styled(location.to_owned(), label_style));
+    }
+
+    let reminder = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(reminder, popup_area);
+}
@@ -0,0 +1,5 @@
+pub(crate) mod retrieve_calendars;
+pub(crate) mod rsvp_command;
+pub(crate) mod rsvp_request;
+pub(crate) mod rsvp_state;
+pub(crate) mod update_rsvp;
@@ -0,0 +1,74 @@
+use color_eyre::eyre::{eyre, Result};
+use eyre::Context;
+use reqwest::StatusCode;
+
+use crate::{
+    configuration::Application,
+    domain::events::{Event, ResponseStatus},
+    features::{
+        event_changes::patch_event::patch_event,
+        fetch_events::deserialise_event_response::deserialise_event_response,
+        oauth_http_client::GoogleOAuthClient,
+    },
+};
+
+use super::{
+    retrieve_calendars::{retrieve_calendars, Calendar},
+    rsvp_request::Rsvp,
+};
+
+/// Reply to an event from the command line, looking for it on every stored
+/// calendar
+pub async fn run_rsvp_command(application: &Application, event_id: &str, rsvp: Rsvp) -> Result<()> {
+    let google_client = &application.google_client;
+
+    let mut found_event = None;
+    for calendar in retrieve_calendars(&application.db).await? {
+        if let Some(event) = fetch_event(event_id, &calendar, google_client).await? {
+            found_event = Some(event);
+            break;
+        }
+    }
+    let event = found_event.ok_or(eyre!("No event found with id {}", event_id))?;
+
+    let request = rsvp.request(&event)?;
+    let event = patch_event(&request, &event, google_client).await?;
+
+    let response = match rsvp.response {
+        ResponseStatus::Accepted => "yes",
+        ResponseStatus::Declined => "no",
+        ResponseStatus::Tentative => "maybe",
+        ResponseStatus::NeedsAction | ResponseStatus::Unknown => "nothing",
+    };
+    println!("Replied {} to \"{}\"", response, event.title);
+
+    Ok(())
+}
+
+/// Fetch an event from a calendar, none if the calendar doesn't have it
+async fn fetch_event(
+    event_id: &str,
+    calendar: &Calendar,
+    google_client: &GoogleOAuthClient,
+) -> Result<Option<Event>> {
+    let event_url = format!(
+        "https://www.googleapis.com/calendar/v3/calendars/{}/events/{}",
+        urlencoding::encode(&calendar.calendar_id),
+        urlencoding::encode(event_id)
+    );
+
+    let http_client = reqwest::Client::new();
+    let response = google_client
+        .send(calendar.account_id, http_client.get(event_url))
+        .await?;
+    if matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::GONE) {
+        return Ok(None);
+    }
+    let response = response
+        .error_for_status()
+        .wrap_err("Failed to fetch event")?;
+
+    deserialise_event_response(response, &calendar.calendar_id, calendar.account_id)
+        .await
+        .map(Some)
+}
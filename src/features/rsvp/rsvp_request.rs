@@ -0,0 +1,170 @@
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{
+    domain::events::{Event, ResponseStatus},
+    features::event_form::event_request::AttendeeRequest,
+};
+
+/// A reply to an event we've been invited to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rsvp {
+    pub response: ResponseStatus,
+    /// Note to the organizer, none keeps any comment already sent
+    pub comment: Option<String>,
+}
+
+// Google replaces the whole attendee list when patching, so every attendee is
+// sent back as they were apart from us
+#[derive(Serialize, Debug, PartialEq)]
+pub struct RsvpRequest {
+    pub attendees: Vec<AttendeeRequest>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RsvpError {
+    #[error("You aren't invited to this event")]
+    NotInvited,
+}
+
+impl Rsvp {
+    pub fn request(&self, event: &Event) -> Result<RsvpRequest, RsvpError> {
+        let updated = self.apply_to(event)?;
+
+        Ok(RsvpRequest {
+            attendees: updated
+                .attendees
+                .iter()
+                .filter(|attendee| attendee.email.is_some())
+                .map(AttendeeRequest::from)
+                .collect(),
+        })
+    }
+
+    /// The event with our reply applied, used to show it before Google has
+    /// confirmed it
+    pub fn apply_to(&self, event: &Event) -> Result<Event, RsvpError> {
+        let mut updated = event.clone();
+        let attendee = updated
+            .attendees
+            .iter_mut()
+            .find(|attendee| attendee.is_self)
+            .ok_or(RsvpError::NotInvited)?;
+
+        attendee.response_status = self.response;
+        if let Some(comment) = &self.comment {
+            attendee.comment = (!comment.trim().is_empty()).then(|| comment.trim().to_owned());
+        }
+
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{Rsvp, RsvpError};
+    use crate::domain::events::{Attendee, Event, EventTime, ResponseStatus};
+
+    fn attendee(email: &str, is_self: bool, comment: Option<&str>) -> Attendee {
+        Attendee {
+            email: Some(email.into()),
+            display_name: None,
+            response_status: ResponseStatus::NeedsAction,
+            comment: comment.map(Into::into),
+            optional: false,
+            organizer: false,
+            resource: false,
+            additional_guests: 0,
+            is_self,
+        }
+    }
+
+    fn event(attendees: Vec<Attendee>) -> Event {
+        Event {
+            id: "review".into(),
+            title: "Design review".into(),
+            time: EventTime::AllDay {
+                start: chrono::NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(),
+                end: chrono::NaiveDate::from_ymd_opt(2024, 6, 4).unwrap(),
+            },
+            attendees,
+            calendar_id: "me@test.com".into(),
+            ..Event::test_default()
+        }
+    }
+
+    #[test]
+    fn only_our_reply_changes() {
+        // Arrange
+        let mut boss = attendee("boss@test.com", false, Some("Be there"));
+        boss.display_name = Some("Boss".into());
+        boss.organizer = true;
+        let mut room = attendee("room@resource.test.com", false, None);
+        room.resource = true;
+        room.response_status = ResponseStatus::Accepted;
+        let mut me = attendee("me@test.com", true, Some("Maybe late"));
+        me.additional_guests = 1;
+        let event = event(vec![boss, room, me]);
+        let rsvp = Rsvp {
+            response: ResponseStatus::Accepted,
+            comment: None,
+        };
+
+        // Act
+        let request = rsvp.request(&event).unwrap();
+
+        // Assert
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({
+                "attendees": [
+                    {
+                        "email": "boss@test.com",
+                        "responseStatus": "needsAction",
+                        "comment": "Be there",
+                        "displayName": "Boss",
+                        "organizer": true
+                    },
+                    { "email": "room@resource.test.com", "responseStatus": "accepted", "resource": true },
+                    {
+                        "email": "me@test.com",
+                        "responseStatus": "accepted",
+                        "comment": "Maybe late",
+                        "additionalGuests": 1,
+                        "self": true
+                    },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn blank_comments_clear_the_previous_comment() {
+        // Arrange
+        let event = event(vec![attendee("me@test.com", true, Some("Maybe late"))]);
+        let rsvp = Rsvp {
+            response: ResponseStatus::Declined,
+            comment: Some(" ".into()),
+        };
+
+        // Act
+        let updated = rsvp.apply_to(&event).unwrap();
+
+        // Assert
+        assert_eq!(updated.own_response(), Some(ResponseStatus::Declined));
+        assert_eq!(updated.attendees[0].comment, None);
+    }
+
+    #[test]
+    fn cannot_reply_to_events_we_are_not_invited_to() {
+        let event = event(vec![attendee("boss@test.com", false, None)]);
+        let rsvp = Rsvp {
+            response: ResponseStatus::Accepted,
+            comment: None,
+        };
+
+        assert_eq!(rsvp.request(&event), Err(RsvpError::NotInvited));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::domain::events::ResponseStatus;
+
+/// A reply being chosen for the event open in the details popup
+#[derive(Debug)]
+pub struct RsvpState {
+    pub response: ResponseStatus,
+    /// Note to the organizer sent with the reply
+    pub comment: String,
+}
+
+/// Replies in the order they are offered
+const RESPONSES: [ResponseStatus; 3] = [
+    ResponseStatus::Accepted,
+    ResponseStatus::Tentative,
+    ResponseStatus::Declined,
+];
+
+impl RsvpState {
+    /// Start from our current reply, suggesting yes if we haven't replied yet
+    pub fn new(response: ResponseStatus, comment: Option<String>) -> Self {
+        let response = match response {
+            ResponseStatus::NeedsAction | ResponseStatus::Unknown => ResponseStatus::Accepted,
+            response => response,
+        };
+
+        Self {
+            response,
+            comment: comment.unwrap_or_default(),
+        }
+    }
+
+    pub fn responses() -> &'static [ResponseStatus] {
+        &RESPONSES
+    }
+
+    pub fn cycle_response(&mut self, forwards: bool) {
+        let count = RESPONSES.len();
+        let index = RESPONSES
+            .iter()
+            .position(|response| *response == self.response)
+            .unwrap_or(0);
+
+        self.response = if forwards {
+            RESPONSES[(index + 1) % count]
+        } else {
+            RESPONSES[(index + count - 1) % count]
+        };
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    features::event_changes::update_event_changes::run_patch_event_task, tui::model::Model,
+};
+
+use super::{rsvp_request::Rsvp, rsvp_state::RsvpState};
+
+/// Ask how to reply to the event open in the details popup
+pub fn handle_rsvp_message(model: &mut Model) {
+    let Some(state) = model.current_state.day_summary_mut() else {
+        return;
+    };
+    let Some(details) = &mut state.event_details else {
+        return;
+    };
+
+    match details
+        .event
+        .attendees
+        .iter()
+        .find(|attendee| attendee.is_self)
+    {
+        Some(attendee) => {
+            details.rsvp = Some(RsvpState::new(
+                attendee.response_status,
+                attendee.comment.to_owned(),
+            ))
+        }
+        None => model.notification = Some("You aren't invited to this event".to_owned()),
+    }
+}
+
+/// The reply being chosen, if the prompt is open
+fn rsvp_state(model: &mut Model) -> Option<&mut RsvpState> {
+    model
+        .current_state
+        .day_summary_mut()?
+        .event_details
+        .as_mut()?
+        .rsvp
+        .as_mut()
+}
+
+pub fn handle_horizontal_message(model: &mut Model, forwards: bool) {
+    if let Some(rsvp) = rsvp_state(model) {
+        rsvp.cycle_response(forwards);
+    }
+}
+
+pub fn handle_char_message(model: &mut Model, c: char) {
+    if let Some(rsvp) = rsvp_state(model) {
+        rsvp.comment.push(c);
+    }
+}
+
+pub fn handle_backspace_message(model: &mut Model) {
+    if let Some(rsvp) = rsvp_state(model) {
+        rsvp.comment.pop();
+    }
+}
+
+/// Send the reply, showing it straight away and rolling it back if Google
+/// rejects it
+pub fn handle_submit_message(model: &mut Model) {
+    let Some(state) = model.current_state.day_summary_mut() else {
+        return;
+    };
+    let Some(details) = &mut state.event_details else {
+        return;
+    };
+    let Some(rsvp_state) = details.rsvp.take() else {
+        return;
+    };
+
+    let rsvp = Rsvp {
+        response: rsvp_state.response,
+        comment: Some(rsvp_state.comment),
+    };
+    let original = details.event.to_owned();
+    let (request, updated) = match rsvp.request(&original).and_then(|request| {
+        let updated = rsvp.apply_to(&original)?;
+        Ok((request, updated))
+    }) {
+        Ok(result) => result,
+        Err(e) => {
+            model.notification = Some(e.to_string());
+            return;
+        }
+    };
+
+    run_patch_event_task(request, updated, original, model);
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::Result;
+use eyre::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::features::oauth_http_client::GoogleOAuthClient;
+
+pub type BusyPeriod = (DateTime<Utc>, DateTime<Utc>);
+
+/// When someone is busy, as far as Google will tell us
+#[derive(Debug)]
+pub struct ParticipantAvailability {
+    /// Email address or calendar id
+    pub id: String,
+    pub busy: Vec<BusyPeriod>,
+    /// Why their availability couldn't be seen, e.g. their calendar isn't shared
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct FreeBusyRequest<'a> {
+    time_min: DateTime<Utc>,
+    time_max: DateTime<Utc>,
+    items: Vec<FreeBusyRequestItem<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+struct FreeBusyRequestItem<'a> {
+    id: &'a str,
+}
+
+#[derive(Deserialize, Debug)]
+struct FreeBusyResponse {
+    #[serde(default)]
+    calendars: HashMap<String, FreeBusyCalendarResource>,
+}
+
+#[derive(Deserialize, Debug)]
+struct FreeBusyCalendarResource {
+    #[serde(default)]
+    busy: Vec<BusyPeriodResource>,
+    #[serde(default)]
+    errors: Vec<FreeBusyErrorResource>,
+}
+
+#[derive(Deserialize, Debug)]
+struct BusyPeriodResource {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+struct FreeBusyErrorResource {
+    reason: String,
+}
+
+/// Ask Google when each participant is busy between the given times, through
+/// the account making the request. Participants are returned in the order given
+#[tracing::instrument(name = "Fetch free busy", skip(google_client))]
+pub async fn fetch_free_busy(
+    participants: &[String],
+    time_min: DateTime<Utc>,
+    time_max: DateTime<Utc>,
+    account_id: i64,
+    google_client: &GoogleOAuthClient,
+) -> Result<Vec<ParticipantAvailability>> {
+    let request = FreeBusyRequest {
+        time_min,
+        time_max,
+        items: participants
+            .iter()
+            .map(|id| FreeBusyRequestItem { id })
+            .collect(),
+    };
+
+    let http_client = reqwest::Client::new();
+    let free_busy_request = http_client
+        .post("https://www.googleapis.com/calendar/v3/freeBusy")
+        .json(&request);
+
+    let mut response = google_client
+        .send(account_id, free_busy_request)
+        .await?
+        .error_for_status()
+        .wrap_err("Google rejected the free busy query")?
+        .json::<FreeBusyResponse>()
+        .await
+        .wrap_err("Failed to parse free busy response")?;
+
+    Ok(participants
+        .iter()
+        .map(|id| {
+            let calendar = response.calendars.remove(id);
+            let error = match &calendar {
+                Some(calendar) => calendar.errors.first().map(|error| error.reason.to_owned()),
+                None => Some("notFound".to_owned()),
+            };
+
+            ParticipantAvailability {
+                id: id.to_owned(),
+                busy: calendar
+                    .map(|calendar| {
+                        calendar
+                            .busy
+                            .into_iter()
+                            .map(|period| (period.start, period.end))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                error,
+            }
+        })
+        .collect())
+}
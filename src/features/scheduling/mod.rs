@@ -0,0 +1,3 @@
+pub(crate) mod free_busy;
+pub(crate) mod rank_slots;
+pub(crate) mod schedule_command;
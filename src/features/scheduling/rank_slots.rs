@@ -0,0 +1,176 @@
+use chrono::{DateTime, Duration, DurationRound, Utc};
+
+use crate::domain::{timezone::DisplayTimezone, working_hours::WorkingHours};
+
+use super::free_busy::BusyPeriod;
+
+/// How far apart the start times offered are
+const CANDIDATE_STEP_MINUTES: i64 = 30;
+/// Gaps left either side of a meeting shorter than this are too short to be
+/// useful
+const MIN_USEFUL_GAP_MINUTES: i64 = 60;
+
+/// A time everyone is free for the meeting
+#[derive(Debug, PartialEq, Eq)]
+pub struct CandidateSlot {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Minutes of the meeting outside working hours
+    pub outside_working_hours: i64,
+    /// Short gaps the meeting would leave in participants' days
+    pub fragments: usize,
+}
+
+/// Every time within the window everyone is free for a meeting of the given
+/// length, best first. Slots in working hours come first, then those leaving
+/// the fewest awkward gaps in people's days, then the earliest
+pub fn rank_slots(
+    participants: &[Vec<BusyPeriod>],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    length: Duration,
+    working_hours: &WorkingHours,
+    display_timezone: DisplayTimezone,
+) -> Vec<CandidateSlot> {
+    let step = Duration::minutes(CANDIDATE_STEP_MINUTES);
+    let mut start = window_start.duration_trunc(step).unwrap_or(window_start);
+    if start < window_start {
+        start += step;
+    }
+
+    let mut candidates = vec![];
+    while start + length <= window_end {
+        let end = start + length;
+        let everyone_free = participants.iter().all(|busy| {
+            busy.iter()
+                .all(|(busy_start, busy_end)| *busy_end <= start || *busy_start >= end)
+        });
+
+        if everyone_free {
+            let day = display_timezone.to_local(&start).date();
+            let day_start = display_timezone.to_utc(&day.and_time(working_hours.start));
+            let day_end = display_timezone.to_utc(&day.and_time(working_hours.end));
+
+            let in_hours = if working_hours.is_working_day(day) {
+                (end.min(day_end) - start.max(day_start)).max(Duration::zero())
+            } else {
+                Duration::zero()
+            };
+
+            candidates.push(CandidateSlot {
+                start,
+                end,
+                outside_working_hours: (length - in_hours).num_minutes(),
+                fragments: participants
+                    .iter()
+                    .map(|busy| fragments(busy, start, end, day_start, day_end))
+                    .sum(),
+            });
+        }
+
+        start += step;
+    }
+
+    candidates.sort_by_key(|candidate| {
+        (
+            candidate.outside_working_hours,
+            candidate.fragments,
+            candidate.start,
+        )
+    });
+    candidates
+}
+
+/// How many too short gaps a meeting leaves either side of it in someone's
+/// working day
+fn fragments(
+    busy: &[BusyPeriod],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    day_start: DateTime<Utc>,
+    day_end: DateTime<Utc>,
+) -> usize {
+    let free_from = busy
+        .iter()
+        .map(|(_, busy_end)| *busy_end)
+        .filter(|busy_end| *busy_end <= start)
+        .fold(day_start, DateTime::max);
+    let free_until = busy
+        .iter()
+        .map(|(busy_start, _)| *busy_start)
+        .filter(|busy_start| *busy_start >= end)
+        .fold(day_end, DateTime::min);
+
+    [start - free_from, free_until - end]
+        .into_iter()
+        .filter(|gap| *gap > Duration::zero() && *gap < Duration::minutes(MIN_USEFUL_GAP_MINUTES))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::rank_slots;
+    use crate::domain::{timezone::DisplayTimezone, working_hours::WorkingHours};
+
+    fn utc(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn ranks_working_hours_then_fewest_gaps_then_earliest() {
+        // Arrange
+        let organizer = vec![(utc("2024-06-03T09:00:00Z"), utc("2024-06-03T10:00:00Z"))];
+        let attendee = vec![(utc("2024-06-03T11:00:00Z"), utc("2024-06-03T12:00:00Z"))];
+
+        // Act
+        let ranked = rank_slots(
+            &[organizer, attendee],
+            utc("2024-06-03T08:50:00Z"),
+            utc("2024-06-03T20:00:00Z"),
+            Duration::hours(1),
+            &WorkingHours::default(),
+            DisplayTimezone::from_name("UTC").unwrap(),
+        );
+
+        // Assert
+        let starts = ranked
+            .iter()
+            .map(|candidate| candidate.start.format("%R").to_string())
+            .collect::<Vec<_>>();
+        // Busy people and times which would overlap them aren't offered
+        assert!(!starts.contains(&"09:00".to_owned()));
+        assert!(!starts.contains(&"10:30".to_owned()));
+
+        assert_eq!(starts[..3], ["10:00", "12:00", "13:00"]);
+
+        let leaves_half_hour = ranked
+            .iter()
+            .find(|candidate| candidate.start == utc("2024-06-03T12:30:00Z"))
+            .unwrap();
+        assert_eq!(leaves_half_hour.fragments, 1);
+
+        let last = ranked.last().unwrap();
+        assert_eq!(last.start, utc("2024-06-03T19:00:00Z"));
+        assert_eq!(last.outside_working_hours, 60);
+    }
+
+    #[test]
+    fn nothing_is_offered_when_someone_is_always_busy() {
+        let busy = vec![(utc("2024-06-03T00:00:00Z"), utc("2024-06-04T00:00:00Z"))];
+
+        let ranked = rank_slots(
+            &[vec![], busy],
+            utc("2024-06-03T09:00:00Z"),
+            utc("2024-06-03T17:00:00Z"),
+            Duration::minutes(30),
+            &WorkingHours::default(),
+            DisplayTimezone::from_name("UTC").unwrap(),
+        );
+
+        assert_eq!(ranked, vec![]);
+    }
+}
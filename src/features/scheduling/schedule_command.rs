@@ -0,0 +1,149 @@
+use std::io::{stdin, stdout, Write};
+
+use chrono::{Days, Duration, NaiveDate, Utc};
+use color_eyre::eyre::{bail, eyre, Result};
+
+use crate::{
+    configuration::Application,
+    features::event_form::{
+        event_request::{AttendeeRequest, EventDateRequest, EventRequest},
+        insert_event::insert_event,
+        retrieve_calendars::retrieve_writable_calendars,
+    },
+};
+
+use super::{free_busy::fetch_free_busy, rank_slots::rank_slots};
+
+/// How many of the best slots are offered to pick from
+const SLOTS_OFFERED: usize = 5;
+
+pub struct MeetingRequest {
+    pub title: String,
+    pub attendees: Vec<String>,
+    pub length: Duration,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+/// Find times everyone is free for a meeting, ask which to use and invite
+/// everyone to it
+pub async fn run_schedule_command(
+    application: &Application,
+    meeting: MeetingRequest,
+) -> Result<()> {
+    let display_timezone = application.display_timezone;
+    let date_time_format = application.config.date_time_format();
+    let google_client = &application.google_client;
+
+    let first_day = meeting.from.unwrap_or(display_timezone.today());
+    let last_day = meeting.to.unwrap_or(first_day + Days::new(4));
+    if last_day < first_day {
+        bail!("The last day must not be before the first day");
+    }
+    let window_start = display_timezone.start_of_day(first_day).max(Utc::now());
+    let window_end = display_timezone.start_of_day(last_day + Days::new(1));
+
+    // The meeting is organised from the primary calendar of the first account
+    let calendars = retrieve_writable_calendars(&application.db).await?;
+    let organizer = calendars
+        .first()
+        .ok_or(eyre!("There are no calendars the meeting can be added to"))?;
+
+    let participants = std::iter::once(organizer.calendar_id.to_owned())
+        .chain(meeting.attendees.iter().cloned())
+        .collect::<Vec<_>>();
+    let availability = fetch_free_busy(
+        &participants,
+        window_start,
+        window_end,
+        organizer.account_id,
+        google_client,
+    )
+    .await?;
+
+    for participant in availability.iter() {
+        if let Some(error) = &participant.error {
+            println!(
+                "Couldn't see when {} is busy ({}), assuming they're free",
+                participant.id, error
+            );
+        }
+    }
+
+    let busy = availability
+        .into_iter()
+        .map(|participant| participant.busy)
+        .collect::<Vec<_>>();
+    let slots = rank_slots(
+        &busy,
+        window_start,
+        window_end,
+        meeting.length,
+        &application.config.working_hours,
+        display_timezone,
+    );
+    if slots.is_empty() {
+        bail!("There's no time everyone is free between those days");
+    }
+
+    let offered = &slots[..slots.len().min(SLOTS_OFFERED)];
+    for (index, slot) in offered.iter().enumerate() {
+        let note = if slot.outside_working_hours > 0 {
+            " (outside working hours)"
+        } else {
+            ""
+        };
+        println!(
+            "{}. {}–{}{}",
+            index + 1,
+            display_timezone.format(&slot.start, &date_time_format),
+            display_timezone.format(&slot.end, application.config.time_format()),
+            note
+        );
+    }
+
+    print!("Invite everyone to which time? [1-{}] ", offered.len());
+    stdout().flush()?;
+    let mut choice = String::new();
+    stdin().read_line(&mut choice)?;
+    let Some(slot) = choice
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|choice| offered.get(choice.checked_sub(1)?))
+    else {
+        println!("No meeting was created");
+        return Ok(());
+    };
+
+    let request = EventRequest {
+        summary: meeting.title,
+        location: None,
+        description: None,
+        start: EventDateRequest::date_time(slot.start),
+        end: EventDateRequest::date_time(slot.end),
+        attendees: Some(
+            meeting
+                .attendees
+                .iter()
+                .map(|email| AttendeeRequest::invite(email))
+                .collect(),
+        ),
+    };
+    let event = insert_event(
+        &request,
+        &organizer.calendar_id,
+        organizer.account_id,
+        google_client,
+    )
+    .await?;
+
+    println!(
+        "Invited {} to \"{}\" on {}",
+        meeting.attendees.join(", "),
+        event.title,
+        display_timezone.format(&slot.start, &date_time_format)
+    );
+
+    Ok(())
+}
@@ -1,13 +1,99 @@
-use color_eyre::eyre::Result;
+use args::{AccountSubcommand, Entity, EventSubcommand};
+use color_eyre::eyre::{bail, Result};
 use configuration::Application;
+use features::{
+    daemon::daemon_command::{run_daemon_command, Hooks},
+    free_slots::free_command::run_free_command,
+    manage_connections::retrieve_accounts::retrieve_accounts,
+    quick_add::quick_add_command::run_quick_add_command,
+    rsvp::{rsvp_command::run_rsvp_command, rsvp_request::Rsvp},
+    scheduling::schedule_command::{run_schedule_command, MeetingRequest},
+};
 
-mod args;
+pub mod args;
 pub mod configuration;
 pub mod domain;
 pub mod features;
 pub mod tui;
 pub mod util;
 
-pub async fn run(application: Application) -> Result<()> {
-    tui::run_tui(application).await
+/// Run a command, or open the calendar if there isn't one
+pub async fn run(application: Application, command: Option<Entity>) -> Result<()> {
+    match command {
+        None => tui::run_tui(application).await,
+
+        Some(Entity::Account(account)) => match account.command {
+            AccountSubcommand::List => {
+                for account in retrieve_accounts(&application.db).await? {
+                    println!("{}", account.email);
+                }
+                Ok(())
+            }
+            AccountSubcommand::New => {
+                bail!("Accounts can only be linked from the calendar, press `a` to manage them")
+            }
+        },
+
+        Some(Entity::Event(event)) => match event.command {
+            EventSubcommand::Rsvp {
+                id,
+                response,
+                comment,
+            } => {
+                let rsvp = Rsvp {
+                    response: response.into(),
+                    comment,
+                };
+                run_rsvp_command(&application, &id, rsvp).await
+            }
+        },
+
+        Some(Entity::Add { text, dry_run }) => {
+            run_quick_add_command(&application, &text.join(" "), dry_run).await
+        }
+
+        Some(Entity::Free {
+            from,
+            to,
+            min,
+            json,
+        }) => run_free_command(&application, from, to, min, json).await,
+
+        Some(Entity::Schedule {
+            attendees,
+            title,
+            minutes,
+            from,
+            to,
+        }) => {
+            let meeting = MeetingRequest {
+                title,
+                attendees,
+                length: chrono::Duration::minutes(minutes),
+                from,
+                to,
+            };
+            run_schedule_command(&application, meeting).await
+        }
+
+        Some(Entity::Daemon {
+            before_commands,
+            minutes_before,
+            start_commands,
+            end_commands,
+            sync_minutes,
+        }) => {
+            let hooks = Hooks {
+                before: before_commands,
+                minutes_before,
+                start: start_commands,
+                end: end_commands,
+            };
+            let sync_interval = match sync_minutes {
+                Some(minutes) => std::time::Duration::from_secs(minutes * 60),
+                None => application.config.refresh_interval(),
+            };
+            run_daemon_command(&application, hooks, sync_interval).await
+        }
+    }
 }
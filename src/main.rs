@@ -1,7 +1,8 @@
 use std::panic;
 
+use clap::Parser;
 use color_eyre::eyre::Result;
-use socal::{configuration::Application, run, tui::restore_terminal};
+use socal::{args::SoCalArgs, configuration::Application, run, tui::restore_terminal};
 use tracing::initialise_tracing;
 
 mod tracing;
@@ -28,15 +29,15 @@ fn install_panic_hook() -> Result<()> {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args = SoCalArgs::parse();
+
     install_panic_hook()?;
 
     initialise_tracing()?;
 
     let application = Application::setup().await?;
 
-    run(application).await?;
-
-    restore_terminal()?;
+    run(application, args.entity).await?;
 
     Ok(())
 }
@@ -1,63 +1,122 @@
-use std::time::Duration;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
 
-use crossterm::event::{self, Event, KeyCode};
-use tokio_util::sync::CancellationToken;
-
-use super::{
-    model::{Message, Model},
-    MessageSender,
+use crate::{
+    domain::{events::ResponseStatus, keymap::Action},
+    features::reminders::reminders_state::{LONG_SNOOZE_MINUTES, SHORT_SNOOZE_MINUTES},
 };
 
-pub fn handle_event(
-    _model: &Model,
-    message_sender: MessageSender,
-    cancellation_token: CancellationToken,
-) -> tokio::task::JoinHandle<()> {
-    tokio::spawn(async move {
-        let tick_rate = Duration::from_millis(5);
-
-        loop {
-            if cancellation_token.is_cancelled() {
-                break;
-            }
-
-            let message: Option<Message> = match event::poll(tick_rate) {
-                Ok(true) => match event::read() {
-                    Ok(Event::Key(key)) if key.kind == event::KeyEventKind::Press => {
-                        handle_key(key)
-                    }
-                    Ok(_) => None,
-                    Err(_) => None,
-                },
-                Ok(false) => None,
-                Err(_) => None,
-            };
-
-            if message.is_none() {
-                continue;
-            }
-
-            if let Err(_) = message_sender.send(message.unwrap()) {
-                // TODO: nicer handling here
-                break;
-            }
+use super::model::Message;
+
+/// The message for something happening in the terminal, if it matters
+pub fn input_message(event: Event) -> Option<Message> {
+    match event {
+        // Raw mode stops ctrl-c sending a signal, so it's picked out here to
+        // quit from anywhere, forms included
+        Event::Key(key)
+            if key.kind == KeyEventKind::Press
+                && key.code == KeyCode::Char('c')
+                && key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            Some(Message::Quit)
         }
-    })
+        // Key presses are mapped to messages by the update, which knows what
+        // is being shown
+        Event::Key(key) if key.kind == KeyEventKind::Press => Some(Message::Key(key)),
+        Event::Resize(columns, rows) => Some(Message::Resize(columns, rows)),
+        _ => None,
+    }
+}
+
+/// The message sent when keys bound to an action are pressed
+pub fn action_message(action: Action) -> Message {
+    match action {
+        Action::Quit => Message::Quit,
+        Action::Back => Message::Back,
+
+        Action::Up => Message::Up,
+        Action::Down => Message::Down,
+        Action::Left => Message::Left,
+        Action::Right => Message::Right,
+        Action::Enter => Message::Enter,
+        Action::First => Message::First,
+        Action::Last => Message::Last,
+        Action::NextView => Message::NextView,
+        Action::PreviousView => Message::PreviousView,
+
+        Action::New => Message::New,
+        Action::QuickAdd => Message::QuickAdd,
+        Action::Edit => Message::Edit,
+        Action::Delete => Message::Delete,
+        Action::Rsvp => Message::Rsvp,
+        Action::Invitations => Message::Invitations,
+        Action::FreeSlots => Message::FreeSlots,
+        Action::ReplyYes => Message::Reply(ResponseStatus::Accepted),
+        Action::ReplyMaybe => Message::Reply(ResponseStatus::Tentative),
+        Action::ReplyNo => Message::Reply(ResponseStatus::Declined),
+
+        Action::Accounts => Message::ManageAccounts,
+        Action::CycleColour => Message::CycleColour,
+        Action::ToggleTentative => Message::ToggleTentative,
+        Action::Snooze => Message::Snooze(SHORT_SNOOZE_MINUTES),
+        Action::SnoozeLong => Message::Snooze(LONG_SNOOZE_MINUTES),
+        Action::ToggleTheme => Message::ToggleTheme,
+        Action::Help => Message::Help,
+    }
 }
 
-#[tracing::instrument(name = "handle_key_event")]
-pub fn handle_key(key: event::KeyEvent) -> Option<Message> {
+/// Keys while typing into a form, characters are passed through as text
+#[tracing::instrument(name = "handle_text_input_key_event")]
+pub fn handle_text_input_key(key: event::KeyEvent) -> Option<Message> {
     match key.code {
-        KeyCode::Char('q') => Some(Message::Quit),
         KeyCode::Esc => Some(Message::Back),
-
-        KeyCode::Down => Some(Message::Down),
-        KeyCode::Up => Some(Message::Up),
         KeyCode::Enter => Some(Message::Enter),
 
-        KeyCode::Char('n') => Some(Message::New),
+        KeyCode::Tab | KeyCode::Down => Some(Message::NextField),
+        KeyCode::BackTab | KeyCode::Up => Some(Message::PreviousField),
+        KeyCode::Left => Some(Message::Left),
+        KeyCode::Right => Some(Message::Right),
 
-        KeyCode::Char('a') => Some(Message::ManageAccounts),
+        KeyCode::Backspace => Some(Message::Backspace),
+        KeyCode::Char(c) => Some(Message::Char(c)),
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+    use super::input_message;
+    use crate::tui::model::Message;
+
+    #[test]
+    fn maps_key_presses_and_resizes_ignoring_the_rest() {
+        let press = KeyEvent::from(KeyCode::Char('j'));
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        );
+
+        assert!(matches!(
+            input_message(Event::Key(press)),
+            Some(Message::Key(key)) if key == press
+        ));
+        assert!(input_message(Event::Key(release)).is_none());
+        assert!(matches!(
+            input_message(Event::Resize(80, 24)),
+            Some(Message::Resize(80, 24))
+        ));
+        assert!(input_message(Event::FocusGained).is_none());
+    }
+
+    #[test]
+    fn ctrl_c_quits() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        assert!(matches!(
+            input_message(Event::Key(ctrl_c)),
+            Some(Message::Quit)
+        ));
+    }
+}
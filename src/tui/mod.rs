@@ -1,28 +1,39 @@
 use std::io::stdout;
 
-use chrono::{DateTime, Duration, Local, NaiveTime, Utc};
 use color_eyre::eyre::Result;
 use crossterm::{
+    event::EventStream,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
+use futures::StreamExt;
 use ratatui::{
     backend::{Backend, CrosstermBackend},
     Terminal,
 };
-use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::{
+    sync::mpsc::{self, UnboundedReceiver},
+    time::MissedTickBehavior,
+};
 use tokio_util::sync::CancellationToken;
 
 use crate::{
     configuration::Application,
-    domain::events::{DayEvent, Event},
-    features::fetch_events::fetch_events::run_fetch_events_task,
+    domain::config::DefaultView,
+    features::{
+        fetch_events::fetch_events::{run_fetch_events_task, run_refresh_ticker},
+        month_view::month_view_state::MonthViewState,
+        reminders::update_reminders::run_reminder_ticker,
+    },
 };
 
 use self::model::{CurrentState, Message, Model};
 use self::update::update;
 use self::view::view;
-use self::{handle_event::handle_event, model::EventsState};
+use self::{
+    handle_event::input_message,
+    model::{EventFilters, EventsState},
+};
 
 pub(crate) mod handle_event;
 pub mod model;
@@ -32,6 +43,10 @@ pub(crate) mod view;
 
 pub type MessageSender = mpsc::UnboundedSender<Message>;
 
+/// How often the screen is drawn again with nothing else happening, so times
+/// and countdowns keep up with the clock
+const REDRAW_INTERVAL_SECONDS: u64 = 1;
+
 pub fn init_terminal() -> Result<Terminal<impl Backend>> {
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
@@ -50,88 +65,85 @@ async fn main_loop(
     model: &mut Model,
     message_receiver: &mut UnboundedReceiver<Message>,
 ) -> Result<()> {
-    terminal.draw(|frame| view(&model, frame))?;
+    let mut input = EventStream::new();
+    let mut redraw_interval =
+        tokio::time::interval(std::time::Duration::from_secs(REDRAW_INTERVAL_SECONDS));
+    redraw_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-    let mut current_msg = message_receiver.recv().await;
+    terminal.draw(|frame| view(model, frame))?;
 
-    while current_msg.is_some() {
-        current_msg = update(model, current_msg.unwrap()).await?;
-    }
+    loop {
+        // Sleep until there's input, a message from a background task or a
+        // redraw, whichever comes first
+        let message = tokio::select! {
+            Some(message) = message_receiver.recv() => message,
+            event = input.next() => match event {
+                Some(event) => match input_message(event?) {
+                    Some(message) => message,
+                    None => continue,
+                },
+                None => return Ok(()),
+            },
+            _ = redraw_interval.tick() => Message::Redraw,
+        };
 
-    Ok(())
+        // Draw after every message, including those update leads on to, so
+        // changes arriving in the background show straight away
+        let mut current_msg = Some(message);
+        while let Some(message) = current_msg {
+            current_msg = update(model, message).await?;
+
+            if matches!(model.current_state, CurrentState::Done) {
+                return Ok(());
+            }
+            terminal.draw(|frame| view(model, frame))?;
+        }
+    }
 }
 
 pub async fn run_tui(application: Application) -> Result<()> {
     let (message_sender, mut message_receiver) = mpsc::unbounded_channel();
 
-    let lunch_start = DateTime::parse_from_rfc3339("2024-06-03T12:00:00Z")
-        .unwrap()
-        .with_timezone(&Utc);
-    let lunch_end = DateTime::parse_from_rfc3339("2024-06-03T13:30:00Z")
-        .unwrap()
-        .with_timezone(&Utc);
-    let meeting_start_time = DateTime::parse_from_rfc3339("2024-06-03T16:30:00Z")
-        .unwrap()
-        .with_timezone(&Utc);
-    let meeting_end_time = DateTime::parse_from_rfc3339("2024-06-03T17:30:00Z")
-        .unwrap()
-        .with_timezone(&Utc);
-
-    let _events = vec![Event {
-        id: "blah".to_string(),
-        title: "Lunch".to_string(),
-        description: None,
-        start_time: lunch_start,
-        end_time: lunch_end,
-    },
-    Event {
-        id: "sttez".to_string(),
-        title: "Important meeting".to_string(),
-        description: Some("Very important meeting with lots of important people. Very important things will be discussed. It is very important a conclusion is reached".to_string()),
-        start_time: meeting_start_time,
-        end_time: meeting_end_time,
-    }];
-    let _day_events = vec![DayEvent {
-        id: "scwhag".to_string(),
-        title: "Birthday".to_string(),
-        description: Some("Wish happy birthday".to_string()),
-        date: Local::now().naive_local().date(),
-    }];
-
     let mut terminal = init_terminal()?;
+    let theme = application.config.initial_theme();
     let mut model = Model {
         application,
-        current_state: CurrentState::MonthView,
+        current_state: CurrentState::MonthView(MonthViewState::default()),
         message_channel: message_sender.clone(),
         events_state: EventsState::Loading,
+        event_filters: EventFilters::default(),
+        key_input: Default::default(),
+        notification: None,
+        reminders: Default::default(),
+        show_help: false,
+        theme,
+        screen: terminal.size()?,
     };
 
     let cancellation_token = CancellationToken::new();
-    let event_thread = handle_event(&model, message_sender.clone(), cancellation_token.clone());
-
-    let now = Local::now();
-    let today_midnight = now.with_time(NaiveTime::MIN).unwrap();
-    let tomorrow_midnight = today_midnight + Duration::days(2);
-    run_fetch_events_task(today_midnight, tomorrow_midnight, &model);
+    run_reminder_ticker(message_sender.clone(), cancellation_token.clone());
+    run_refresh_ticker(
+        model.application.config.refresh_interval(),
+        message_sender.clone(),
+        cancellation_token.clone(),
+    );
+
+    let (start, end) = model
+        .application
+        .config
+        .fetch_window(model.application.display_timezone);
+    run_fetch_events_task(start, end, &model);
+
+    if model.application.config.default_view == DefaultView::Days {
+        message_sender.send(Message::DaysView)?;
+    }
 
-    loop {
-        match main_loop(&mut terminal, &mut model, &mut message_receiver).await {
-            Ok(_) => {
-                if matches!(model.current_state, CurrentState::Done) {
-                    cancellation_token.cancel();
-                    event_thread.await?;
+    let result = main_loop(&mut terminal, &mut model, &mut message_receiver).await;
 
-                    restore_terminal()?;
+    cancellation_token.cancel();
 
-                    return Ok(());
-                }
-            }
-            Err(e) => {
-                cancellation_token.cancel();
-                event_thread.await?;
+    result?;
+    restore_terminal()?;
 
-                return Err(e);
-            }
-        };
-    }
+    Ok(())
 }
@@ -1,11 +1,23 @@
+use crossterm::event::KeyEvent;
+use ratatui::layout::Rect;
 use tokio_util::sync::CancellationToken;
 
 use crate::{
     configuration::Application,
-    domain::events::{DayEvent, Event},
+    domain::{
+        calendar::Calendar,
+        config::DefaultView,
+        events::{Event, ResponseStatus},
+        keymap::{KeyInput, KeyScope},
+        theme::Theme,
+    },
     features::{
-        days_view::days_view_state::DaysViewState,
+        day_summary::day_summary_state::DaySummaryState, days_view::days_view_state::DaysViewState,
+        event_form::event_form_state::EventFormState, free_slots::free_slots_state::FreeSlotsState,
+        invitations::invitations_state::InvitationsState,
         manage_connections::manage_connections_state::ManageConnectionsState,
+        month_view::month_view_state::MonthViewState, quick_add::quick_add_state::QuickAddState,
+        reminders::reminders_state::RemindersState,
     },
 };
 
@@ -17,12 +29,34 @@ pub struct Model {
     pub current_state: CurrentState,
     pub message_channel: MessageSender,
     pub events_state: EventsState,
+    pub event_filters: EventFilters,
+    pub key_input: KeyInput,
+    /// Error shown along the bottom of the screen until dismissed
+    pub notification: Option<String>,
+    pub reminders: RemindersState,
+    /// Whether the keys for the current view are listed over it
+    pub show_help: bool,
+    /// Colours everything is drawn in, starting with the config file's theme
+    pub theme: Theme,
+    /// Size of the terminal, so scrolling can stop at the end of what's drawn
+    pub screen: Rect,
+}
+
+/// Display filters applied to events across every view
+#[derive(Debug, Default)]
+pub struct EventFilters {
+    pub hide_tentative: bool,
 }
 
 #[derive(Debug)]
 pub enum CurrentState {
     DaysView(DaysViewState),
-    MonthView,
+    MonthView(MonthViewState),
+
+    EventForm(Box<EventFormState>),
+    QuickAdd(QuickAddState),
+    Invitations(InvitationsState),
+    FreeSlots(FreeSlotsState),
 
     ManageConnections(ManageConnectionsState),
     SignUpOptions(usize),
@@ -31,29 +65,133 @@ pub enum CurrentState {
     Done,
 }
 
+impl CurrentState {
+    /// Whether key presses should be typed into a text field
+    pub fn takes_text_input(&self) -> bool {
+        match self {
+            Self::EventForm(_) | Self::QuickAdd(_) => true,
+            _ => self
+                .day_summary()
+                .is_some_and(DaySummaryState::is_answering_rsvp),
+        }
+    }
+
+    /// The calendar view the app opens on, which forms go back to once closed
+    pub fn calendar(default_view: DefaultView) -> Self {
+        match default_view {
+            DefaultView::Month => Self::MonthView(Default::default()),
+            DefaultView::Days => Self::DaysView(Default::default()),
+        }
+    }
+
+    /// Today's events and the event opened from them, in the views listing them
+    pub fn day_summary(&self) -> Option<&DaySummaryState> {
+        match self {
+            Self::MonthView(state) => Some(&state.summary),
+            Self::DaysView(state) => Some(&state.summary),
+            _ => None,
+        }
+    }
+
+    pub fn day_summary_mut(&mut self) -> Option<&mut DaySummaryState> {
+        match self {
+            Self::MonthView(state) => Some(&mut state.summary),
+            Self::DaysView(state) => Some(&mut state.summary),
+            _ => None,
+        }
+    }
+
+    /// Which view's key bindings apply
+    pub fn key_scope(&self) -> KeyScope {
+        match self {
+            Self::DaysView(_) => KeyScope::Days,
+            Self::MonthView(_) => KeyScope::Month,
+            Self::Invitations(_) => KeyScope::Invitations,
+            Self::FreeSlots(_) => KeyScope::FreeSlots,
+            Self::ManageConnections(_) => KeyScope::Accounts,
+            Self::SignUpOptions(_) | Self::PendingLogin(_) => KeyScope::SignUp,
+            Self::EventForm(_) | Self::QuickAdd(_) | Self::Done => KeyScope::Global,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum EventsState {
-    Ready(Vec<Event>, Vec<DayEvent>),
+    Ready(Vec<Event>),
     Loading,
     Error(String),
 }
 
 #[derive(Debug)]
 pub enum Message {
+    /// A key press, mapped to a message through the key bindings or typed
+    /// into a text field
+    Key(KeyEvent),
+
     DaysView,
 
-    EventsReady(Vec<Event>, Vec<DayEvent>),
+    EventsReady(Vec<Event>),
     EventsError,
+    /// Fetch events again in the background
+    Refresh,
+    /// Nothing has changed, but the screen should be drawn again, e.g. so the
+    /// time shown moves on
+    Redraw,
+    /// The terminal is now this many columns and rows
+    Resize(u16, u16),
+    ToggleTentative,
+    /// Switch to the next theme
+    ToggleTheme,
+    CheckReminders,
+    /// Hide the shown reminder for the given number of minutes
+    Snooze(i64),
+
+    Help,
+
+    /// Open the prompt to describe a new event in a single line
+    QuickAdd,
+    Edit,
+    Delete,
+    Rsvp,
+    Invitations,
+    InvitationsReady(Vec<Event>),
+    InvitationsError,
+    FreeSlots,
+    FreeSlotsReady(Vec<Event>),
+    FreeSlotsError,
+    /// Reply to the highlighted invitation
+    Reply(ResponseStatus),
+    EventSaved(Box<Event>),
+    /// A change to an event was rejected, holds the event as it was before the
+    /// change and why it failed
+    EventChangeFailed(Box<Event>, String),
+    /// A new event was created on the given calendar
+    EventCreated(Box<Event>, Box<Calendar>),
+    /// A new event was rejected, holds why it failed
+    EventCreateFailed(String),
 
     ManageAccounts,
     LoginStarted(CancellationToken),
     LoginSuccess,
 
     ManageCalendars,
+    CycleColour,
 
     Down,
     Up,
+    Left,
+    Right,
+    /// Move to the top of the list
+    First,
+    /// Move to the bottom of the list
+    Last,
+    NextView,
+    PreviousView,
     Enter,
+    NextField,
+    PreviousField,
+    Char(char),
+    Backspace,
 
     New,
     Back,
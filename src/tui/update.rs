@@ -1,24 +1,135 @@
 use crate::features;
+use crate::features::reminders::reminders_state::{LONG_SNOOZE_MINUTES, SHORT_SNOOZE_MINUTES};
 use color_eyre::eyre::Result;
+use crossterm::event::KeyEvent;
+use ratatui::layout::Rect;
 
+use super::handle_event::{action_message, handle_text_input_key};
 use super::model::{CurrentState, EventsState, Message, Model};
 
 pub async fn update(model: &mut Model, msg: Message) -> Result<Option<Message>> {
+    // A reminder takes key presses until it is snoozed or dismissed, typing keys
+    // included
+    if model.reminders.is_showing() {
+        let snooze_minutes = match msg {
+            Message::Snooze(minutes) => Some(minutes),
+            Message::Char('s') => Some(SHORT_SNOOZE_MINUTES),
+            Message::Char('S') => Some(LONG_SNOOZE_MINUTES),
+            _ => None,
+        };
+        if let Some(minutes) = snooze_minutes {
+            features::reminders::update_reminders::handle_snooze_message(model, minutes);
+            return Ok(None);
+        }
+        if matches!(msg, Message::Enter | Message::Back) {
+            features::reminders::update_reminders::handle_dismiss_message(model).await?;
+            return Ok(None);
+        }
+    }
+
     // Handle any unique actions
     match msg {
+        Message::Key(key) => return handle_key(model, key).await,
+        // Drawing happens after every message
+        Message::Redraw => return Ok(None),
+        Message::Resize(columns, rows) => {
+            model.screen = Rect::new(0, 0, columns, rows);
+            return Ok(None);
+        }
+
         Message::Quit => graceful_shutdown(model),
 
-        Message::Back => return handle_back_navigation(model),
+        Message::Help => {
+            model.show_help = !model.show_help;
+            return Ok(None);
+        }
+        // Help stays open while using the keys it lists, until it's closed
+        Message::Back if model.show_help => {
+            model.show_help = false;
+            return Ok(None);
+        }
+        Message::Back => {
+            model.notification = None;
+            return handle_back_navigation(model);
+        }
+
+        Message::EventsReady(events) => {
+            model.events_state = EventsState::Ready(events);
+            return Ok(None);
+        }
+        Message::EventsError => {
+            // A failed refresh keeps showing the events already fetched
+            if matches!(model.events_state, EventsState::Ready(_)) {
+                model.notification = Some("Failed to refresh events".to_owned());
+            } else {
+                model.events_state = EventsState::Error("Failed to fetch events".to_owned());
+            }
+            return Ok(None);
+        }
 
-        Message::EventsReady(events, day_events) => {
-            model.events_state = EventsState::Ready(events, day_events);
+        Message::Refresh => {
+            let (start, end) = model
+                .application
+                .config
+                .fetch_window(model.application.display_timezone);
+            features::fetch_events::fetch_events::run_fetch_events_task(start, end, model);
             return Ok(None);
         }
 
-        Message::DaysView => {
-            features::days_view::handle_days_view_message::handle_load_days_view(model).await?
+        Message::EventSaved(event) => {
+            features::event_changes::update_event_changes::handle_event_saved(model, *event);
+            return Ok(None);
+        }
+        Message::EventChangeFailed(original, error) => {
+            features::event_changes::update_event_changes::handle_event_change_failed(
+                model, *original, error,
+            );
+            return Ok(None);
         }
 
+        Message::EventCreated(event, calendar) => {
+            features::event_form::update_event_form::handle_event_created(model, *event, &calendar);
+            return Ok(None);
+        }
+        Message::EventCreateFailed(error) => {
+            features::event_form::update_event_form::handle_event_create_failed(model, error);
+            return Ok(None);
+        }
+
+        Message::InvitationsReady(events) => {
+            features::invitations::update_invitations::handle_invitations_ready(model, events);
+            return Ok(None);
+        }
+        Message::InvitationsError => {
+            features::invitations::update_invitations::handle_invitations_error(model);
+            return Ok(None);
+        }
+        Message::FreeSlotsReady(events) => {
+            features::free_slots::update_free_slots::handle_free_slots_ready(model, events);
+            return Ok(None);
+        }
+        Message::FreeSlotsError => {
+            features::free_slots::update_free_slots::handle_free_slots_error(model);
+            return Ok(None);
+        }
+
+        Message::CheckReminders => {
+            features::reminders::update_reminders::handle_check_reminders(model).await?;
+            return Ok(None);
+        }
+
+        Message::ToggleTentative => {
+            model.event_filters.hide_tentative = !model.event_filters.hide_tentative;
+            return Ok(None);
+        }
+
+        Message::ToggleTheme => {
+            model.theme = model.application.config.theme_after(&model.theme.name);
+            return Ok(None);
+        }
+
+        Message::DaysView => model.current_state = CurrentState::DaysView(Default::default()),
+
         Message::ManageAccounts => {
             features::manage_connections::update_manage_connections::handle_manage_accounts(model)
                 .await?
@@ -29,17 +140,153 @@ pub async fn update(model: &mut Model, msg: Message) -> Result<Option<Message>>
         Message::LoginSuccess => return Ok(Some(Message::ManageAccounts)),
 
         Message::Up => match model.current_state {
+            CurrentState::Invitations(_) => {
+                features::invitations::update_invitations::handle_up_message(model)
+            }
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::day_summary::update_day_summary::handle_vertical_message(model, false)
+            }
             CurrentState::ManageConnections(_) => {
                 features::manage_connections::update_manage_connections::handle_up_message(model)
             }
             _ => {}
         },
         Message::Down => match model.current_state {
+            CurrentState::Invitations(_) => {
+                features::invitations::update_invitations::handle_down_message(model)
+            }
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::day_summary::update_day_summary::handle_vertical_message(model, true)
+            }
             CurrentState::ManageConnections(_) => {
                 features::manage_connections::update_manage_connections::handle_down_message(model)
             }
             _ => {}
         },
+        Message::Enter => match model.current_state {
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::day_summary::update_day_summary::handle_enter_message(model)
+            }
+            CurrentState::EventForm(_) => {
+                features::event_form::update_event_form::handle_submit_message(model)
+            }
+            CurrentState::QuickAdd(_) => {
+                features::quick_add::update_quick_add::handle_submit_message(model)
+            }
+            _ => {}
+        },
+        Message::Left => match model.current_state {
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::rsvp::update_rsvp::handle_horizontal_message(model, false)
+            }
+            CurrentState::EventForm(_) => {
+                features::event_form::update_event_form::handle_horizontal_message(model, false)
+            }
+            CurrentState::FreeSlots(_) => {
+                features::free_slots::update_free_slots::handle_horizontal_message(model, false)
+            }
+            CurrentState::ManageConnections(_) => {
+                features::manage_connections::update_manage_connections::handle_left_message(model)
+            }
+            _ => {}
+        },
+        Message::Right => match model.current_state {
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::rsvp::update_rsvp::handle_horizontal_message(model, true)
+            }
+            CurrentState::EventForm(_) => {
+                features::event_form::update_event_form::handle_horizontal_message(model, true)
+            }
+            CurrentState::FreeSlots(_) => {
+                features::free_slots::update_free_slots::handle_horizontal_message(model, true)
+            }
+            CurrentState::ManageConnections(_) => {
+                features::manage_connections::update_manage_connections::handle_right_message(model)
+            }
+            _ => {}
+        },
+        Message::First | Message::Last => {
+            let last = matches!(msg, Message::Last);
+            match model.current_state {
+                CurrentState::Invitations(_) => {
+                    features::invitations::update_invitations::handle_jump_message(model, last)
+                }
+                CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                    features::day_summary::update_day_summary::handle_jump_message(model, last)
+                }
+                CurrentState::ManageConnections(_) => {
+                    features::manage_connections::update_manage_connections::handle_jump_message(
+                        model, last,
+                    )
+                }
+                _ => {}
+            }
+        }
+        Message::NextView => return Ok(switch_view(model, true)),
+        Message::PreviousView => return Ok(switch_view(model, false)),
+        Message::QuickAdd => {
+            if let CurrentState::MonthView(_) | CurrentState::DaysView(_) = model.current_state {
+                features::quick_add::update_quick_add::handle_open_quick_add(model).await?
+            }
+        }
+        Message::Edit => {
+            if let CurrentState::MonthView(_) | CurrentState::DaysView(_) = model.current_state {
+                features::day_summary::update_day_summary::handle_edit_message(model).await?
+            }
+        }
+        Message::Delete => {
+            if let CurrentState::MonthView(_) | CurrentState::DaysView(_) = model.current_state {
+                features::day_summary::update_day_summary::handle_delete_message(model)
+            }
+        }
+        Message::Invitations => {
+            features::invitations::update_invitations::handle_open_invitations(model)
+        }
+        Message::FreeSlots => {
+            features::free_slots::update_free_slots::handle_open_free_slots(model)
+        }
+        Message::Reply(response) => {
+            if let CurrentState::Invitations(_) = model.current_state {
+                features::invitations::update_invitations::handle_reply_message(model, response)
+            }
+        }
+        Message::Rsvp => {
+            if let CurrentState::MonthView(_) | CurrentState::DaysView(_) = model.current_state {
+                features::rsvp::update_rsvp::handle_rsvp_message(model)
+            }
+        }
+        Message::NextField => {
+            features::event_form::update_event_form::handle_next_field_message(model)
+        }
+        Message::PreviousField => {
+            features::event_form::update_event_form::handle_previous_field_message(model)
+        }
+        Message::Char(c) => match model.current_state {
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::rsvp::update_rsvp::handle_char_message(model, c)
+            }
+            CurrentState::QuickAdd(_) => {
+                features::quick_add::update_quick_add::handle_char_message(model, c)
+            }
+            _ => features::event_form::update_event_form::handle_char_message(model, c),
+        },
+        Message::Backspace => match model.current_state {
+            CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+                features::rsvp::update_rsvp::handle_backspace_message(model)
+            }
+            CurrentState::QuickAdd(_) => {
+                features::quick_add::update_quick_add::handle_backspace_message(model)
+            }
+            _ => features::event_form::update_event_form::handle_backspace_message(model),
+        },
+        Message::CycleColour => {
+            if let CurrentState::ManageConnections(_) = model.current_state {
+                features::manage_connections::update_manage_connections::handle_cycle_colour_message(
+                    model,
+                )
+                .await?
+            }
+        }
 
         _ => {}
     };
@@ -49,6 +296,9 @@ pub async fn update(model: &mut Model, msg: Message) -> Result<Option<Message>>
         CurrentState::ManageConnections(_) if matches!(msg, Message::New) => {
             model.current_state = CurrentState::SignUpOptions(0)
         }
+        CurrentState::MonthView(_) | CurrentState::DaysView(_) if matches!(msg, Message::New) => {
+            features::event_form::update_event_form::handle_new_event(model).await?
+        }
         CurrentState::SignUpOptions(list_state) => {
             features::new_account::handle_event::handle_list_interaction(model, msg, list_state)?
         }
@@ -58,6 +308,53 @@ pub async fn update(model: &mut Model, msg: Message) -> Result<Option<Message>>
     Ok(None)
 }
 
+/// Type the key into the open form, or do whatever is bound to it once all of
+/// a binding's keys have been pressed
+async fn handle_key(model: &mut Model, key: KeyEvent) -> Result<Option<Message>> {
+    if model.current_state.takes_text_input() {
+        model.key_input.reset();
+        model.show_help = false;
+        return Ok(handle_text_input_key(key));
+    }
+
+    let bindings = model
+        .application
+        .config
+        .keymap
+        .bindings(model.current_state.key_scope());
+    let Some((action, count)) = model.key_input.press(key.into(), &bindings) else {
+        return Ok(None);
+    };
+
+    for _ in 0..count {
+        let mut next = Some(action_message(action));
+        while let Some(msg) = next {
+            next = Box::pin(update(model, msg)).await?;
+        }
+    }
+
+    Ok(None)
+}
+
+/// Move between the calendar, invitations and free time like tabs
+fn switch_view(model: &mut Model, forwards: bool) -> Option<Message> {
+    let next = match (&model.current_state, forwards) {
+        (CurrentState::MonthView(_), true) | (CurrentState::FreeSlots(_), false) => {
+            Message::Invitations
+        }
+        (CurrentState::Invitations(_), true) | (CurrentState::MonthView(_), false) => {
+            Message::FreeSlots
+        }
+        (CurrentState::FreeSlots(_), true) | (CurrentState::Invitations(_), false) => {
+            model.current_state = CurrentState::MonthView(Default::default());
+            return None;
+        }
+        _ => return None,
+    };
+
+    Some(next)
+}
+
 // Graceful shutdown of any remaining tasks
 fn graceful_shutdown(model: &mut Model) {
     match &model.current_state {
@@ -70,6 +367,21 @@ fn graceful_shutdown(model: &mut Model) {
 
 fn handle_back_navigation(model: &mut Model) -> Result<Option<Message>> {
     match &model.current_state {
+        CurrentState::MonthView(_) | CurrentState::DaysView(_) => {
+            features::day_summary::update_day_summary::handle_back_message(model)
+        }
+        CurrentState::EventForm(_) => {
+            features::event_form::update_event_form::handle_back_message(model)
+        }
+        CurrentState::QuickAdd(_) => {
+            features::quick_add::update_quick_add::handle_back_message(model)
+        }
+        CurrentState::Invitations(_) => {
+            features::invitations::update_invitations::handle_back_message(model)
+        }
+        CurrentState::FreeSlots(_) => {
+            features::free_slots::update_free_slots::handle_back_message(model)
+        }
         CurrentState::ManageConnections(_) => return Ok(Some(Message::DaysView)),
         CurrentState::SignUpOptions(_) => return Ok(Some(Message::ManageAccounts)),
         CurrentState::PendingLogin(cancellation_token) => {
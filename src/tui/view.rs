@@ -1,6 +1,9 @@
-use ratatui::Frame;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
 
+use crate::domain::theme::Theme;
 use crate::features;
+use crate::features::month_view::month_view_state::MonthViewState;
 
 use super::model::CurrentState;
 use super::model::Model;
@@ -9,18 +12,132 @@ pub fn view(model: &Model, frame: &mut Frame) {
     match &model.current_state {
         CurrentState::Done => {}
 
-        CurrentState::MonthView => features::month_view::view::render(frame, &model.events_state),
-        CurrentState::DaysView(_) => features::days_view::view::render(frame),
+        CurrentState::MonthView(page_state) => features::month_view::view::render(
+            frame,
+            page_state,
+            &model.events_state,
+            &model.event_filters,
+            &model.application.config,
+            &model.theme,
+            model.application.display_timezone,
+        ),
+        CurrentState::EventForm(page_state) => {
+            features::month_view::view::render(
+                frame,
+                &MonthViewState::default(),
+                &model.events_state,
+                &model.event_filters,
+                &model.application.config,
+                &model.theme,
+                model.application.display_timezone,
+            );
+            features::event_form::view::render(frame, page_state, &model.theme);
+        }
+        CurrentState::QuickAdd(page_state) => {
+            features::month_view::view::render(
+                frame,
+                &MonthViewState::default(),
+                &model.events_state,
+                &model.event_filters,
+                &model.application.config,
+                &model.theme,
+                model.application.display_timezone,
+            );
+            features::quick_add::view::render(
+                frame,
+                page_state,
+                &model.application.config,
+                &model.theme,
+                model.application.display_timezone,
+            );
+        }
+        CurrentState::Invitations(page_state) => features::invitations::view::render(
+            frame,
+            page_state,
+            &model.application.config,
+            &model.theme,
+            model.application.display_timezone,
+        ),
+        CurrentState::FreeSlots(page_state) => features::free_slots::view::render(
+            frame,
+            page_state,
+            &model.application.config,
+            &model.theme,
+            model.application.display_timezone,
+        ),
+        CurrentState::DaysView(page_state) => features::days_view::view::render(
+            frame,
+            page_state,
+            &model.events_state,
+            &model.event_filters,
+            &model.application.config,
+            &model.theme,
+            model.application.display_timezone,
+        ),
 
         CurrentState::ManageConnections(page_state) => {
-            features::manage_connections::manage_connections_view::render(page_state, frame)
+            features::manage_connections::manage_connections_view::render(
+                page_state,
+                &model.application.config.keymap,
+                &model.theme,
+                frame,
+            )
         }
 
         CurrentState::SignUpOptions(selected_index) => {
-            features::new_account::view::render_sign_in_options(frame, *selected_index)
+            features::new_account::view::render_sign_in_options(
+                frame,
+                *selected_index,
+                &model.theme,
+            )
         }
         CurrentState::PendingLogin(_) => {
             features::new_account::view::render_waiting_for_signin(frame)
         }
     };
+
+    if model.show_help && !model.current_state.takes_text_input() {
+        features::help::view::render(
+            frame,
+            &model.application.config.keymap,
+            model.current_state.key_scope(),
+            &model.theme,
+        );
+    }
+
+    features::reminders::view::render(
+        frame,
+        &model.reminders,
+        &model.application.config,
+        &model.theme,
+        model.application.display_timezone,
+    );
+
+    if let Some(notification) = &model.notification {
+        render_notification(frame, notification, &model.theme);
+    }
+}
+
+/// A line along the bottom of the screen over whatever is shown
+fn render_notification(frame: &mut Frame, notification: &str, theme: &Theme) {
+    let screen = frame.size();
+    let area = Rect {
+        y: screen.bottom().saturating_sub(1),
+        height: screen.height.min(1),
+        ..screen
+    };
+
+    let line = Line::from(vec![
+        Span::raw(format!(" {} ", notification)),
+        Span::styled("(esc to dismiss)", Style::new().italic()),
+    ]);
+    let style = if theme.monochrome {
+        Style::new().reversed().bold()
+    } else {
+        Style::new().bg(theme.error).fg(theme.popup)
+    };
+    let notification = Paragraph::new(line).style(style);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(notification, area);
 }
@@ -0,0 +1,85 @@
+use ratatui::style::Color;
+
+use crate::domain::colour::Colour;
+
+// Channel intensities of the xterm 6x6x6 colour cube (indexes 16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Map an arbitrary RGB colour onto the closest entry of the xterm 256 colour
+/// palette, which is supported by far more terminals than truecolor
+pub fn nearest_terminal_colour(colour: &Colour) -> Color {
+    let cube_index = |channel: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (i32::from(**level) - i32::from(channel)).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    };
+
+    let (r, g, b) = (
+        cube_index(colour.red),
+        cube_index(colour.green),
+        cube_index(colour.blue),
+    );
+    let cube_colour = Colour {
+        red: CUBE_LEVELS[r as usize],
+        green: CUBE_LEVELS[g as usize],
+        blue: CUBE_LEVELS[b as usize],
+    };
+    let cube_palette_index = 16 + 36 * r + 6 * g + b;
+
+    // Greyscale ramp (indexes 232-255) runs from 8 to 238 in steps of 10
+    let average = (u16::from(colour.red) + u16::from(colour.green) + u16::from(colour.blue)) / 3;
+    let grey_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey_level = 8 + 10 * grey_step;
+    let grey_colour = Colour {
+        red: grey_level,
+        green: grey_level,
+        blue: grey_level,
+    };
+    let grey_palette_index = 232 + grey_step;
+
+    if distance(colour, &grey_colour) < distance(colour, &cube_colour) {
+        Color::Indexed(grey_palette_index)
+    } else {
+        Color::Indexed(cube_palette_index)
+    }
+}
+
+fn distance(a: &Colour, b: &Colour) -> u32 {
+    let channel = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+
+    channel(a.red, b.red) + channel(a.green, b.green) + channel(a.blue, b.blue)
+}
+
+#[cfg(test)]
+mod test {
+    use ratatui::style::Color;
+
+    use super::nearest_terminal_colour;
+    use crate::domain::colour::Colour;
+
+    #[test]
+    fn maps_exact_cube_colours() {
+        let red = Colour::from_hex("#ff0000").unwrap();
+        let peacock = Colour::from_hex("#0087d7").unwrap();
+
+        assert_eq!(nearest_terminal_colour(&red), Color::Indexed(196));
+        assert_eq!(nearest_terminal_colour(&peacock), Color::Indexed(32));
+    }
+
+    #[test]
+    fn maps_greys_onto_greyscale_ramp() {
+        let graphite = Colour::from_hex("#616161").unwrap();
+
+        assert_eq!(nearest_terminal_colour(&graphite), Color::Indexed(241));
+    }
+
+    #[test]
+    fn maps_google_calendar_colours_to_nearest() {
+        let sage = Colour::from_hex("#33b679").unwrap();
+
+        assert_eq!(nearest_terminal_colour(&sage), Color::Indexed(72));
+    }
+}
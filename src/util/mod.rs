@@ -1 +1,2 @@
+pub(crate) mod colour_helpers;
 pub(crate) mod text_helpers;
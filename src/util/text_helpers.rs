@@ -6,3 +6,76 @@ pub fn truncate_text(text: &str, length: usize) -> String {
 
     format!("{}…", &text[..length - 1].trim())
 }
+
+/// Google stores descriptions as HTML, keep the text and its line breaks
+/// Example: "<p>Agenda:<br>1. Intro &amp; notes</p>" -> "Agenda:\n1. Intro & notes"
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        text.push_str(&rest[..tag_start]);
+
+        let Some(tag_end) = rest[tag_start..].find('>') else {
+            // A lone '<' is just text
+            text.push_str(&rest[tag_start..]);
+            rest = "";
+            break;
+        };
+
+        let tag = rest[tag_start + 1..tag_start + tag_end]
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        if matches!(tag.as_str(), "br" | "p" | "div" | "li" | "ul" | "ol" | "tr") {
+            text.push('\n');
+        }
+
+        rest = &rest[tag_start + tag_end + 1..];
+    }
+    text.push_str(rest);
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    // Collapse the blank lines left behind by nested block tags
+    text.lines()
+        .map(str::trim_end)
+        .fold(Vec::<&str>::new(), |mut lines, line| {
+            let previous_blank = lines.last().is_none_or(|last| last.is_empty());
+            if !(line.is_empty() && previous_blank) {
+                lines.push(line);
+            }
+            lines
+        })
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::strip_html;
+
+    #[test]
+    fn strips_tags_and_keeps_line_breaks() {
+        let description = "<p>Agenda:<br>1. Intro &amp; notes</p><p><b>Bring</b> laptops</p>";
+
+        assert_eq!(
+            strip_html(description),
+            "Agenda:\n1. Intro & notes\n\nBring laptops"
+        );
+    }
+
+    #[test]
+    fn leaves_plain_text_alone() {
+        assert_eq!(strip_html("Room 2 < Room 3"), "Room 2 < Room 3");
+    }
+}
@@ -0,0 +1,300 @@
+use chrono::Duration;
+use socal::{
+    domain::events::{Attendee, Event, EventTime, Person, ResponseStatus},
+    tui::{
+        model::{CurrentState, EventsState, Message, Model},
+        update::update,
+    },
+};
+use sqlx::SqlitePool;
+
+use crate::create_default_model;
+
+#[tokio::test]
+async fn can_open_and_close_selected_event_details() {
+    fn opened_event_id(model: &Model) -> Option<String> {
+        let CurrentState::MonthView(ref page_state) = model.current_state else {
+            panic!("model not in expected state")
+        };
+
+        page_state
+            .summary
+            .event_details
+            .as_ref()
+            .map(|details| details.event.id.clone())
+    }
+
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+
+    model.events_state = EventsState::Ready(vec![
+        create_event("afternoon", today_start + Duration::hours(14)),
+        create_event("morning", today_start + Duration::hours(9)),
+    ]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act/Assert
+    let _ = update(&mut model, Message::Down).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(opened_event_id(&model).as_deref(), Some("afternoon"));
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert_eq!(opened_event_id(&model), None);
+
+    let _ = update(&mut model, Message::Down).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(opened_event_id(&model).as_deref(), Some("morning"));
+}
+
+#[tokio::test]
+async fn can_select_and_open_events_in_the_days_view() {
+    fn opened_event_id(model: &Model) -> Option<String> {
+        let CurrentState::DaysView(ref page_state) = model.current_state else {
+            panic!("model not in expected state")
+        };
+
+        page_state
+            .summary
+            .event_details
+            .as_ref()
+            .map(|details| details.event.id.clone())
+    }
+
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+
+    model.events_state = EventsState::Ready(vec![
+        create_event("afternoon", today_start + Duration::hours(14)),
+        create_event("morning", today_start + Duration::hours(9)),
+        create_event("evening", today_start + Duration::hours(19)),
+    ]);
+    model.current_state = CurrentState::DaysView(Default::default());
+
+    // Act/Assert
+    let _ = update(&mut model, Message::Up).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(opened_event_id(&model).as_deref(), Some("evening"));
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert_eq!(opened_event_id(&model), None);
+
+    let _ = update(&mut model, Message::First).await.unwrap();
+    let _ = update(&mut model, Message::Down).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(opened_event_id(&model).as_deref(), Some("afternoon"));
+}
+
+#[tokio::test]
+async fn deleting_an_event_asks_for_confirmation_and_rolls_back_on_failure() {
+    fn event_ids(model: &Model) -> Vec<String> {
+        let EventsState::Ready(ref events) = model.events_state else {
+            panic!("events not loaded")
+        };
+
+        events.iter().map(|event| event.id.clone()).collect()
+    }
+
+    fn is_confirming(model: &Model) -> bool {
+        let CurrentState::MonthView(ref page_state) = model.current_state else {
+            panic!("model not in expected state")
+        };
+
+        page_state
+            .summary
+            .event_details
+            .as_ref()
+            .is_some_and(|details| details.confirm_delete)
+    }
+
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+    let event = create_event("standup", today_start + Duration::hours(9));
+
+    model.events_state = EventsState::Ready(vec![event.clone()]);
+    model.current_state = CurrentState::MonthView(Default::default());
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+
+    // Act/Assert
+    let _ = update(&mut model, Message::Delete).await.unwrap();
+    assert!(is_confirming(&model));
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(!is_confirming(&model));
+    assert_eq!(event_ids(&model), vec!["standup"]);
+
+    let _ = update(&mut model, Message::Delete).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert!(event_ids(&model).is_empty());
+
+    let _ = update(
+        &mut model,
+        Message::EventChangeFailed(Box::new(event), "Failed to delete event".into()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(event_ids(&model), vec!["standup"]);
+    assert_eq!(
+        model.notification.as_deref(),
+        Some("Failed to delete event")
+    );
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert_eq!(model.notification, None);
+}
+
+#[tokio::test]
+async fn edits_are_shown_before_they_are_saved() {
+    // Arrange
+    let mut model = create_default_model().await;
+    seed_writable_calendar("test@test.com", &model.application.db).await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+
+    model.events_state = EventsState::Ready(vec![create_event(
+        "standup",
+        today_start + Duration::hours(9),
+    )]);
+    model.current_state = CurrentState::MonthView(Default::default());
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+
+    // Act
+    let _ = update(&mut model, Message::Edit).await.unwrap();
+    for c in " moved".chars() {
+        let _ = update(&mut model, Message::Char(c)).await.unwrap();
+    }
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+
+    // Assert
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+    let EventsState::Ready(ref events) = model.events_state else {
+        panic!("events not loaded")
+    };
+    assert_eq!(events[0].title, "standup moved");
+    assert_eq!(
+        events[0].time.start_instant(display_timezone),
+        today_start + Duration::hours(9)
+    );
+}
+
+#[tokio::test]
+async fn events_organised_by_others_cannot_be_edited() {
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+    let mut event = create_event("standup", today_start + Duration::hours(9));
+    event.organizer = Some(Person {
+        email: Some("boss@test.com".into()),
+        display_name: None,
+        is_self: false,
+    });
+
+    model.events_state = EventsState::Ready(vec![event]);
+    model.current_state = CurrentState::MonthView(Default::default());
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+
+    // Act
+    let _ = update(&mut model, Message::Edit).await.unwrap();
+
+    // Assert
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+    assert_eq!(
+        model.notification.as_deref(),
+        Some("Only events you organise can be changed")
+    );
+}
+
+#[tokio::test]
+async fn replies_are_shown_before_they_are_sent() {
+    fn own_reply(model: &Model) -> (Option<ResponseStatus>, Option<String>) {
+        let EventsState::Ready(ref events) = model.events_state else {
+            panic!("events not loaded")
+        };
+        let me = events[0].attendees.iter().find(|attendee| attendee.is_self);
+
+        (
+            me.map(|attendee| attendee.response_status),
+            me.and_then(|attendee| attendee.comment.clone()),
+        )
+    }
+
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+    let mut event = create_event("offsite", today_start + Duration::hours(9));
+    event.attendees = vec![Attendee {
+        email: Some("me@test.com".into()),
+        display_name: None,
+        response_status: ResponseStatus::NeedsAction,
+        comment: None,
+        optional: false,
+        organizer: false,
+        resource: false,
+        additional_guests: 0,
+        is_self: true,
+    }];
+
+    model.events_state = EventsState::Ready(vec![event]);
+    model.current_state = CurrentState::MonthView(Default::default());
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+
+    // Act
+    let _ = update(&mut model, Message::Rsvp).await.unwrap();
+    assert!(model.current_state.takes_text_input());
+
+    let _ = update(&mut model, Message::Right).await.unwrap();
+    for c in "late".chars() {
+        let _ = update(&mut model, Message::Char(c)).await.unwrap();
+    }
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+
+    // Assert
+    assert!(!model.current_state.takes_text_input());
+    assert_eq!(
+        own_reply(&model),
+        (Some(ResponseStatus::Tentative), Some("late".into()))
+    );
+}
+
+async fn seed_writable_calendar(calendar_id: &str, db: &SqlitePool) {
+    let expires_at = chrono::Utc::now().to_rfc3339();
+
+    let account = sqlx::query!(
+        "INSERT INTO accounts (email, access_token, refresh_token, expires_at) VALUES ($1, 'blah', 'bloh', $2) RETURNING id",
+        calendar_id,
+        expires_at
+    )
+    .fetch_one(db)
+    .await
+    .expect("failed to seed account");
+
+    sqlx::query!(
+        "INSERT INTO calendars (calendar_id, account_id, title, primary_calendar, access_role) VALUES ($1, $2, 'Work', 1, 'owner')",
+        calendar_id,
+        account.id
+    )
+    .execute(db)
+    .await
+    .expect("failed to seed calendar");
+}
+
+fn create_event(id: &str, start: chrono::DateTime<chrono::Utc>) -> Event {
+    Event {
+        id: id.into(),
+        title: id.into(),
+        time: EventTime::Timed {
+            start,
+            end: start + Duration::hours(1),
+        },
+        etag: Some(format!("\"{}\"", id)),
+        description: Some("<p>Agenda</p>".into()),
+        ..Event::test_default()
+    }
+}
@@ -0,0 +1,18 @@
+use socal::tui::{model::Message, update::update};
+
+use crate::create_default_model;
+
+#[tokio::test]
+async fn can_toggle_hiding_tentative_events() {
+    // Arrange
+    let mut model = create_default_model().await;
+
+    // Act/Assert
+    assert!(!model.event_filters.hide_tentative);
+
+    let _ = update(&mut model, Message::ToggleTentative).await.unwrap();
+    assert!(model.event_filters.hide_tentative);
+
+    let _ = update(&mut model, Message::ToggleTentative).await.unwrap();
+    assert!(!model.event_filters.hide_tentative);
+}
@@ -0,0 +1,87 @@
+use socal::{
+    domain::config::DefaultView,
+    tui::{
+        model::{CurrentState, EventsState, Message, Model},
+        update::update,
+    },
+};
+
+use crate::create_default_model;
+
+fn form_error(model: &Model) -> Option<String> {
+    let CurrentState::EventForm(ref form) = model.current_state else {
+        panic!("model not in expected state")
+    };
+
+    form.error.clone()
+}
+
+#[tokio::test]
+async fn can_fill_in_and_cancel_new_event_form() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act
+    let _ = update(&mut model, Message::New).await.unwrap();
+    for c in "Lunch".chars() {
+        let _ = update(&mut model, Message::Char(c)).await.unwrap();
+    }
+    let _ = update(&mut model, Message::Backspace).await.unwrap();
+
+    // Assert
+    let CurrentState::EventForm(ref form) = model.current_state else {
+        panic!("model not in expected state")
+    };
+    assert_eq!(form.title, "Lunc");
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+}
+
+#[tokio::test]
+async fn events_can_be_added_from_the_days_view() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.application.config.default_view = DefaultView::Days;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::DaysView(Default::default());
+
+    // Act/Assert
+    let _ = update(&mut model, Message::New).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::EventForm(_)));
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::DaysView(_)));
+
+    let _ = update(&mut model, Message::QuickAdd).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::QuickAdd(_)));
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::DaysView(_)));
+}
+
+#[tokio::test]
+async fn invalid_forms_show_an_error_instead_of_saving() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+    let _ = update(&mut model, Message::New).await.unwrap();
+
+    // Act/Assert
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(
+        form_error(&model).as_deref(),
+        Some("The event needs a title")
+    );
+
+    let _ = update(&mut model, Message::Char('x')).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(
+        form_error(&model).as_deref(),
+        Some("There are no calendars the event can be added to")
+    );
+    assert!(matches!(&model.events_state, EventsState::Ready(events) if events.is_empty()));
+}
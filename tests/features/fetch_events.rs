@@ -0,0 +1,30 @@
+use socal::{
+    domain::events::Event,
+    tui::{
+        model::{EventsState, Message},
+        update::update,
+    },
+};
+
+use crate::create_default_model;
+
+#[tokio::test]
+async fn failed_fetches_show_an_error_but_keep_fetched_events() {
+    // Arrange
+    let mut model = create_default_model().await;
+
+    // Act/Assert
+    let _ = update(&mut model, Message::EventsError).await.unwrap();
+    assert!(matches!(model.events_state, EventsState::Error(_)));
+
+    let events = vec![Event::test_default()];
+    let _ = update(&mut model, Message::EventsReady(events))
+        .await
+        .unwrap();
+    let _ = update(&mut model, Message::EventsError).await.unwrap();
+    assert!(matches!(model.events_state, EventsState::Ready(ref events) if events.len() == 1));
+    assert_eq!(
+        model.notification.as_deref(),
+        Some("Failed to refresh events")
+    );
+}
@@ -0,0 +1,41 @@
+use socal::tui::{
+    model::{CurrentState, EventsState, Message, Model},
+    update::update,
+};
+
+use crate::create_default_model;
+
+fn panel_state(model: &Model) -> (bool, usize) {
+    let CurrentState::FreeSlots(ref state) = model.current_state else {
+        panic!("model not in expected state")
+    };
+
+    (matches!(state.events, EventsState::Ready(_)), state.length)
+}
+
+#[tokio::test]
+async fn can_open_free_time_and_change_the_shortest_slot() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act/Assert
+    let _ = update(&mut model, Message::FreeSlots).await.unwrap();
+    assert_eq!(panel_state(&model), (false, 1));
+
+    let _ = update(&mut model, Message::FreeSlotsReady(vec![]))
+        .await
+        .unwrap();
+    let _ = update(&mut model, Message::Right).await.unwrap();
+    let _ = update(&mut model, Message::Right).await.unwrap();
+    assert_eq!(panel_state(&model), (true, 3));
+
+    for _ in 0..5 {
+        let _ = update(&mut model, Message::Left).await.unwrap();
+    }
+    assert_eq!(panel_state(&model), (true, 0));
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+}
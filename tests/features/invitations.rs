@@ -0,0 +1,91 @@
+use chrono::{Duration, Utc};
+use socal::{
+    domain::events::{Attendee, Event, EventTime, ResponseStatus},
+    tui::{
+        model::{CurrentState, EventsState, Message, Model},
+        update::update,
+    },
+};
+
+use crate::create_default_model;
+
+#[tokio::test]
+async fn can_reply_to_invitations_inline() {
+    fn own_responses(model: &Model) -> Vec<(String, Option<ResponseStatus>)> {
+        let CurrentState::Invitations(ref state) = model.current_state else {
+            panic!("model not in expected state")
+        };
+        let EventsState::Ready(ref events) = state.events else {
+            panic!("invitations not loaded")
+        };
+
+        let mut responses = events
+            .iter()
+            .map(|event| (event.id.clone(), event.own_response()))
+            .collect::<Vec<_>>();
+        responses.sort_by(|a, b| a.0.cmp(&b.0));
+        responses
+    }
+
+    // Arrange
+    let mut model = create_default_model().await;
+    let now = Utc::now();
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act
+    let _ = update(&mut model, Message::Invitations).await.unwrap();
+    // Invitations are fetched over more days than the calendar
+    let _ = update(
+        &mut model,
+        Message::InvitationsReady(vec![
+            create_invitation("later", now + Duration::days(20)),
+            create_invitation("sooner", now + Duration::hours(1)),
+        ]),
+    )
+    .await
+    .unwrap();
+    let _ = update(&mut model, Message::Down).await.unwrap();
+    let _ = update(&mut model, Message::Reply(ResponseStatus::Declined))
+        .await
+        .unwrap();
+    let _ = update(&mut model, Message::Reply(ResponseStatus::Accepted))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        own_responses(&model),
+        vec![
+            ("later".into(), Some(ResponseStatus::Declined)),
+            ("sooner".into(), Some(ResponseStatus::Accepted)),
+        ]
+    );
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+}
+
+fn create_invitation(id: &str, start: chrono::DateTime<Utc>) -> Event {
+    Event {
+        id: id.into(),
+        title: id.into(),
+        time: EventTime::Timed {
+            start,
+            end: start + Duration::hours(1),
+        },
+        attendees: vec![Attendee {
+            email: Some("me@test.com".into()),
+            display_name: None,
+            response_status: ResponseStatus::NeedsAction,
+            comment: None,
+            optional: false,
+            organizer: false,
+            resource: false,
+            additional_guests: 0,
+            is_self: true,
+        }],
+        calendar_id: "me@test.com".into(),
+        ..Event::test_default()
+    }
+}
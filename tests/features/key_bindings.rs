@@ -0,0 +1,153 @@
+use chrono::{Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+use socal::{
+    domain::events::{Event, EventTime},
+    tui::{
+        model::{CurrentState, EventsState, Message, Model},
+        update::update,
+    },
+};
+
+use crate::create_default_model;
+
+/// Press each key, following on with any messages it leads to like the main loop
+async fn press_keys(model: &mut Model, keys: &str) {
+    for c in keys.chars() {
+        let mut next = Some(Message::Key(KeyEvent::from(KeyCode::Char(c))));
+        while let Some(msg) = next {
+            next = update(model, msg).await.unwrap();
+        }
+    }
+}
+
+fn selected_event(model: &Model) -> usize {
+    let CurrentState::MonthView(ref state) = model.current_state else {
+        panic!("model not in expected state")
+    };
+
+    state.summary.selected_event
+}
+
+#[tokio::test]
+async fn keys_move_with_counts_and_multi_key_bindings() {
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+
+    model.events_state = EventsState::Ready(
+        (0..4)
+            .map(|hour| create_event(&hour.to_string(), today_start + Duration::hours(9 + hour)))
+            .collect(),
+    );
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act/Assert
+    press_keys(&mut model, "2j").await;
+    assert_eq!(selected_event(&model), 2);
+
+    press_keys(&mut model, "G").await;
+    assert_eq!(selected_event(&model), 3);
+
+    press_keys(&mut model, "gg").await;
+    assert_eq!(selected_event(&model), 0);
+
+    press_keys(&mut model, "gt").await;
+    assert!(matches!(model.current_state, CurrentState::Invitations(_)));
+}
+
+#[tokio::test]
+async fn redrawing_between_keys_keeps_the_binding_being_typed() {
+    // Arrange
+    let mut model = create_default_model().await;
+    let display_timezone = model.application.display_timezone;
+    let today_start = display_timezone.start_of_day(display_timezone.today());
+
+    model.events_state = EventsState::Ready(
+        (0..3)
+            .map(|hour| create_event(&hour.to_string(), today_start + Duration::hours(9 + hour)))
+            .collect(),
+    );
+    model.current_state = CurrentState::MonthView(Default::default());
+    press_keys(&mut model, "G").await;
+
+    // Act
+    press_keys(&mut model, "g").await;
+    let next = update(&mut model, Message::Redraw).await.unwrap();
+    press_keys(&mut model, "g").await;
+
+    // Assert
+    assert!(next.is_none());
+    assert_eq!(selected_event(&model), 0);
+}
+
+#[tokio::test]
+async fn keys_are_typed_into_forms_rather_than_mapped() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act
+    press_keys(&mut model, "Nq 2j").await;
+
+    // Assert
+    let CurrentState::QuickAdd(ref state) = model.current_state else {
+        panic!("model not in expected state")
+    };
+    assert_eq!(state.input, "q 2j");
+}
+
+#[tokio::test]
+async fn help_opens_in_every_view_and_closes_without_going_back() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+
+    for state in [
+        CurrentState::MonthView(Default::default()),
+        CurrentState::SignUpOptions(0),
+    ] {
+        model.current_state = state;
+
+        // Act/Assert
+        press_keys(&mut model, "?").await;
+        assert!(model.show_help);
+
+        let _ = update(&mut model, Message::Back).await.unwrap();
+        assert!(!model.show_help);
+    }
+    assert!(matches!(
+        model.current_state,
+        CurrentState::SignUpOptions(_)
+    ));
+}
+
+#[tokio::test]
+async fn theme_switches_through_every_theme_and_back() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act/Assert
+    assert_eq!(model.theme.name, "light");
+
+    press_keys(&mut model, "T").await;
+    assert_eq!(model.theme.name, "dark");
+
+    press_keys(&mut model, "TT").await;
+    assert_eq!(model.theme.name, "light");
+}
+
+fn create_event(id: &str, start: chrono::DateTime<Utc>) -> Event {
+    Event {
+        id: id.into(),
+        title: id.into(),
+        time: EventTime::Timed {
+            start,
+            end: start + Duration::hours(1),
+        },
+        ..Event::test_default()
+    }
+}
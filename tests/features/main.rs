@@ -1,14 +1,25 @@
 use oauth2::{basic::BasicClient, AuthUrl, ClientId, ClientSecret, RedirectUrl, TokenUrl};
+use ratatui::layout::Rect;
 use std::{fs::create_dir_all, path::PathBuf};
 
 use socal::{
-    configuration::Application, features::oauth_http_client::GoogleOAuthClient, tui::model::Model,
+    configuration::Application, domain::timezone::DisplayTimezone,
+    features::oauth_http_client::GoogleOAuthClient, tui::model::Model,
 };
 use sqlx::{sqlite::SqliteConnectOptions, SqlitePool};
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
+mod event_details;
+mod event_filters;
+mod event_form;
+mod fetch_events;
+mod free_slots;
+mod invitations;
+mod key_bindings;
 mod manage_connections;
+mod quick_add;
+mod reminders;
 
 pub async fn create_default_model() -> Model {
     // random test id so tests can be isolated
@@ -32,6 +43,8 @@ pub async fn create_default_model() -> Model {
         db_path,
         oauth_client,
         google_client,
+        display_timezone: DisplayTimezone::Local,
+        config: Default::default(),
     };
 
     let (message_sender, _message_receiver) = mpsc::unbounded_channel();
@@ -40,6 +53,14 @@ pub async fn create_default_model() -> Model {
         application,
         current_state: socal::tui::model::CurrentState::Done,
         message_channel: message_sender,
+        events_state: socal::tui::model::EventsState::Loading,
+        event_filters: socal::tui::model::EventFilters::default(),
+        key_input: Default::default(),
+        notification: None,
+        reminders: Default::default(),
+        show_help: false,
+        theme: Default::default(),
+        screen: Rect::new(0, 0, 80, 24),
     }
 }
 
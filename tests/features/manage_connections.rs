@@ -40,6 +40,41 @@ async fn can_navigate_account_list() {
     assert_selected_index(0, &model);
 }
 
+#[tokio::test]
+async fn can_cycle_calendar_colour_override() {
+    async fn stored_override(db: &SqlitePool) -> Option<String> {
+        sqlx::query!("SELECT colour_override FROM calendars")
+            .fetch_one(db)
+            .await
+            .expect("failed to read calendar")
+            .colour_override
+    }
+
+    // Arrange
+    let mut model = create_default_model().await;
+    let account_id = seed_account("test@test.com".into(), &model.application.db).await;
+    seed_calendar("work@test.com".into(), account_id, &model.application.db).await;
+
+    let _ = update(&mut model, Message::ManageAccounts).await.unwrap();
+
+    // Act/Assert
+    let _ = update(&mut model, Message::CycleColour).await.unwrap();
+    assert_eq!(stored_override(&model.application.db).await, None);
+
+    let _ = update(&mut model, Message::Right).await.unwrap();
+    let _ = update(&mut model, Message::CycleColour).await.unwrap();
+    assert_eq!(
+        stored_override(&model.application.db).await.as_deref(),
+        Some("#d50000")
+    );
+
+    let _ = update(&mut model, Message::CycleColour).await.unwrap();
+    assert_eq!(
+        stored_override(&model.application.db).await.as_deref(),
+        Some("#e67c73")
+    );
+}
+
 async fn seed_account(email: String, db: &SqlitePool) -> i64 {
     let access_token = "blah";
     let refresh_token = "bloh";
@@ -57,3 +92,17 @@ async fn seed_account(email: String, db: &SqlitePool) -> i64 {
 
     row.id
 }
+
+async fn seed_calendar(calendar_id: String, account_id: i64, db: &SqlitePool) {
+    let title = "Work";
+
+    sqlx::query!(
+        "INSERT INTO calendars (calendar_id, account_id, title, primary_calendar) VALUES ($1, $2, $3, 0)",
+        calendar_id,
+        account_id,
+        title
+    )
+    .execute(db)
+    .await
+    .expect("failed to seed calendar");
+}
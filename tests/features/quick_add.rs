@@ -0,0 +1,95 @@
+use socal::{
+    domain::{calendar::Calendar, events::Event},
+    tui::{
+        model::{CurrentState, EventsState, Message, Model},
+        update::update,
+    },
+};
+
+use crate::create_default_model;
+
+fn prompt_error(model: &Model) -> Option<String> {
+    let CurrentState::QuickAdd(ref state) = model.current_state else {
+        panic!("model not in expected state")
+    };
+
+    state.error.clone()
+}
+
+#[tokio::test]
+async fn quick_add_explains_why_it_cannot_create_the_event() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act/Assert
+    let _ = update(&mut model, Message::QuickAdd).await.unwrap();
+    assert!(model.current_state.takes_text_input());
+
+    for c in "tomorrow 1pm".chars() {
+        let _ = update(&mut model, Message::Char(c)).await.unwrap();
+    }
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(
+        prompt_error(&model).as_deref(),
+        Some("The event needs a title")
+    );
+
+    for c in " lunch".chars() {
+        let _ = update(&mut model, Message::Char(c)).await.unwrap();
+    }
+    assert_eq!(prompt_error(&model), None);
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(
+        prompt_error(&model).as_deref(),
+        Some("There are no calendars the event can be added to")
+    );
+
+    let _ = update(&mut model, Message::Back).await.unwrap();
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+}
+
+#[tokio::test]
+async fn created_events_close_the_prompt_and_are_shown() {
+    // Arrange
+    let mut model = create_default_model().await;
+    model.events_state = EventsState::Ready(vec![]);
+    model.current_state = CurrentState::MonthView(Default::default());
+    let calendar = Calendar {
+        calendar_id: "test@test.com".to_owned(),
+        account_id: 1,
+        title: "Work".to_owned(),
+        color_id: None,
+        background_color: None,
+        colour_override: None,
+        access_role: Some("owner".to_owned()),
+    };
+
+    // Act/Assert
+    let _ = update(&mut model, Message::QuickAdd).await.unwrap();
+    let _ = update(
+        &mut model,
+        Message::EventCreated(Box::new(Event::test_default()), Box::new(calendar)),
+    )
+    .await
+    .unwrap();
+    assert!(matches!(model.current_state, CurrentState::MonthView(_)));
+    let EventsState::Ready(ref events) = model.events_state else {
+        panic!("events not ready")
+    };
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].calendar_title.as_deref(), Some("Work"));
+
+    // A failure reported once the prompt has closed is still shown
+    let _ = update(
+        &mut model,
+        Message::EventCreateFailed("Failed to create event: offline".to_owned()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        model.notification.as_deref(),
+        Some("Failed to create event: offline")
+    );
+}
@@ -0,0 +1,65 @@
+use chrono::{Duration, Utc};
+use socal::{
+    domain::events::{Event, EventTime, Reminder, ReminderMethod},
+    tui::{
+        model::{CurrentState, EventsState, Message, Model},
+        update::update,
+    },
+};
+
+use crate::create_default_model;
+
+fn showing_count(model: &Model) -> usize {
+    model.reminders.showing.len()
+}
+
+#[tokio::test]
+async fn reminders_can_be_snoozed_and_stay_dismissed_after_restart() {
+    // Arrange
+    let mut model = create_default_model().await;
+    let start = Utc::now() + Duration::minutes(5);
+    model.events_state = EventsState::Ready(vec![
+        create_event("standup", start),
+        create_event("review", start + Duration::minutes(2)),
+    ]);
+    model.current_state = CurrentState::MonthView(Default::default());
+
+    // Act/Assert
+    let _ = update(&mut model, Message::CheckReminders).await.unwrap();
+    assert_eq!(showing_count(&model), 2);
+    assert_eq!(model.reminders.showing[0].event.id, "standup");
+
+    // Keys go to the reminder rather than the calendar
+    let _ = update(&mut model, Message::Snooze(5)).await.unwrap();
+    let _ = update(&mut model, Message::Enter).await.unwrap();
+    assert_eq!(showing_count(&model), 0);
+    assert!(matches!(
+        model.current_state,
+        CurrentState::MonthView(ref state) if state.summary.event_details.is_none()
+    ));
+
+    let _ = update(&mut model, Message::CheckReminders).await.unwrap();
+    assert_eq!(showing_count(&model), 0);
+
+    // Reopening the calendar forgets snoozes but not dismissals
+    model.reminders = Default::default();
+    let _ = update(&mut model, Message::CheckReminders).await.unwrap();
+    assert_eq!(showing_count(&model), 1);
+    assert_eq!(model.reminders.showing[0].event.id, "standup");
+}
+
+fn create_event(id: &str, start: chrono::DateTime<Utc>) -> Event {
+    Event {
+        id: id.into(),
+        title: id.into(),
+        time: EventTime::Timed {
+            start,
+            end: start + Duration::hours(1),
+        },
+        reminders: vec![Reminder {
+            method: ReminderMethod::Popup,
+            minutes: 10,
+        }],
+        ..Event::test_default()
+    }
+}